@@ -88,6 +88,206 @@ function mutateIdl() {
         type: "publicKey",
       });
     }
+    if (instruction.name === "ConfigureSeatAuction") {
+      instruction.args.push(
+        {
+          name: "reservePrice",
+          type: "u64",
+        },
+        {
+          name: "durationInSlots",
+          type: "u64",
+        }
+      );
+    }
+    if (instruction.name === "BidForSeat") {
+      instruction.args.push({
+        name: "amount",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "WithdrawTreasury") {
+      instruction.args.push({
+        name: "amount",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "SetDustThreshold") {
+      instruction.args.push({
+        name: "dustThresholdInLots",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "SetEvictionPolicy") {
+      instruction.args.push({
+        name: "evictionPolicy",
+        type: {
+          defined: "EvictionPolicy",
+        },
+      });
+    }
+    if (instruction.name === "SetPermissionlessAccess") {
+      instruction.args.push(
+        {
+          name: "disablePermissionlessEviction",
+          type: "bool",
+        },
+        {
+          name: "disablePermissionlessClaims",
+          type: "bool",
+        }
+      );
+    }
+    if (instruction.name === "SetHeartbeatStalenessWindow") {
+      instruction.args.push({
+        name: "heartbeatStalenessWindowInSlots",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "SetReclaimCooldown") {
+      instruction.args.push({
+        name: "reclaimCooldownInSlots",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "SetReservedSeats") {
+      instruction.args.push({
+        name: "numReservedSeats",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "SetLaunchPhaseEndSlot") {
+      instruction.args.push({
+        name: "launchPhaseEndSlot",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "CreateMultisig") {
+      instruction.args.push(
+        {
+          name: "threshold",
+          type: "u64",
+        },
+        {
+          name: "signers",
+          type: {
+            vec: "publicKey",
+          },
+        }
+      );
+    }
+    if (instruction.name === "UpdateMultisig") {
+      instruction.args.push(
+        {
+          name: "threshold",
+          type: "u64",
+        },
+        {
+          name: "signers",
+          type: {
+            vec: "publicKey",
+          },
+        }
+      );
+    }
+    if (instruction.name === "SetRole") {
+      instruction.args.push(
+        {
+          name: "role",
+          type: {
+            defined: "SeatManagerRole",
+          },
+        },
+        {
+          name: "holder",
+          type: "publicKey",
+        }
+      );
+    }
+    if (instruction.name === "SetTimelockDelay") {
+      instruction.args.push({
+        name: "timelockDelayInSlots",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "QueueTimelockedAction") {
+      instruction.args.push({
+        name: "action",
+        type: {
+          defined: "TimelockedAction",
+        },
+      });
+    }
+    if (instruction.name === "CancelTimelockedAction") {
+      instruction.args.push({
+        name: "action",
+        type: {
+          defined: "TimelockedAction",
+        },
+      });
+    }
+    if (instruction.name === "InitiateRenounceCapabilities") {
+      instruction.args.push({
+        name: "capabilities",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "ConfirmRenounceCapabilities") {
+      instruction.args.push({
+        name: "capabilities",
+        type: "u64",
+      });
+    }
+    if (instruction.name === "SetGuardian") {
+      instruction.args.push({
+        name: "guardian",
+        type: "publicKey",
+      });
+    }
+    if (instruction.name === "GuardianPauseMarket") {
+      instruction.args.push({
+        name: "marketStatus",
+        type: {
+          defined: "MarketStatus",
+        },
+      });
+    }
+    if (instruction.name === "NameAdminGroupSuccessor") {
+      instruction.args.push({
+        name: "successor",
+        type: "publicKey",
+      });
+    }
+    if (instruction.name === "CreateDmmRegistry") {
+      instruction.args.push({
+        name: "authority",
+        type: "publicKey",
+      });
+    }
+    if (instruction.name === "AddRegistryDmm") {
+      instruction.args.push({
+        name: "trader",
+        type: "publicKey",
+      });
+    }
+    if (instruction.name === "RemoveRegistryDmm") {
+      instruction.args.push({
+        name: "trader",
+        type: "publicKey",
+      });
+    }
+    if (instruction.name === "SetDmmRegistry") {
+      instruction.args.push({
+        name: "dmmRegistry",
+        type: "publicKey",
+      });
+    }
+    if (instruction.name === "EvictSeat") {
+      instruction.args.push({
+        name: "numTraders",
+        type: "u8",
+      });
+    }
   }
   fs.writeFileSync(generatedIdlPath, JSON.stringify(idl, null, 2));
 }
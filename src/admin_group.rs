@@ -0,0 +1,42 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+use static_assertions::const_assert_eq;
+
+/// A single admin identity that can hold the seat manager authority of many markets, seeds are [creator, b"admin_group"].
+/// While a seat manager's authority is the admin group, authority-gated instructions take the admin group account
+/// and the group's authority as an extra signing account, so rotating the group's authority rotates every linked market.
+/// The rotation is not subject to the timelocks of the linked seat managers. Like seat manager nominations,
+/// successor nominations expire after `DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS` unless an expiry slot is given.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct AdminGroup {
+    pub creator: Pubkey,
+    /// A single key or a multisig. An admin group can't be the authority of another admin group
+    pub authority: Pubkey,
+    pub successor: Pubkey,
+    /// Slot after which the successor can no longer claim the authority. 0 if the nomination does not expire
+    pub successor_expiry_slot: u64,
+    pub _padding: [u64; 7],
+}
+
+const_assert_eq!(std::mem::size_of::<AdminGroup>(), 160);
+
+impl AdminGroup {
+    pub fn is_successor_expired(&self, current_slot: u64) -> bool {
+        self.successor_expiry_slot != 0 && current_slot > self.successor_expiry_slot
+    }
+
+    pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
+        bytemuck::try_from_bytes::<AdminGroup>(bytes).map_err(|_| {
+            msg!("Failed to load admin group from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn load_mut(bytes: &'_ mut [u8]) -> Result<&'_ mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut::<AdminGroup>(bytes).map_err(|_| {
+            msg!("Failed to load admin group from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+}
@@ -0,0 +1,165 @@
+use bytemuck::{Pod, Zeroable};
+use num_enum::TryFromPrimitive;
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+use static_assertions::const_assert_eq;
+
+pub const ADMIN_HISTORY_LEN: usize = 64;
+
+/// The kind of a recorded admin action. The record's `key` and `value` hold the arguments noted on each kind,
+/// and are zero otherwise.
+#[repr(u64)]
+#[derive(TryFromPrimitive, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AdminActionKind {
+    /// `value` is the new `MarketStatus`
+    ChangeMarketStatus = 0,
+    /// `value` is the new `MarketStatus`
+    GuardianPauseMarket = 1,
+    /// `key` is the new guardian
+    SetGuardian = 2,
+    /// `key` is the new fee recipient
+    ChangeMarketFeeRecipient = 3,
+    /// `key` is the market authority successor
+    NameMarketAuthoritySuccessor = 4,
+    /// `key` is the successor, `value` is the nomination's expiry slot
+    NameSuccessor = 5,
+    CancelSuccessor = 6,
+    /// The signer is the new authority
+    ClaimSeatManagerAuthority = 7,
+    ConfirmRenounceSeatManagerAuthority = 8,
+    /// `key` is the DMM
+    AddDmm = 9,
+    /// `key` is the DMM
+    RemoveDmm = 10,
+    /// `key` is the applicant
+    ApproveDmmApplication = 11,
+    /// `key` is the applicant
+    RejectDmmApplication = 12,
+    /// `key` is the DMM
+    ClaimSeatAndDesignate = 13,
+    /// `key` is the trader
+    ClaimSeatAuthorized = 14,
+    /// `key` is the trader that claimed the seat
+    ClaimSeatWithEvictionAuthorized = 15,
+    /// `key` is the evicted trader
+    PrivilegedEviction = 16,
+    /// `value` is the reserve price
+    ConfigureSeatAuction = 17,
+    /// `key` is the destination, `value` is the amount
+    WithdrawTreasury = 18,
+    /// `value` is the dust threshold in lots
+    SetDustThreshold = 19,
+    /// `value` is the `EvictionPolicy` discriminant
+    SetEvictionPolicy = 20,
+    /// `value` has bit 0 set if permissionless eviction is disabled, bit 1 if permissionless claims are disabled
+    SetPermissionlessAccess = 21,
+    /// `value` is the window in slots
+    SetHeartbeatStalenessWindow = 22,
+    /// `value` is the cooldown in slots
+    SetReclaimCooldown = 23,
+    /// `value` is the number of reserved seats
+    SetReservedSeats = 24,
+    /// `value` is the launch phase end slot
+    SetLaunchPhaseEndSlot = 25,
+    /// `key` is the multisig, `value` is its threshold
+    CreateMultisig = 26,
+    /// `key` is the holder, `value` is the `SeatManagerRole` index
+    SetRole = 27,
+    /// `value` is the delay in slots
+    SetTimelockDelay = 28,
+    /// `key` is the action's target, `value` is the action's discriminant
+    QueueTimelockedAction = 29,
+    /// `key` is the action's target, `value` is the action's discriminant
+    CancelTimelockedAction = 30,
+    /// `key` is the action's target, `value` is the action's discriminant. The signer is the payer of the pending action, since anyone can execute it
+    ExecuteTimelockedAction = 31,
+    /// `value` is the capabilities bitmask
+    InitiateRenounceCapabilities = 32,
+    /// `value` is the capabilities bitmask
+    ConfirmRenounceCapabilities = 33,
+    /// The first record of every history
+    CreateAdminHistory = 34,
+    /// `key` is the linked `DmmRegistry`, or the default pubkey if unlinked
+    SetDmmRegistry = 35,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+pub struct AdminActionRecord {
+    /// Discriminant of the `AdminActionKind`
+    pub kind: u64,
+    /// The key that approved the action, e.g. the authority, a role holder or a multisig
+    pub signer: Pubkey,
+    pub slot: u64,
+    pub key: Pubkey,
+    pub value: u64,
+}
+
+impl AdminActionRecord {
+    pub fn get_kind(&self) -> Option<AdminActionKind> {
+        AdminActionKind::try_from(self.kind).ok()
+    }
+}
+
+/// A ring buffer of the most recent admin actions on a market, seeds are [market_address, b"admin_history"]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct AdminHistory {
+    pub market: Pubkey,
+    /// The number of actions ever recorded. The next record is written at `num_actions % ADMIN_HISTORY_LEN`
+    pub num_actions: u64,
+    pub _padding: [u64; 3],
+    pub records: [AdminActionRecord; ADMIN_HISTORY_LEN],
+}
+
+const_assert_eq!(std::mem::size_of::<AdminHistory>(), 5696);
+
+impl AdminHistory {
+    pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
+        bytemuck::try_from_bytes::<AdminHistory>(bytes).map_err(|_| {
+            msg!("Failed to load admin history from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn load_mut(bytes: &'_ mut [u8]) -> Result<&'_ mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut::<AdminHistory>(bytes).map_err(|_| {
+            msg!("Failed to load admin history from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    /// Overwrites the oldest record once the buffer is full
+    pub fn push(&mut self, record: AdminActionRecord) {
+        self.records[self.num_actions as usize % ADMIN_HISTORY_LEN] = record;
+        self.num_actions += 1;
+    }
+
+    /// The retained records, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &AdminActionRecord> {
+        let len = (self.num_actions as usize).min(ADMIN_HISTORY_LEN);
+        let start = self.num_actions as usize - len;
+        (start..self.num_actions as usize).map(move |i| &self.records[i % ADMIN_HISTORY_LEN])
+    }
+}
+
+#[test]
+fn test_admin_history_wraps_around() {
+    let mut history = AdminHistory::zeroed();
+    assert_eq!(history.iter().count(), 0);
+
+    for slot in 0..ADMIN_HISTORY_LEN as u64 + 5 {
+        history.push(AdminActionRecord {
+            kind: AdminActionKind::SetDustThreshold as u64,
+            slot,
+            ..Default::default()
+        });
+    }
+    let slots = history.iter().map(|record| record.slot).collect::<Vec<_>>();
+    assert_eq!(slots.len(), ADMIN_HISTORY_LEN);
+    assert_eq!(slots[0], 5);
+    assert_eq!(*slots.last().unwrap(), ADMIN_HISTORY_LEN as u64 + 4);
+    assert_eq!(
+        history.records[0].get_kind(),
+        Some(AdminActionKind::SetDustThreshold)
+    );
+}
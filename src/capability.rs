@@ -0,0 +1,58 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Powers of the seat manager authority that can be irrevocably renounced one at a time.
+/// A renounced capability cannot be exercised by the authority, its successors or any role holder.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum SeatManagerCapability {
+    /// Changing the market status
+    ChangeMarketStatus,
+    /// Changing the market fee recipient
+    ChangeFeeRecipient,
+    /// Naming a successor to the market authority
+    NameMarketAuthoritySuccessor,
+    /// Adding DMMs, directly or through applications and `ClaimSeatAndDesignate`
+    AddDmms,
+    /// Removing DMMs
+    RemoveDmms,
+    /// Evicting seats with the authority's eviction privileges
+    PrivilegedEviction,
+    /// Claiming seats through the authorized claim instructions
+    AuthorizedClaims,
+    /// Withdrawing from the treasury
+    WithdrawTreasury,
+    /// Naming a successor to the seat manager authority. Renouncing the authority entirely is still possible
+    NameSuccessor,
+}
+
+pub const NUM_CAPABILITIES: u32 = 9;
+
+impl SeatManagerCapability {
+    pub fn mask(&self) -> u64 {
+        1 << (*self as u64)
+    }
+
+    pub fn to_mask(capabilities: &[SeatManagerCapability]) -> u64 {
+        capabilities
+            .iter()
+            .fold(0, |mask, capability| mask | capability.mask())
+    }
+
+    /// Returns true if the mask only contains known capabilities
+    pub fn is_valid_mask(mask: u64) -> bool {
+        mask >> NUM_CAPABILITIES == 0
+    }
+}
+
+#[test]
+fn test_capability_masks() {
+    let mask = SeatManagerCapability::to_mask(&[
+        SeatManagerCapability::ChangeFeeRecipient,
+        SeatManagerCapability::RemoveDmms,
+    ]);
+    assert_eq!(mask, 0b10010);
+    assert!(SeatManagerCapability::is_valid_mask(mask));
+    assert!(SeatManagerCapability::is_valid_mask(
+        SeatManagerCapability::NameSuccessor.mask()
+    ));
+    assert!(!SeatManagerCapability::is_valid_mask(1 << NUM_CAPABILITIES));
+}
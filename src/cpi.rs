@@ -0,0 +1,851 @@
+//! Helpers for programs that hold the seat manager authority as a PDA and sign for it through CPI.
+//!
+//! Each helper builds the instruction with the matching function in `instruction_builders` and
+//! invokes it with `authority_seeds`, the seeds (including the bump) of the authority PDA.
+//! `account_infos` must contain every account of the instruction and the seat manager program.
+//! Once the market's admin history is enabled, `account_infos` must also contain the admin history account
+//! at `get_admin_history_address(market)`, which is then passed to every instruction on the market.
+
+use std::mem::size_of;
+
+use phoenix::program::{status::MarketStatus, MarketHeader};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    capability::SeatManagerCapability, eviction_policy::EvictionPolicy, get_admin_history_address,
+    get_seat_manager_address, get_seat_record_address, instruction_builders::*,
+    pending_action::TimelockedAction, role::SeatManagerRole, seat_manager::SeatManager,
+};
+
+fn invoke_as_authority(
+    market: &Pubkey,
+    mut instruction: Instruction,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    // The admin history is optional, and the market's admin history is forwarded whenever it is supplied and initialized
+    let (admin_history, _) = get_admin_history_address(market);
+    let is_admin_history_supplied = account_infos
+        .iter()
+        .any(|ai| *ai.key == admin_history && ai.owner == &crate::id() && !ai.data_is_empty());
+    if is_admin_history_supplied
+        && !instruction
+            .accounts
+            .iter()
+            .any(|account| account.pubkey == admin_history)
+    {
+        instruction
+            .accounts
+            .push(AccountMeta::new(admin_history, false));
+    }
+    invoke_signed(&instruction, account_infos, &[authority_seeds])
+}
+
+/// The market's mints and the optional accounts that evictions on the market require
+struct EvictionParams {
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    requires_seat_records: bool,
+    dmm_registry: Option<Pubkey>,
+}
+
+impl EvictionParams {
+    fn load(market: &Pubkey, account_infos: &[AccountInfo]) -> Result<Self, ProgramError> {
+        let (seat_manager, _) = get_seat_manager_address(market);
+        let find_account = |key: &Pubkey| {
+            account_infos
+                .iter()
+                .find(|ai| ai.key == key)
+                .ok_or_else(|| {
+                    msg!("Missing account {}", key);
+                    ProgramError::NotEnoughAccountKeys
+                })
+        };
+        let (base_mint, quote_mint) = {
+            let market_data = find_account(market)?.try_borrow_data()?;
+            let market_header = bytemuck::try_from_bytes::<MarketHeader>(
+                market_data
+                    .get(..size_of::<MarketHeader>())
+                    .ok_or(ProgramError::InvalidAccountData)?,
+            )
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+            (
+                market_header.base_params.mint_key,
+                market_header.quote_params.mint_key,
+            )
+        };
+        let seat_manager_data = find_account(&seat_manager)?.try_borrow_data()?;
+        let seat_manager = SeatManager::load(&seat_manager_data)?;
+        Ok(Self {
+            base_mint,
+            quote_mint,
+            requires_seat_records: seat_manager.requires_seat_records(),
+            dmm_registry: seat_manager.get_dmm_registry(),
+        })
+    }
+
+    fn with_dmm_registry(&self, instruction: Instruction) -> Instruction {
+        match self.dmm_registry {
+            Some(dmm_registry) => with_dmm_registry(instruction, &dmm_registry),
+            None => instruction,
+        }
+    }
+}
+
+pub fn name_successor(
+    market: &Pubkey,
+    authority: &Pubkey,
+    successor: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_name_seat_manager_successor_instruction(authority, market, successor),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn name_successor_with_expiry(
+    market: &Pubkey,
+    authority: &Pubkey,
+    successor: &Pubkey,
+    expiry_slot: u64,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_name_seat_manager_successor_with_expiry_instruction(
+            authority,
+            market,
+            successor,
+            expiry_slot,
+        ),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn cancel_successor(
+    market: &Pubkey,
+    authority: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_cancel_seat_manager_successor_instruction(authority, market),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+/// Here `authority_seeds` are the seeds of the successor PDA that claims the authority
+pub fn claim_seat_manager_authority(
+    market: &Pubkey,
+    successor: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_claim_seat_manager_authority_instruction(market, successor),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn initiate_renounce_seat_manager_authority(
+    market: &Pubkey,
+    authority: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_initiate_renounce_seat_manager_authority_instruction(authority, market),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn confirm_renounce_seat_manager_authority(
+    market: &Pubkey,
+    authority: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_confirm_renounce_seat_manager_authority_instruction(authority, market),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+/// The authority pays for the seat, so the PDA must hold enough lamports and no data
+pub fn claim_seat_authorized(
+    market: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_claim_seat_authorized_instruction(trader, market, authority),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+/// The authority pays for the seat, so the PDA must hold enough lamports and no data
+pub fn claim_seat_and_designate(
+    market: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_claim_seat_and_designate_instruction(trader, market, authority),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+/// Evicts the traders with the authority's eviction privileges.
+/// The seat records and the DMM registry are added if the seat manager requires them
+pub fn evict_seat(
+    market: &Pubkey,
+    authority: &Pubkey,
+    traders: Vec<EvictTraderAccountBackup>,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    if traders.len() > u8::MAX as usize {
+        msg!("At most 255 traders can be evicted in one instruction");
+        return Err(ProgramError::InvalidArgument);
+    }
+    let params = EvictionParams::load(market, account_infos)?;
+    let instruction = if params.requires_seat_records {
+        create_evict_seat_with_seat_records_instruction(
+            market,
+            &params.base_mint,
+            &params.quote_mint,
+            authority,
+            traders,
+        )
+    } else {
+        create_evict_seat_instruction(
+            market,
+            &params.base_mint,
+            &params.quote_mint,
+            authority,
+            traders,
+        )
+    };
+    invoke_as_authority(
+        market,
+        params.with_dmm_registry(instruction),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+/// The authority pays for the seat, so the PDA must hold enough lamports and no data.
+/// The evicted trader's seat record and the DMM registry are added if the seat manager requires them
+pub fn claim_seat_with_eviction_authorized(
+    market: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    evicted_trader: EvictTraderAccountBackup,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    let params = EvictionParams::load(market, account_infos)?;
+    let (evicted_seat_record, _) = get_seat_record_address(market, &evicted_trader.trader_pubkey);
+    let mut instruction = create_claim_seat_with_eviction_authorized_instruction(
+        trader,
+        market,
+        authority,
+        &params.base_mint,
+        &params.quote_mint,
+        evicted_trader,
+    );
+    if params.requires_seat_records {
+        instruction
+            .accounts
+            .push(AccountMeta::new(evicted_seat_record, false));
+    }
+    invoke_as_authority(
+        market,
+        params.with_dmm_registry(instruction),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn add_designated_market_maker(
+    market: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_add_dmm_instruction(market, authority, trader),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn remove_designated_market_maker(
+    market: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_remove_dmm_instruction(market, authority, trader),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn approve_dmm_application(
+    market: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_approve_dmm_application_instruction(market, trader, authority),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn reject_dmm_application(
+    market: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_reject_dmm_application_instruction(market, trader, authority),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn change_market_status(
+    market: &Pubkey,
+    authority: &Pubkey,
+    status: MarketStatus,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_change_market_status_instruction(market, authority, status),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+/// Here `authority_seeds` are the seeds of the guardian PDA
+pub fn guardian_pause_market(
+    market: &Pubkey,
+    guardian: &Pubkey,
+    status: MarketStatus,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_guardian_pause_market_instruction(market, guardian, status),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_guardian(
+    market: &Pubkey,
+    authority: &Pubkey,
+    guardian: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_guardian_instruction(market, authority, guardian),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn create_admin_history(
+    market: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_create_admin_history_instruction(market, authority, payer),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn create_multisig(
+    market: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    threshold: u64,
+    signers: Vec<Pubkey>,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_create_multisig_instruction(market, authority, payer, threshold, signers),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+/// Here `authority_seeds` are the seeds of a PDA among `approving_signers`.
+/// The other approving signers must have signed the outer instruction
+pub fn update_multisig(
+    market: &Pubkey,
+    approving_signers: &[Pubkey],
+    threshold: u64,
+    signers: Vec<Pubkey>,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_update_multisig_instruction(market, approving_signers, threshold, signers),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn create_admin_group(
+    creator: &Pubkey,
+    payer: &Pubkey,
+    account_infos: &[AccountInfo],
+    creator_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(
+        &create_create_admin_group_instruction(creator, payer),
+        account_infos,
+        &[creator_seeds],
+    )
+}
+
+pub fn name_admin_group_successor(
+    admin_group: &Pubkey,
+    authority: &Pubkey,
+    successor: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(
+        &create_name_admin_group_successor_instruction(admin_group, authority, successor),
+        account_infos,
+        &[authority_seeds],
+    )
+}
+
+pub fn claim_admin_group_authority(
+    admin_group: &Pubkey,
+    successor: &Pubkey,
+    account_infos: &[AccountInfo],
+    successor_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(
+        &create_claim_admin_group_authority_instruction(admin_group, successor),
+        account_infos,
+        &[successor_seeds],
+    )
+}
+
+/// Here `creator_seeds` are the seeds of the creator PDA
+pub fn create_dmm_registry(
+    creator: &Pubkey,
+    payer: &Pubkey,
+    authority: &Pubkey,
+    account_infos: &[AccountInfo],
+    creator_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(
+        &create_create_dmm_registry_instruction(creator, payer, authority),
+        account_infos,
+        &[creator_seeds],
+    )
+}
+
+pub fn add_registry_dmm(
+    dmm_registry: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(
+        &create_add_registry_dmm_instruction(dmm_registry, authority, trader),
+        account_infos,
+        &[authority_seeds],
+    )
+}
+
+pub fn remove_registry_dmm(
+    dmm_registry: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(
+        &create_remove_registry_dmm_instruction(dmm_registry, authority, trader),
+        account_infos,
+        &[authority_seeds],
+    )
+}
+
+pub fn set_dmm_registry(
+    market: &Pubkey,
+    authority: &Pubkey,
+    dmm_registry: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_dmm_registry_instruction(market, authority, dmm_registry),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn name_market_authority_successor(
+    market: &Pubkey,
+    authority: &Pubkey,
+    successor: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_name_market_authority_successor_instruction(market, authority, successor),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn change_market_fee_recipient(
+    market: &Pubkey,
+    authority: &Pubkey,
+    new_recipient: &Pubkey,
+    quote_mint: &Pubkey,
+    current_fee_recipient: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_change_market_fee_recipient_instruction(
+            market,
+            authority,
+            new_recipient,
+            quote_mint,
+            current_fee_recipient,
+        ),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+/// The authority pays for the seat auction account, so the PDA must hold enough lamports and no data
+pub fn configure_seat_auction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    reserve_price: u64,
+    duration_in_slots: u64,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_configure_seat_auction_instruction(
+            market,
+            authority,
+            reserve_price,
+            duration_in_slots,
+        ),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn withdraw_treasury(
+    market: &Pubkey,
+    authority: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_withdraw_treasury_instruction(market, authority, destination, amount),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_dust_threshold(
+    market: &Pubkey,
+    authority: &Pubkey,
+    dust_threshold_in_lots: u64,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_dust_threshold_instruction(market, authority, dust_threshold_in_lots),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_eviction_policy(
+    market: &Pubkey,
+    authority: &Pubkey,
+    eviction_policy: EvictionPolicy,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_eviction_policy_instruction(market, authority, eviction_policy),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_permissionless_access(
+    market: &Pubkey,
+    authority: &Pubkey,
+    disable_permissionless_eviction: bool,
+    disable_permissionless_claims: bool,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_permissionless_access_instruction(
+            market,
+            authority,
+            disable_permissionless_eviction,
+            disable_permissionless_claims,
+        ),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_heartbeat_staleness_window(
+    market: &Pubkey,
+    authority: &Pubkey,
+    heartbeat_staleness_window_in_slots: u64,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_heartbeat_staleness_window_instruction(
+            market,
+            authority,
+            heartbeat_staleness_window_in_slots,
+        ),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_reclaim_cooldown(
+    market: &Pubkey,
+    authority: &Pubkey,
+    reclaim_cooldown_in_slots: u64,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_reclaim_cooldown_instruction(market, authority, reclaim_cooldown_in_slots),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_reserved_seats(
+    market: &Pubkey,
+    authority: &Pubkey,
+    num_reserved_seats: u64,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_reserved_seats_instruction(market, authority, num_reserved_seats),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_launch_phase_end_slot(
+    market: &Pubkey,
+    authority: &Pubkey,
+    launch_phase_end_slot: u64,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_launch_phase_end_slot_instruction(market, authority, launch_phase_end_slot),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn initiate_renounce_capabilities(
+    market: &Pubkey,
+    authority: &Pubkey,
+    capabilities: &[SeatManagerCapability],
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_initiate_renounce_capabilities_instruction(market, authority, capabilities),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn confirm_renounce_capabilities(
+    market: &Pubkey,
+    authority: &Pubkey,
+    capabilities: &[SeatManagerCapability],
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_confirm_renounce_capabilities_instruction(market, authority, capabilities),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_role(
+    market: &Pubkey,
+    authority: &Pubkey,
+    role: SeatManagerRole,
+    holder: &Pubkey,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_role_instruction(market, authority, role, holder),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn revoke_role(
+    market: &Pubkey,
+    authority: &Pubkey,
+    role: SeatManagerRole,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_revoke_role_instruction(market, authority, role),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn set_timelock_delay(
+    market: &Pubkey,
+    authority: &Pubkey,
+    timelock_delay_in_slots: u64,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_set_timelock_delay_instruction(market, authority, timelock_delay_in_slots),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn queue_timelocked_action(
+    market: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    action: TimelockedAction,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_queue_timelocked_action_instruction(market, authority, payer, action),
+        account_infos,
+        authority_seeds,
+    )
+}
+
+pub fn cancel_timelocked_action(
+    market: &Pubkey,
+    authority: &Pubkey,
+    rent_receiver: &Pubkey,
+    action: TimelockedAction,
+    account_infos: &[AccountInfo],
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_as_authority(
+        market,
+        create_cancel_timelocked_action_instruction(market, authority, rent_receiver, action),
+        account_infos,
+        authority_seeds,
+    )
+}
@@ -0,0 +1,29 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// A trader's pending application to become a DMM, seeds are [market_address, trader_address, b"dmm_application"].
+/// The account is closed when the seat manager authority approves or rejects the application.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct DmmApplication {
+    pub market: Pubkey,
+    pub trader: Pubkey,
+    pub application_slot: u64,
+    pub _padding: [u64; 7],
+}
+
+impl DmmApplication {
+    pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
+        bytemuck::try_from_bytes::<DmmApplication>(bytes).map_err(|_| {
+            msg!("Failed to load DMM application from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn load_mut(bytes: &'_ mut [u8]) -> Result<&'_ mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut::<DmmApplication>(bytes).map_err(|_| {
+            msg!("Failed to load DMM application from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+}
@@ -0,0 +1,85 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+use static_assertions::const_assert_eq;
+
+pub const MAX_REGISTRY_DMMS: usize = 128;
+
+/// A list of DMMs shared by every seat manager linked to it, seeds are [creator, b"dmm_registry"].
+/// Traders in the registry are protected from eviction on every linked market, as if they were in the
+/// seat manager's own `designated_market_makers`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct DmmRegistry {
+    pub creator: Pubkey,
+    /// Adds and removes DMMs. Can be a single key, a multisig or an admin group
+    pub authority: Pubkey,
+    pub num_makers: u64,
+    pub _padding: [u64; 3],
+    pub designated_market_makers: [Pubkey; MAX_REGISTRY_DMMS],
+}
+
+const_assert_eq!(std::mem::size_of::<DmmRegistry>(), 4192);
+
+impl DmmRegistry {
+    pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
+        bytemuck::try_from_bytes::<DmmRegistry>(bytes).map_err(|_| {
+            msg!("Failed to load DMM registry from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn load_mut(bytes: &'_ mut [u8]) -> Result<&'_ mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut::<DmmRegistry>(bytes).map_err(|_| {
+            msg!("Failed to load DMM registry from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn get_dmms(&self) -> &[Pubkey] {
+        &self.designated_market_makers[..self.num_makers as usize]
+    }
+
+    pub fn contains(&self, trader: &Pubkey) -> bool {
+        self.get_dmms().contains(trader)
+    }
+
+    pub fn insert(&mut self, trader: &Pubkey) -> Option<usize> {
+        if self.contains(trader) {
+            msg!("Trader is already in the DMM registry");
+            return None;
+        }
+        if self.num_makers as usize >= MAX_REGISTRY_DMMS {
+            msg!("DMM registry is full");
+            return None;
+        }
+        let index = self.num_makers as usize;
+        self.designated_market_makers[index] = *trader;
+        self.num_makers += 1;
+        Some(index)
+    }
+
+    /// Performs a swap-remove on the designated market makers array.
+    pub fn remove(&mut self, dmm: &Pubkey) -> Option<usize> {
+        let index = self.get_dmms().iter().position(|maker| maker == dmm)?;
+        let last_index = (self.num_makers - 1) as usize;
+        self.designated_market_makers[index] = self.designated_market_makers[last_index];
+        self.designated_market_makers[last_index] = Pubkey::default();
+        self.num_makers -= 1;
+        Some(index)
+    }
+}
+
+#[test]
+fn test_dmm_registry_insert_remove() {
+    let mut registry = DmmRegistry::zeroed();
+    let dmms = (0..3).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+    for dmm in dmms.iter() {
+        assert!(registry.insert(dmm).is_some());
+    }
+    assert!(registry.insert(&dmms[0]).is_none());
+
+    assert_eq!(registry.remove(&dmms[0]), Some(0));
+    assert_eq!(registry.get_dmms(), &[dmms[2], dmms[1]]);
+    assert!(!registry.contains(&dmms[0]));
+    assert!(registry.remove(&dmms[0]).is_none());
+}
@@ -0,0 +1,100 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Decides who can evict which seats. Set by the seat manager authority, and `Default` unless configured.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum EvictionPolicy {
+    /// Empty seats can be evicted by anyone. Seats with no locked lots can be evicted by anyone once the market is full
+    Default,
+    /// Empty seats can be evicted by anyone. Seats with no locked lots can be evicted by anyone once the
+    /// number of registered traders reaches `occupancy_threshold_bps` of the market's seats
+    OccupancyThreshold { occupancy_threshold_bps: u64 },
+    /// Only the seat manager authority can evict seats
+    AuthorityOnly,
+}
+
+/// The seats that a signer is allowed to evict. DMM seats can never be evicted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EvictionPrivileges {
+    None,
+    /// Seats with no locked lots and only dust free lots
+    EmptySeats,
+    /// Seats with no locked lots
+    SeatsWithoutLockedLots,
+}
+
+impl EvictionPrivileges {
+    /// Whether the privileges cover a seat. `is_empty` seats have no locked lots and only dust free lots
+    pub fn covers(&self, has_locked_lots: bool, is_empty: bool) -> bool {
+        match self {
+            EvictionPrivileges::None => false,
+            EvictionPrivileges::EmptySeats => is_empty,
+            EvictionPrivileges::SeatsWithoutLockedLots => !has_locked_lots,
+        }
+    }
+}
+
+impl EvictionPolicy {
+    /// Returns the signer's eviction privileges on a market with `num_registered_traders` of `num_seats` seats taken.
+    /// `num_seats` excludes reserved seats that are not held by DMMs.
+    /// The seat manager authority can always evict seats without locked lots.
+    pub fn evaluate(
+        &self,
+        signer_is_authority: bool,
+        num_registered_traders: usize,
+        num_seats: usize,
+    ) -> EvictionPrivileges {
+        if signer_is_authority {
+            return EvictionPrivileges::SeatsWithoutLockedLots;
+        }
+        let has_eviction_privileges = match self {
+            EvictionPolicy::Default => num_registered_traders >= num_seats,
+            EvictionPolicy::OccupancyThreshold {
+                occupancy_threshold_bps,
+            } => {
+                num_registered_traders as u128 * 10_000
+                    >= num_seats as u128 * *occupancy_threshold_bps as u128
+            }
+            EvictionPolicy::AuthorityOnly => return EvictionPrivileges::None,
+        };
+        if has_eviction_privileges {
+            EvictionPrivileges::SeatsWithoutLockedLots
+        } else {
+            EvictionPrivileges::EmptySeats
+        }
+    }
+}
+
+#[test]
+fn test_eviction_policy_evaluation() {
+    use EvictionPrivileges::*;
+
+    for policy in [
+        EvictionPolicy::Default,
+        EvictionPolicy::OccupancyThreshold {
+            occupancy_threshold_bps: 8_000,
+        },
+        EvictionPolicy::AuthorityOnly,
+    ] {
+        assert_eq!(policy.evaluate(true, 0, 100), SeatsWithoutLockedLots);
+    }
+
+    assert_eq!(EvictionPolicy::Default.evaluate(false, 99, 100), EmptySeats);
+    assert_eq!(
+        EvictionPolicy::Default.evaluate(false, 100, 100),
+        SeatsWithoutLockedLots
+    );
+
+    let occupancy_threshold = EvictionPolicy::OccupancyThreshold {
+        occupancy_threshold_bps: 8_000,
+    };
+    assert_eq!(occupancy_threshold.evaluate(false, 79, 100), EmptySeats);
+    assert_eq!(
+        occupancy_threshold.evaluate(false, 80, 100),
+        SeatsWithoutLockedLots
+    );
+
+    assert_eq!(
+        EvictionPolicy::AuthorityOnly.evaluate(false, 100, 100),
+        None
+    );
+}
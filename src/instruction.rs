@@ -25,6 +25,7 @@ pub enum SeatManagerInstruction {
     #[account(6, writable, signer, name = "payer")]
     #[account(7, writable, name = "seat")]
     #[account(8, name = "system_program", desc = "System program")]
+    #[account(9, writable, optional, name = "seat_record", desc = "The trader's seat record, required while the seat manager has a re-claim cooldown, seeds are [market_address, trader_address, b'record']")]
     ClaimSeat = 1,
 
     /// Claim Seat Authorized
@@ -39,7 +40,8 @@ pub enum SeatManagerInstruction {
     #[account(8, name = "system_program", desc = "System program")]
     ClaimSeatAuthorized = 2,
 
-    /// Evict Seat 
+    /// Evict Seat. The instruction data is the number of evicted traders. Without it, every account after the signer must belong to an evicted trader,
+    /// and the instruction fails if the seat manager requires any of the accounts that follow the evicted traders
     #[account(0, name = "phoenix_program", desc = "Phoenix program")]
     #[account(1, name = "log_authority", desc = "Phoenix log authority")]
     #[account(2, writable, name = "market", desc = "This account holds the market state")]
@@ -60,6 +62,11 @@ pub enum SeatManagerInstruction {
     #[account(16, writable, name = "quote_account", desc = "The trader's associated token account for the quote mint")]
     #[account(17, writable, name = "base_account_backup", desc = "Non-ATA token account for the base mint, in case the ATA owner is no longer the trader")]
     #[account(18, writable, name = "quote_account_backup", desc = "Non-ATA token account for the quote mint, in case the ATA owner is no longer the trader")]
+    // If the seat manager has a heartbeat staleness window or a re-claim cooldown, each trader's accounts end with the trader's seat record,
+    // seeds are [market_address, trader_address, b'record']
+    // If traders are waitlisted, the seat manager must be writable and the trader accounts must be followed by
+    // the seat waitlist and the seat and trader accounts of the traders at the front of the waitlist.
+    // A multisig or admin group and their signers, the admin history and the DMM registry can follow in any order
     EvictSeat = 3,
 
     /// Add DMM Seat 
@@ -76,7 +83,7 @@ pub enum SeatManagerInstruction {
     #[account(3, signer, name = "seat_manager_authority", desc = "The seat manager authority account must sign to remove a DMM")]
     RemoveDesignatedMarketMaker = 5,
 
-    /// Name Successor 
+    /// Name Successor. The instruction data is an optional expiry slot, the nomination expires after a default window without one
     #[account(0, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
     #[account(1, signer, name = "seat_manager_authority", desc = "The seat manager account must sign name a successor")]
     #[account(2, name = "successor", desc = "The new authority account")]
@@ -112,10 +119,378 @@ pub enum SeatManagerInstruction {
     #[account(8, name = "spl_token", desc = "The SPL token program")]
     ChangeMarketFeeRecipient = 10,
 
-    /// Confirm the renunciation of the seat manager authority by setting the SM authority to the system program
+    /// Confirm the renunciation of the seat manager authority by setting the SM authority to the system program.
+    /// Fails while timelocked actions are pending
     #[account(0, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
     #[account(1, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to renounce the seat manager authority")]
     ConfirmRenounceSeatManagerAuthority = 11,
+
+    /// Evict a single seat and claim a seat for the trader in the same instruction
+    #[account(0, name = "phoenix_program", desc = "Phoenix program")]
+    #[account(1, name = "log_authority", desc = "Phoenix log authority")]
+    #[account(2, writable, name = "market", desc = "This account holds the market state")]
+    #[account(3, writable, name = "seat_manager", desc = "The seat manager account must sign to evict and approve seats")]
+    #[account(4, writable, name = "seat_deposit_collector", desc = "Collects deposits for claiming new seats and refunds for evicting seats")]
+    #[account(5, name = "base_mint")]
+    #[account(6, name = "quote_mint")]
+    #[account(7, writable, name = "base_vault")]
+    #[account(8, writable, name = "quote_vault")]
+    #[account(9, name = "associated_token_account_program", desc = "Associated token account program")]
+    #[account(10, name = "token_program", desc = "Token program")]
+    #[account(11, name = "system_program", desc = "System program")]
+    #[account(12, writable, signer, name = "payer", desc = "Pays the seat deposit")]
+    #[account(13, signer, name = "trader", desc = "The trader claiming the seat")]
+    #[account(14, writable, name = "seat", desc = "The claiming trader's PDA seat account")]
+    #[account(15, writable, name = "evicted_trader")]
+    #[account(16, name = "evicted_seat", desc = "The evicted trader's PDA seat account, seeds are [b'seat', market_address, trader_address]")]
+    #[account(17, writable, name = "base_account", desc = "The evicted trader's associated token account for the base mint")]
+    #[account(18, writable, name = "quote_account", desc = "The evicted trader's associated token account for the quote mint")]
+    #[account(19, writable, name = "base_account_backup", desc = "Non-ATA token account for the base mint, in case the ATA owner is no longer the evicted trader")]
+    #[account(20, writable, name = "quote_account_backup", desc = "Non-ATA token account for the quote mint, in case the ATA owner is no longer the evicted trader")]
+    // If the seat manager has a heartbeat staleness window or a re-claim cooldown, the evicted trader's seat record follows the evicted trader accounts
+    // If the seat manager has a re-claim cooldown, the claiming trader's seat record follows
+    ClaimSeatWithEviction = 12,
+
+    /// Evict a single seat and claim a seat for the trader in the same instruction, authorized by the seat manager authority
+    #[account(0, name = "phoenix_program", desc = "Phoenix program")]
+    #[account(1, name = "log_authority", desc = "Phoenix log authority")]
+    #[account(2, writable, name = "market", desc = "This account holds the market state")]
+    #[account(3, writable, name = "seat_manager", desc = "The seat manager account must sign to evict and approve seats")]
+    #[account(4, writable, name = "seat_deposit_collector", desc = "Collects deposits for claiming new seats and refunds for evicting seats")]
+    #[account(5, name = "base_mint")]
+    #[account(6, name = "quote_mint")]
+    #[account(7, writable, name = "base_vault")]
+    #[account(8, writable, name = "quote_vault")]
+    #[account(9, name = "associated_token_account_program", desc = "Associated token account program")]
+    #[account(10, name = "token_program", desc = "Token program")]
+    #[account(11, name = "system_program", desc = "System program")]
+    #[account(12, writable, signer, name = "seat_manager_authority", desc = "The seat manager authority account must sign to claim seat")]
+    #[account(13, name = "trader", desc = "The trader claiming the seat")]
+    #[account(14, writable, name = "seat", desc = "The claiming trader's PDA seat account")]
+    #[account(15, writable, name = "evicted_trader")]
+    #[account(16, name = "evicted_seat", desc = "The evicted trader's PDA seat account, seeds are [b'seat', market_address, trader_address]")]
+    #[account(17, writable, name = "base_account", desc = "The evicted trader's associated token account for the base mint")]
+    #[account(18, writable, name = "quote_account", desc = "The evicted trader's associated token account for the quote mint")]
+    #[account(19, writable, name = "base_account_backup", desc = "Non-ATA token account for the base mint, in case the ATA owner is no longer the evicted trader")]
+    #[account(20, writable, name = "quote_account_backup", desc = "Non-ATA token account for the quote mint, in case the ATA owner is no longer the evicted trader")]
+    // If the seat manager has a heartbeat staleness window or a re-claim cooldown, the evicted trader's seat record follows the evicted trader accounts
+    ClaimSeatWithEvictionAuthorized = 13,
+
+    /// Join the FIFO waitlist for a seat on a full market
+    #[account(0, name = "phoenix_program", desc = "Phoenix program")]
+    #[account(1, name = "log_authority", desc = "Phoenix log authority")]
+    #[account(2, writable, name = "market", desc = "This account holds the market state")]
+    #[account(3, writable, name = "seat_manager", desc = "The seat manager account must sign to request the seat")]
+    #[account(4, writable, name = "seat_deposit_collector", desc = "Collects deposits for claiming new seats and refunds for evicting seats")]
+    #[account(5, writable, name = "seat_waitlist", desc = "The market's seat waitlist, seeds are [market_address, b'waitlist']")]
+    #[account(6, signer, name = "trader")]
+    #[account(7, writable, signer, name = "payer")]
+    #[account(8, writable, name = "seat")]
+    #[account(9, name = "system_program", desc = "System program")]
+    #[account(10, writable, optional, name = "seat_record", desc = "The trader's seat record, required while the seat manager has a re-claim cooldown, seeds are [market_address, trader_address, b'record']")]
+    JoinSeatWaitlist = 14,
+
+    /// Leave the seat waitlist and get the deposit back
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "seat_deposit_collector", desc = "Collects deposits for claiming new seats and refunds for evicting seats")]
+    #[account(3, writable, name = "seat_waitlist", desc = "The market's seat waitlist, seeds are [market_address, b'waitlist']")]
+    #[account(4, writable, signer, name = "trader")]
+    #[account(5, name = "system_program", desc = "System program")]
+    LeaveSeatWaitlist = 15,
+
+    /// Set the seat auction's reserve price and round duration
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "seat_auction", desc = "The market's seat auction, seeds are [market_address, b'auction']")]
+    #[account(3, writable, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to configure the seat auction")]
+    #[account(4, name = "treasury", desc = "The market's treasury, seeds are [market_address, b'treasury']")]
+    #[account(5, name = "system_program", desc = "System program")]
+    ConfigureSeatAuction = 16,
+
+    /// Bid lamports for a seat on a full market, refunding the outbid bidder
+    #[account(0, name = "phoenix_program", desc = "Phoenix program")]
+    #[account(1, name = "log_authority", desc = "Phoenix log authority")]
+    #[account(2, writable, name = "market", desc = "This account holds the market state")]
+    #[account(3, name = "seat_manager", desc = "The seat manager account must sign to request the seat")]
+    #[account(4, writable, name = "seat_auction", desc = "The market's seat auction, seeds are [market_address, b'auction']")]
+    #[account(5, writable, signer, name = "bidder")]
+    #[account(6, writable, name = "seat", desc = "The bidder's PDA seat account")]
+    #[account(7, writable, name = "previous_bidder", desc = "The current highest bidder, who is refunded when outbid")]
+    #[account(8, name = "system_program", desc = "System program")]
+    #[account(9, writable, optional, name = "seat_record", desc = "The bidder's seat record, required while the seat manager has a re-claim cooldown, seeds are [market_address, trader_address, b'record']")]
+    BidForSeat = 17,
+
+    /// Settle a finished seat auction round by evicting the lowest-priority evictable seat and approving the winner's seat
+    #[account(0, name = "phoenix_program", desc = "Phoenix program")]
+    #[account(1, name = "log_authority", desc = "Phoenix log authority")]
+    #[account(2, writable, name = "market", desc = "This account holds the market state")]
+    #[account(3, name = "seat_manager", desc = "The seat manager account must sign to evict and approve seats")]
+    #[account(4, writable, name = "seat_deposit_collector", desc = "Collects deposits for claiming new seats and refunds for evicting seats")]
+    #[account(5, name = "base_mint")]
+    #[account(6, name = "quote_mint")]
+    #[account(7, writable, name = "base_vault")]
+    #[account(8, writable, name = "quote_vault")]
+    #[account(9, name = "associated_token_account_program", desc = "Associated token account program")]
+    #[account(10, name = "token_program", desc = "Token program")]
+    #[account(11, name = "system_program", desc = "System program")]
+    #[account(12, writable, signer, name = "signer")]
+    #[account(13, writable, name = "seat_auction", desc = "The market's seat auction, seeds are [market_address, b'auction']")]
+    #[account(14, writable, name = "treasury", desc = "The market's treasury, seeds are [market_address, b'treasury']")]
+    #[account(15, writable, name = "winner", desc = "The highest bidder")]
+    #[account(16, writable, name = "winner_seat", desc = "The highest bidder's PDA seat account")]
+    // The evicted trader accounts are only required if the market is full
+    #[account(17, writable, name = "evicted_trader")]
+    #[account(18, name = "evicted_seat", desc = "The evicted trader's PDA seat account, seeds are [b'seat', market_address, trader_address]")]
+    #[account(19, writable, name = "base_account", desc = "The evicted trader's associated token account for the base mint")]
+    #[account(20, writable, name = "quote_account", desc = "The evicted trader's associated token account for the quote mint")]
+    #[account(21, writable, name = "base_account_backup", desc = "Non-ATA token account for the base mint, in case the ATA owner is no longer the evicted trader")]
+    #[account(22, writable, name = "quote_account_backup", desc = "Non-ATA token account for the quote mint, in case the ATA owner is no longer the evicted trader")]
+    // If the seat manager has a heartbeat staleness window or a re-claim cooldown, the evicted trader's seat record follows the evicted trader accounts.
+    // The seat records of lower-priority traders whose seats are protected by a recent heartbeat follow
+    SettleSeatAuction = 18,
+
+    /// Withdraw lamports from the market's treasury
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "treasury", desc = "The market's treasury, seeds are [market_address, b'treasury']")]
+    #[account(3, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to withdraw from the treasury")]
+    #[account(4, writable, name = "destination", desc = "Receives the withdrawn lamports")]
+    #[account(5, name = "system_program", desc = "System program")]
+    WithdrawTreasury = 19,
+
+    /// Set the number of free lots below which a seat with no locked lots can be evicted by anyone
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to set the dust threshold")]
+    SetDustThreshold = 20,
+
+    /// Set the eviction policy that decides who can evict which seats
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to set the eviction policy")]
+    SetEvictionPolicy = 21,
+
+    /// Disable or re-enable permissionless seat evictions and permissionless seat claims
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to change permissionless access")]
+    SetPermissionlessAccess = 22,
+
+    /// Record that the trader is active by updating the last active slot in the trader's seat record
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_record", desc = "The trader's seat record, seeds are [market_address, trader_address, b'record']")]
+    #[account(2, writable, signer, name = "trader", desc = "The trader pays for the seat record on the first heartbeat")]
+    #[account(3, name = "system_program", desc = "System program")]
+    Heartbeat = 23,
+
+    /// Set the number of slots after a trader's last heartbeat before their seat can be evicted. The authority is held to the window once the market is full
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to set the heartbeat staleness window")]
+    SetHeartbeatStalenessWindow = 24,
+
+    /// Set the number of slots after an eviction during which the evicted trader can only claim a seat through the authority
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to set the re-claim cooldown")]
+    SetReclaimCooldown = 25,
+
+    /// Set the number of seats kept free for DMMs and authority claims
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to set the number of reserved seats")]
+    SetReservedSeats = 26,
+
+    /// Set the slot until which only the authority and pre-registered DMMs can claim seats
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to set the launch phase end slot")]
+    SetLaunchPhaseEndSlot = 27,
+
+    /// Claim a seat for the trader and add them as a DMM in the same instruction
+    #[account(0, name = "phoenix_program", desc = "Phoenix program")]
+    #[account(1, name = "log_authority", desc = "Phoenix log authority")]
+    #[account(2, writable, name = "market", desc = "This account holds the market state")]
+    #[account(3, writable, name = "seat_manager", desc = "The seat manager account is the market authority")]
+    #[account(4, writable, name = "seat_deposit_collector", desc = "Collects deposits for claiming new seats and refunds for evicting seats")]
+    #[account(5, name = "trader")]
+    #[account(6, signer, writable, name = "seat_manager_authority", desc = "The seat manager authority account must sign to claim the seat and designate the trader")]
+    #[account(7, writable, name = "seat")]
+    #[account(8, name = "system_program", desc = "System program")]
+    ClaimSeatAndDesignate = 28,
+
+    /// Remove DMMs that no longer have a seat on the market. Anyone can call this
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    SyncDmms = 29,
+
+    /// Apply to become a DMM. The application is approved or rejected by the seat manager authority
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "dmm_application", desc = "The trader's DMM application, seeds are [market_address, trader_address, b'dmm_application']")]
+    #[account(3, writable, signer, name = "trader", desc = "The trader pays for the DMM application")]
+    #[account(4, name = "system_program", desc = "System program")]
+    ApplyForDmm = 30,
+
+    /// Approve a trader's DMM application and add the trader as a DMM
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "dmm_application", desc = "The trader's DMM application, closed after approval")]
+    #[account(3, writable, name = "trader", desc = "The trader receives the DMM application rent")]
+    #[account(4, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to approve the DMM application")]
+    ApproveDmmApplication = 31,
+
+    /// Reject a trader's DMM application
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "dmm_application", desc = "The trader's DMM application, closed after rejection")]
+    #[account(3, writable, name = "trader", desc = "The trader receives the DMM application rent")]
+    #[account(4, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to reject the DMM application")]
+    RejectDmmApplication = 32,
+
+    /// Remove the signing trader from the DMMs
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "trader", desc = "The DMM resigning")]
+    ResignDmm = 33,
+
+    /// Create the market's multisig. The authority can then hand itself to the multisig by naming it as the successor
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "multisig", desc = "The multisig account, seeds are [market_address, b'multisig']")]
+    #[account(3, name = "seat_manager_authority", desc = "The seat manager authority must approve creating the multisig")]
+    #[account(4, writable, signer, name = "payer", desc = "Pays for the multisig account")]
+    #[account(5, name = "system_program", desc = "System program")]
+    CreateMultisig = 34,
+
+    /// Replace the multisig's signers and threshold. The multisig signers follow as extra signing accounts
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "multisig", desc = "The multisig account, seeds are [market_address, b'multisig']")]
+    UpdateMultisig = 35,
+
+    /// Assign a role to a key, or revoke it by assigning the default pubkey
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to assign or revoke roles")]
+    SetRole = 36,
+
+    /// Set the number of slots that fee recipient and successor changes wait in the queue. Decreases must themselves be queued
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to set the timelock delay")]
+    SetTimelockDelay = 37,
+
+    /// Queue a timelocked action, executable by anyone once the timelock delay has passed
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "pending_action", desc = "The pending action, seeds are [market_address, b'pending_action', action_discriminant]")]
+    #[account(3, signer, name = "seat_manager_authority", desc = "The seat manager authority, or the fee admin for fee recipient changes, must sign to queue the action")]
+    #[account(4, writable, signer, name = "payer", desc = "Pays for the pending action, and receives the rent when it is executed or cancelled")]
+    #[account(5, name = "system_program", desc = "System program")]
+    QueueTimelockedAction = 38,
+
+    /// Cancel a queued timelocked action
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "pending_action", desc = "The pending action, seeds are [market_address, b'pending_action', action_discriminant]")]
+    #[account(3, signer, name = "seat_manager_authority", desc = "The seat manager authority, or the fee admin for fee recipient changes, must sign to cancel the action")]
+    #[account(4, writable, name = "rent_receiver", desc = "The payer of the pending action")]
+    CancelTimelockedAction = 39,
+
+    /// Execute a queued timelocked action after its delay. Anyone can call this.
+    /// The accounts of the instruction that the action replaces follow, without requiring its authority to sign.
+    /// Its seat manager account must be writable. Actions queued by a previous authority or fee admin cannot be executed
+    #[account(0, writable, name = "pending_action", desc = "The pending action, seeds are [market_address, b'pending_action', action_discriminant]")]
+    #[account(1, writable, name = "rent_receiver", desc = "The payer of the pending action")]
+    ExecuteTimelockedAction = 40,
+
+    /// Cancel a pending successor nomination by resetting the successor to the current authority
+    #[account(0, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(1, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to cancel the successor")]
+    CancelSuccessor = 41,
+
+    /// Stage a bitmask of `SeatManagerCapability`s to renounce. A zero bitmask cancels the renunciation
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to renounce capabilities")]
+    InitiateRenounceCapabilities = 42,
+
+    /// Irrevocably renounce the staged capabilities. The bitmask must match the staged one
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to renounce capabilities")]
+    ConfirmRenounceCapabilities = 43,
+
+    /// Move the market to PostOnly or Paused as the guardian
+    #[account(0, name = "phoenix_program", desc = "Phoenix program")]
+    #[account(1, name = "log_authority", desc = "Phoenix log authority")]
+    #[account(2, writable, name = "market", desc = "This account holds the market state")]
+    #[account(3, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(4, signer, name = "guardian", desc = "The guardian must sign to pause the market")]
+    GuardianPauseMarket = 44,
+
+    /// Set or clear the guardian
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority must sign to set the guardian")]
+    SetGuardian = 45,
+
+    /// Create the market's admin history. Once it exists, every admin instruction must include it and is recorded in it
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, writable, name = "admin_history", desc = "The admin history account, seeds are [market_address, b'admin_history']")]
+    #[account(3, name = "seat_manager_authority", desc = "The seat manager authority must approve creating the admin history")]
+    #[account(4, writable, signer, name = "payer", desc = "Pays for the admin history account")]
+    #[account(5, name = "system_program", desc = "System program")]
+    CreateAdminHistory = 46,
+
+    /// Create an admin group. The creator is its first authority, and seat managers join it by naming it as their successor
+    #[account(0, writable, name = "admin_group", desc = "The admin group account, seeds are [creator, b'admin_group']")]
+    #[account(1, signer, name = "creator", desc = "The creator of the admin group")]
+    #[account(2, writable, signer, name = "payer", desc = "Pays for the admin group account")]
+    #[account(3, name = "system_program", desc = "System program")]
+    CreateAdminGroup = 47,
+
+    /// Name the successor of the admin group authority. The instruction data is the successor and an optional expiry slot, the nomination expires after a default window without one
+    #[account(0, writable, name = "admin_group", desc = "This account holds the admin group state")]
+    #[account(1, name = "admin_group_authority", desc = "The admin group authority must approve naming the successor")]
+    NameAdminGroupSuccessor = 48,
+
+    /// Claim the admin group authority, which rotates the authority of every market governed by the group
+    #[account(0, writable, name = "admin_group", desc = "This account holds the admin group state")]
+    #[account(1, name = "successor", desc = "The successor must approve claiming the admin group authority")]
+    ClaimAdminGroupAuthority = 49,
+
+    /// Create a DMM registry that seat managers can link to share a list of DMMs
+    #[account(0, writable, name = "dmm_registry", desc = "The DMM registry account, seeds are [creator, b'dmm_registry']")]
+    #[account(1, signer, name = "creator", desc = "The creator of the DMM registry")]
+    #[account(2, writable, signer, name = "payer", desc = "Pays for the DMM registry account")]
+    #[account(3, name = "system_program", desc = "System program")]
+    CreateDmmRegistry = 50,
+
+    /// Add a DMM to the registry, protecting them from eviction on every linked market
+    #[account(0, writable, name = "dmm_registry", desc = "This account holds the DMM registry state")]
+    #[account(1, name = "dmm_registry_authority", desc = "The DMM registry authority must approve adding a DMM")]
+    AddRegistryDmm = 51,
+
+    /// Remove a DMM from the registry
+    #[account(0, writable, name = "dmm_registry", desc = "This account holds the DMM registry state")]
+    #[account(1, name = "dmm_registry_authority", desc = "The DMM registry authority must approve removing a DMM")]
+    RemoveRegistryDmm = 52,
+
+    /// Link the seat manager to a DMM registry, or unlink it. Once linked, evictions must include the registry
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "seat_manager", desc = "This account holds the seat manager state")]
+    #[account(2, signer, name = "seat_manager_authority", desc = "The seat manager authority or the DMM manager must sign to link a DMM registry")]
+    #[account(3, name = "dmm_registry", desc = "The DMM registry to link. Omitted when unlinking")]
+    SetDmmRegistry = 53,
+
+    /// Withdraw the signing trader's pending DMM application
+    #[account(0, name = "market", desc = "This account holds the market state")]
+    #[account(1, writable, name = "dmm_application", desc = "The trader's DMM application, closed after withdrawal")]
+    #[account(2, writable, signer, name = "trader", desc = "The trader receives the DMM application rent")]
+    WithdrawDmmApplication = 54,
 }
 
 impl SeatManagerInstruction {
@@ -126,7 +501,7 @@ impl SeatManagerInstruction {
 
 #[test]
 fn test_instruction_serialization() {
-    for i in 0..=10 {
+    for i in 0..=54 {
         let instruction = SeatManagerInstruction::try_from(i).unwrap();
         assert_eq!(instruction as u8, i);
     }
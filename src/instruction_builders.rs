@@ -11,8 +11,18 @@ use solana_program::{
 use spl_associated_token_account::get_associated_token_address;
 
 use crate::{
-    get_seat_deposit_collector_address, get_seat_manager_address,
+    capability::SeatManagerCapability,
+    eviction_policy::EvictionPolicy,
+    get_admin_group_address, get_admin_history_address, get_dmm_application_address,
+    get_dmm_registry_address, get_multisig_address, get_pending_action_address,
+    get_seat_auction_address, get_seat_deposit_collector_address, get_seat_manager_address,
+    get_seat_record_address, get_seat_waitlist_address, get_treasury_address,
     instruction::SeatManagerInstruction,
+    pending_action::TimelockedAction,
+    processor::{
+        ConfigureSeatAuctionParams, MultisigParams, PermissionlessAccessParams, SetRoleParams,
+    },
+    role::SeatManagerRole,
 };
 
 pub struct EvictTraderAccountBackup {
@@ -27,6 +37,30 @@ pub fn create_evict_seat_instruction(
     quote_mint: &Pubkey,
     signer: &Pubkey,
     traders: Vec<EvictTraderAccountBackup>,
+) -> Instruction {
+    evict_seat_instruction(market, base_mint, quote_mint, signer, traders, false)
+}
+
+/// Use this instead of `create_evict_seat_instruction` when the seat manager has a heartbeat staleness window or a re-claim cooldown,
+/// which requires each trader's accounts to end with their seat record.
+pub fn create_evict_seat_with_seat_records_instruction(
+    market: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    signer: &Pubkey,
+    traders: Vec<EvictTraderAccountBackup>,
+) -> Instruction {
+    evict_seat_instruction(market, base_mint, quote_mint, signer, traders, true)
+}
+
+/// Panics if there are more than 255 traders
+fn evict_seat_instruction(
+    market: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    signer: &Pubkey,
+    traders: Vec<EvictTraderAccountBackup>,
+    with_seat_records: bool,
 ) -> Instruction {
     let (base_vault, _) = get_vault_address(market, base_mint);
     let (quote_vault, _) = get_vault_address(market, quote_mint);
@@ -50,33 +84,176 @@ pub fn create_evict_seat_instruction(
     ];
 
     for trader_accounts in traders.iter() {
-        let base_account = get_associated_token_address(&trader_accounts.trader_pubkey, base_mint);
-        let quote_account =
-            get_associated_token_address(&trader_accounts.trader_pubkey, quote_mint);
-        let (seat, _) = get_seat_address(market, &trader_accounts.trader_pubkey);
-        accounts.push(AccountMeta::new(trader_accounts.trader_pubkey, false));
-        accounts.push(AccountMeta::new(seat, false));
-        accounts.push(AccountMeta::new(base_account, false));
-        accounts.push(AccountMeta::new(quote_account, false));
-
-        for backup_account in [
-            trader_accounts.base_token_account_backup,
-            trader_accounts.quote_token_account_backup,
+        accounts.extend(get_evict_trader_account_metas(
+            market,
+            base_mint,
+            quote_mint,
+            trader_accounts,
+        ));
+        if with_seat_records {
+            let (seat_record, _) = get_seat_record_address(market, &trader_accounts.trader_pubkey);
+            accounts.push(AccountMeta::new(seat_record, false));
+        }
+    }
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [
+            SeatManagerInstruction::EvictSeat.to_vec(),
+            u8::try_from(traders.len())
+                .expect("At most 255 traders can be evicted in one instruction")
+                .try_to_vec()
+                .unwrap(),
         ]
-        .iter()
-        {
-            if backup_account.is_some() {
-                accounts.push(AccountMeta::new(backup_account.unwrap(), false));
-            } else {
-                accounts.push(AccountMeta::new_readonly(Pubkey::default(), false));
-            }
+        .concat(),
+    }
+}
+
+/// Evicts the given traders and approves the seats of the traders at the front of the seat waitlist.
+/// `waitlisted_traders` should list the traders at the front of the waitlist, in order, one per eviction,
+/// including the traders whose seats are no longer pending, who are dropped from the waitlist and refunded.
+pub fn create_evict_seat_with_waitlist_instruction(
+    market: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    signer: &Pubkey,
+    traders: Vec<EvictTraderAccountBackup>,
+    waitlisted_traders: Vec<Pubkey>,
+) -> Instruction {
+    let mut instruction =
+        create_evict_seat_instruction(market, base_mint, quote_mint, signer, traders);
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (seat_waitlist, _) = get_seat_waitlist_address(market);
+    // The seat manager tracks the number of waitlisted traders, so it must be writable
+    instruction.accounts[3] = AccountMeta::new(seat_manager, false);
+    instruction
+        .accounts
+        .push(AccountMeta::new(seat_waitlist, false));
+    for trader in waitlisted_traders.iter() {
+        let (seat, _) = get_seat_address(market, trader);
+        instruction.accounts.push(AccountMeta::new(seat, false));
+        instruction.accounts.push(AccountMeta::new(*trader, false));
+    }
+    instruction
+}
+
+fn get_evict_trader_account_metas(
+    market: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    trader_accounts: &EvictTraderAccountBackup,
+) -> Vec<AccountMeta> {
+    let base_account = get_associated_token_address(&trader_accounts.trader_pubkey, base_mint);
+    let quote_account = get_associated_token_address(&trader_accounts.trader_pubkey, quote_mint);
+    let (seat, _) = get_seat_address(market, &trader_accounts.trader_pubkey);
+    let mut accounts = vec![
+        AccountMeta::new(trader_accounts.trader_pubkey, false),
+        AccountMeta::new(seat, false),
+        AccountMeta::new(base_account, false),
+        AccountMeta::new(quote_account, false),
+    ];
+
+    for backup_account in [
+        trader_accounts.base_token_account_backup,
+        trader_accounts.quote_token_account_backup,
+    ]
+    .iter()
+    {
+        if backup_account.is_some() {
+            accounts.push(AccountMeta::new(backup_account.unwrap(), false));
+        } else {
+            accounts.push(AccountMeta::new_readonly(Pubkey::default(), false));
         }
     }
+    accounts
+}
+
+/// If the seat manager has a heartbeat staleness window or a re-claim cooldown, the evicted trader's seat record must be appended to the accounts,
+/// followed by the claiming trader's seat record if the seat manager has a re-claim cooldown.
+pub fn create_claim_seat_with_eviction_instruction(
+    trader: &Pubkey,
+    market: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    evicted_trader: EvictTraderAccountBackup,
+) -> Instruction {
+    claim_seat_with_eviction_instruction(
+        trader,
+        market,
+        trader,
+        base_mint,
+        quote_mint,
+        evicted_trader,
+        SeatManagerInstruction::ClaimSeatWithEviction,
+    )
+}
+
+pub fn create_claim_seat_with_eviction_authorized_instruction(
+    trader: &Pubkey,
+    market: &Pubkey,
+    authority: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    evicted_trader: EvictTraderAccountBackup,
+) -> Instruction {
+    claim_seat_with_eviction_instruction(
+        trader,
+        market,
+        authority,
+        base_mint,
+        quote_mint,
+        evicted_trader,
+        SeatManagerInstruction::ClaimSeatWithEvictionAuthorized,
+    )
+}
+
+fn claim_seat_with_eviction_instruction(
+    trader: &Pubkey,
+    market: &Pubkey,
+    payer: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    evicted_trader: EvictTraderAccountBackup,
+    instruction: SeatManagerInstruction,
+) -> Instruction {
+    let (base_vault, _) = get_vault_address(market, base_mint);
+    let (quote_vault, _) = get_vault_address(market, quote_mint);
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (seat_deposit_collector, _) = get_seat_deposit_collector_address(market);
+    let (seat, _) = get_seat_address(market, trader);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(phoenix::id(), false),
+        AccountMeta::new_readonly(phoenix_log_authority::id(), false),
+        AccountMeta::new(*market, false),
+        AccountMeta::new(seat_manager, false),
+        AccountMeta::new(seat_deposit_collector, false),
+        AccountMeta::new_readonly(*base_mint, false),
+        AccountMeta::new_readonly(*quote_mint, false),
+        AccountMeta::new(base_vault, false),
+        AccountMeta::new(quote_vault, false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(
+            *trader,
+            instruction == SeatManagerInstruction::ClaimSeatWithEviction,
+        ),
+        AccountMeta::new(seat, false),
+    ];
+    accounts.extend(get_evict_trader_account_metas(
+        market,
+        base_mint,
+        quote_mint,
+        &evicted_trader,
+    ));
 
     Instruction {
         program_id: crate::id(),
         accounts,
-        data: SeatManagerInstruction::EvictSeat.to_vec(),
+        data: instruction.to_vec(),
     }
 }
 
@@ -115,6 +292,36 @@ pub fn create_name_seat_manager_successor_instruction(
     }
 }
 
+/// Names a successor whose nomination expires after `expiry_slot` instead of the default window
+pub fn create_name_seat_manager_successor_with_expiry_instruction(
+    authority: &Pubkey,
+    market: &Pubkey,
+    successor: &Pubkey,
+    expiry_slot: u64,
+) -> Instruction {
+    let mut instruction =
+        create_name_seat_manager_successor_instruction(authority, market, successor);
+    instruction
+        .data
+        .extend_from_slice(&expiry_slot.try_to_vec().unwrap());
+    instruction
+}
+
+pub fn create_cancel_seat_manager_successor_instruction(
+    authority: &Pubkey,
+    market: &Pubkey,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: SeatManagerInstruction::CancelSuccessor.to_vec(),
+    }
+}
+
 pub fn create_claim_seat_manager_authority_instruction(
     market: &Pubkey,
     successor: &Pubkey,
@@ -134,6 +341,7 @@ pub fn create_claim_seat_instruction(trader: &Pubkey, market: &Pubkey) -> Instru
     let (seat_manager, _) = get_seat_manager_address(market);
     let (seat_deposit_collector, _) = get_seat_deposit_collector_address(market);
     let (seat, _) = get_seat_address(market, trader);
+    let (seat_record, _) = get_seat_record_address(market, trader);
     Instruction {
         program_id: crate::id(),
         accounts: vec![
@@ -146,6 +354,7 @@ pub fn create_claim_seat_instruction(trader: &Pubkey, market: &Pubkey) -> Instru
             AccountMeta::new(*trader, true),
             AccountMeta::new(seat, false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(seat_record, false),
         ],
         data: SeatManagerInstruction::ClaimSeat.to_vec(),
     }
@@ -319,3 +528,1081 @@ pub fn create_confirm_renounce_seat_manager_authority_instruction(
         data: SeatManagerInstruction::ConfirmRenounceSeatManagerAuthority.to_vec(),
     }
 }
+
+pub fn create_join_seat_waitlist_instruction(trader: &Pubkey, market: &Pubkey) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (seat_deposit_collector, _) = get_seat_deposit_collector_address(market);
+    let (seat_waitlist, _) = get_seat_waitlist_address(market);
+    let (seat, _) = get_seat_address(market, trader);
+    let (seat_record, _) = get_seat_record_address(market, trader);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(phoenix::id(), false),
+            AccountMeta::new_readonly(phoenix_log_authority::id(), false),
+            AccountMeta::new(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new(seat_deposit_collector, false),
+            AccountMeta::new(seat_waitlist, false),
+            AccountMeta::new_readonly(*trader, true),
+            AccountMeta::new(*trader, true),
+            AccountMeta::new(seat, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(seat_record, false),
+        ],
+        data: SeatManagerInstruction::JoinSeatWaitlist.to_vec(),
+    }
+}
+
+pub fn create_leave_seat_waitlist_instruction(trader: &Pubkey, market: &Pubkey) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (seat_deposit_collector, _) = get_seat_deposit_collector_address(market);
+    let (seat_waitlist, _) = get_seat_waitlist_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new(seat_deposit_collector, false),
+            AccountMeta::new(seat_waitlist, false),
+            AccountMeta::new(*trader, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: SeatManagerInstruction::LeaveSeatWaitlist.to_vec(),
+    }
+}
+
+pub fn create_configure_seat_auction_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    reserve_price: u64,
+    duration_in_slots: u64,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (seat_auction, _) = get_seat_auction_address(market);
+    let (treasury, _) = get_treasury_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new(seat_auction, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [
+            SeatManagerInstruction::ConfigureSeatAuction.to_vec(),
+            ConfigureSeatAuctionParams {
+                reserve_price,
+                duration_in_slots,
+            }
+            .try_to_vec()
+            .unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+/// `previous_bidder` is the current highest bidder, or `None` if no round is in progress.
+pub fn create_bid_for_seat_instruction(
+    market: &Pubkey,
+    bidder: &Pubkey,
+    previous_bidder: Option<Pubkey>,
+    amount: u64,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (seat_auction, _) = get_seat_auction_address(market);
+    let (seat, _) = get_seat_address(market, bidder);
+    let (seat_record, _) = get_seat_record_address(market, bidder);
+    let previous_bidder = match previous_bidder {
+        Some(previous_bidder) => AccountMeta::new(previous_bidder, false),
+        None => AccountMeta::new_readonly(Pubkey::default(), false),
+    };
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(phoenix::id(), false),
+            AccountMeta::new_readonly(phoenix_log_authority::id(), false),
+            AccountMeta::new(*market, false),
+            AccountMeta::new_readonly(seat_manager, false),
+            AccountMeta::new(seat_auction, false),
+            AccountMeta::new(*bidder, true),
+            AccountMeta::new(seat, false),
+            previous_bidder,
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(seat_record, false),
+        ],
+        data: [
+            SeatManagerInstruction::BidForSeat.to_vec(),
+            amount.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+/// `evicted_trader` must hold the seat returned by `get_lowest_priority_evictable_trader`, and can be `None` if the market is no longer full.
+/// If the seat manager has a heartbeat staleness window or a re-claim cooldown, the evicted trader's seat record must be appended to the accounts.
+pub fn create_settle_seat_auction_instruction(
+    market: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    signer: &Pubkey,
+    winner: &Pubkey,
+    evicted_trader: Option<EvictTraderAccountBackup>,
+) -> Instruction {
+    settle_seat_auction_instruction(
+        market,
+        base_mint,
+        quote_mint,
+        signer,
+        winner,
+        evicted_trader,
+        vec![],
+    )
+}
+
+/// For seat managers with a heartbeat staleness window. The evicted trader's seat record is appended, followed by the seat records of
+/// `protected_traders`, the traders with a lower priority than `evicted_trader` whose seats are protected by a recent heartbeat.
+pub fn create_settle_seat_auction_with_protected_traders_instruction(
+    market: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    signer: &Pubkey,
+    winner: &Pubkey,
+    evicted_trader: Option<EvictTraderAccountBackup>,
+    protected_traders: &[Pubkey],
+) -> Instruction {
+    let seat_record_traders = evicted_trader
+        .iter()
+        .map(|evicted_trader| evicted_trader.trader_pubkey)
+        .chain(protected_traders.iter().copied())
+        .collect();
+    settle_seat_auction_instruction(
+        market,
+        base_mint,
+        quote_mint,
+        signer,
+        winner,
+        evicted_trader,
+        seat_record_traders,
+    )
+}
+
+fn settle_seat_auction_instruction(
+    market: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    signer: &Pubkey,
+    winner: &Pubkey,
+    evicted_trader: Option<EvictTraderAccountBackup>,
+    seat_record_traders: Vec<Pubkey>,
+) -> Instruction {
+    let (base_vault, _) = get_vault_address(market, base_mint);
+    let (quote_vault, _) = get_vault_address(market, quote_mint);
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (seat_deposit_collector, _) = get_seat_deposit_collector_address(market);
+    let (seat_auction, _) = get_seat_auction_address(market);
+    let (treasury, _) = get_treasury_address(market);
+    let (winner_seat, _) = get_seat_address(market, winner);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(phoenix::id(), false),
+        AccountMeta::new_readonly(phoenix_log_authority::id(), false),
+        AccountMeta::new(*market, false),
+        AccountMeta::new_readonly(seat_manager, false),
+        AccountMeta::new(seat_deposit_collector, false),
+        AccountMeta::new_readonly(*base_mint, false),
+        AccountMeta::new_readonly(*quote_mint, false),
+        AccountMeta::new(base_vault, false),
+        AccountMeta::new(quote_vault, false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(*signer, true),
+        AccountMeta::new(seat_auction, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new(*winner, false),
+        AccountMeta::new(winner_seat, false),
+    ];
+    if let Some(evicted_trader) = evicted_trader {
+        accounts.extend(get_evict_trader_account_metas(
+            market,
+            base_mint,
+            quote_mint,
+            &evicted_trader,
+        ));
+    }
+    accounts.extend(
+        seat_record_traders
+            .iter()
+            .map(|trader| AccountMeta::new(get_seat_record_address(market, trader).0, false)),
+    );
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: SeatManagerInstruction::SettleSeatAuction.to_vec(),
+    }
+}
+
+pub fn create_withdraw_treasury_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (treasury, _) = get_treasury_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new_readonly(seat_manager, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [
+            SeatManagerInstruction::WithdrawTreasury.to_vec(),
+            amount.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_set_dust_threshold_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    dust_threshold_in_lots: u64,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetDustThreshold.to_vec(),
+            dust_threshold_in_lots.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_set_eviction_policy_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    eviction_policy: EvictionPolicy,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetEvictionPolicy.to_vec(),
+            eviction_policy.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_set_permissionless_access_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    disable_permissionless_eviction: bool,
+    disable_permissionless_claims: bool,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetPermissionlessAccess.to_vec(),
+            PermissionlessAccessParams {
+                disable_permissionless_eviction,
+                disable_permissionless_claims,
+            }
+            .try_to_vec()
+            .unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_heartbeat_instruction(market: &Pubkey, trader: &Pubkey) -> Instruction {
+    let (seat_record, _) = get_seat_record_address(market, trader);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_record, false),
+            AccountMeta::new(*trader, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: SeatManagerInstruction::Heartbeat.to_vec(),
+    }
+}
+
+pub fn create_set_heartbeat_staleness_window_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    heartbeat_staleness_window_in_slots: u64,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetHeartbeatStalenessWindow.to_vec(),
+            heartbeat_staleness_window_in_slots.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_set_reclaim_cooldown_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    reclaim_cooldown_in_slots: u64,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetReclaimCooldown.to_vec(),
+            reclaim_cooldown_in_slots.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_set_reserved_seats_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    num_reserved_seats: u64,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetReservedSeats.to_vec(),
+            num_reserved_seats.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_set_launch_phase_end_slot_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    launch_phase_end_slot: u64,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetLaunchPhaseEndSlot.to_vec(),
+            launch_phase_end_slot.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_claim_seat_and_designate_instruction(
+    trader: &Pubkey,
+    market: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (seat_deposit_collector, _) = get_seat_deposit_collector_address(market);
+    let (seat, _) = get_seat_address(market, trader);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(phoenix::id(), false),
+            AccountMeta::new_readonly(phoenix_log_authority::id(), false),
+            AccountMeta::new(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new(seat_deposit_collector, false),
+            AccountMeta::new_readonly(*trader, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(seat, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: SeatManagerInstruction::ClaimSeatAndDesignate.to_vec(),
+    }
+}
+
+pub fn create_sync_dmms_instruction(market: &Pubkey) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+        ],
+        data: SeatManagerInstruction::SyncDmms.to_vec(),
+    }
+}
+
+pub fn create_apply_for_dmm_instruction(market: &Pubkey, trader: &Pubkey) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (dmm_application, _) = get_dmm_application_address(market, trader);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new_readonly(seat_manager, false),
+            AccountMeta::new(dmm_application, false),
+            AccountMeta::new(*trader, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: SeatManagerInstruction::ApplyForDmm.to_vec(),
+    }
+}
+
+pub fn create_withdraw_dmm_application_instruction(
+    market: &Pubkey,
+    trader: &Pubkey,
+) -> Instruction {
+    let (dmm_application, _) = get_dmm_application_address(market, trader);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(dmm_application, false),
+            AccountMeta::new(*trader, true),
+        ],
+        data: SeatManagerInstruction::WithdrawDmmApplication.to_vec(),
+    }
+}
+
+pub fn create_approve_dmm_application_instruction(
+    market: &Pubkey,
+    trader: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    review_dmm_application_instruction(
+        market,
+        trader,
+        authority,
+        SeatManagerInstruction::ApproveDmmApplication,
+    )
+}
+
+pub fn create_reject_dmm_application_instruction(
+    market: &Pubkey,
+    trader: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    review_dmm_application_instruction(
+        market,
+        trader,
+        authority,
+        SeatManagerInstruction::RejectDmmApplication,
+    )
+}
+
+fn review_dmm_application_instruction(
+    market: &Pubkey,
+    trader: &Pubkey,
+    authority: &Pubkey,
+    instruction: SeatManagerInstruction,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (dmm_application, _) = get_dmm_application_address(market, trader);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new(dmm_application, false),
+            AccountMeta::new(*trader, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: instruction.to_vec(),
+    }
+}
+
+pub fn create_resign_dmm_instruction(market: &Pubkey, trader: &Pubkey) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*trader, true),
+        ],
+        data: SeatManagerInstruction::ResignDmm.to_vec(),
+    }
+}
+
+pub fn create_create_multisig_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    threshold: u64,
+    signers: Vec<Pubkey>,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (multisig, _) = get_multisig_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new_readonly(seat_manager, false),
+            AccountMeta::new(multisig, false),
+            AccountMeta::new_readonly(*authority, authority != payer),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [
+            SeatManagerInstruction::CreateMultisig.to_vec(),
+            MultisigParams { threshold, signers }.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_update_multisig_instruction(
+    market: &Pubkey,
+    approving_signers: &[Pubkey],
+    threshold: u64,
+    signers: Vec<Pubkey>,
+) -> Instruction {
+    let (multisig, _) = get_multisig_address(market);
+    let instruction = Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(multisig, false),
+        ],
+        data: [
+            SeatManagerInstruction::UpdateMultisig.to_vec(),
+            MultisigParams { threshold, signers }.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    };
+    with_multisig_approval(instruction, market, approving_signers)
+}
+
+pub fn create_create_admin_history_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (admin_history, _) = get_admin_history_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new(admin_history, false),
+            AccountMeta::new_readonly(*authority, authority != payer),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: SeatManagerInstruction::CreateAdminHistory.to_vec(),
+    }
+}
+
+/// Appends the market's admin history to an admin instruction, which is required once the admin history exists.
+pub fn with_admin_history(mut instruction: Instruction, market: &Pubkey) -> Instruction {
+    let (admin_history, _) = get_admin_history_address(market);
+    if !instruction
+        .accounts
+        .iter()
+        .any(|account| account.pubkey == admin_history)
+    {
+        instruction
+            .accounts
+            .push(AccountMeta::new(admin_history, false));
+    }
+    instruction
+}
+
+pub fn create_create_admin_group_instruction(creator: &Pubkey, payer: &Pubkey) -> Instruction {
+    let (admin_group, _) = get_admin_group_address(creator);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(admin_group, false),
+            AccountMeta::new_readonly(*creator, creator != payer),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: SeatManagerInstruction::CreateAdminGroup.to_vec(),
+    }
+}
+
+pub fn create_name_admin_group_successor_instruction(
+    admin_group: &Pubkey,
+    authority: &Pubkey,
+    successor: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(*admin_group, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::NameAdminGroupSuccessor.to_vec(),
+            successor.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+/// Names an admin group successor whose nomination expires after `expiry_slot` instead of the default window
+pub fn create_name_admin_group_successor_with_expiry_instruction(
+    admin_group: &Pubkey,
+    authority: &Pubkey,
+    successor: &Pubkey,
+    expiry_slot: u64,
+) -> Instruction {
+    let mut instruction =
+        create_name_admin_group_successor_instruction(admin_group, authority, successor);
+    instruction
+        .data
+        .extend_from_slice(&expiry_slot.try_to_vec().unwrap());
+    instruction
+}
+
+pub fn create_claim_admin_group_authority_instruction(
+    admin_group: &Pubkey,
+    successor: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(*admin_group, false),
+            AccountMeta::new_readonly(*successor, true),
+        ],
+        data: SeatManagerInstruction::ClaimAdminGroupAuthority.to_vec(),
+    }
+}
+
+pub fn create_create_dmm_registry_instruction(
+    creator: &Pubkey,
+    payer: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    let (dmm_registry, _) = get_dmm_registry_address(creator);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(dmm_registry, false),
+            AccountMeta::new_readonly(*creator, creator != payer),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [
+            SeatManagerInstruction::CreateDmmRegistry.to_vec(),
+            authority.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_add_registry_dmm_instruction(
+    dmm_registry: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+) -> Instruction {
+    update_dmm_registry_instruction(
+        dmm_registry,
+        authority,
+        trader,
+        SeatManagerInstruction::AddRegistryDmm,
+    )
+}
+
+pub fn create_remove_registry_dmm_instruction(
+    dmm_registry: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+) -> Instruction {
+    update_dmm_registry_instruction(
+        dmm_registry,
+        authority,
+        trader,
+        SeatManagerInstruction::RemoveRegistryDmm,
+    )
+}
+
+fn update_dmm_registry_instruction(
+    dmm_registry: &Pubkey,
+    authority: &Pubkey,
+    trader: &Pubkey,
+    instruction: SeatManagerInstruction,
+) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(*dmm_registry, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [instruction.to_vec(), trader.try_to_vec().unwrap()].concat(),
+    }
+}
+
+pub fn create_set_dmm_registry_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    dmm_registry: &Pubkey,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*market, false),
+        AccountMeta::new(seat_manager, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+    if *dmm_registry != Pubkey::default() {
+        accounts.push(AccountMeta::new_readonly(*dmm_registry, false));
+    }
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: [
+            SeatManagerInstruction::SetDmmRegistry.to_vec(),
+            dmm_registry.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_clear_dmm_registry_instruction(market: &Pubkey, authority: &Pubkey) -> Instruction {
+    create_set_dmm_registry_instruction(market, authority, &Pubkey::default())
+}
+
+/// Appends the seat manager's linked DMM registry to an eviction instruction, which is required once a registry is linked
+pub fn with_dmm_registry(mut instruction: Instruction, dmm_registry: &Pubkey) -> Instruction {
+    if !instruction
+        .accounts
+        .iter()
+        .any(|account| account.pubkey == *dmm_registry)
+    {
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(*dmm_registry, false));
+    }
+    instruction
+}
+
+/// Adapts an authority-gated instruction built for a single-key authority to an admin group authority.
+/// Pass the admin group address wherever the builder takes the authority. The admin group is unmarked as a signer,
+/// and the admin group and its authority are appended. Wrap the result with `with_multisig_approval` if the group's authority is a multisig.
+pub fn with_admin_group_approval(
+    mut instruction: Instruction,
+    admin_group: &Pubkey,
+    admin_group_authority: &Pubkey,
+) -> Instruction {
+    for account in instruction.accounts.iter_mut() {
+        if account.pubkey == *admin_group {
+            account.is_signer = false;
+        }
+    }
+    if !instruction
+        .accounts
+        .iter()
+        .any(|account| account.pubkey == *admin_group)
+    {
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(*admin_group, false));
+    }
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*admin_group_authority, true));
+    instruction
+}
+
+/// Adapts an authority-gated instruction built for a single-key authority to a multisig authority.
+/// Pass the multisig address wherever the builder takes the authority, or for builders where the authority pays,
+/// one of the approving signers. The multisig is unmarked as a signer, and the multisig and its approving signers are appended.
+pub fn with_multisig_approval(
+    mut instruction: Instruction,
+    market: &Pubkey,
+    approving_signers: &[Pubkey],
+) -> Instruction {
+    let (multisig, _) = get_multisig_address(market);
+    for account in instruction.accounts.iter_mut() {
+        if account.pubkey == multisig {
+            account.is_signer = false;
+        }
+    }
+    if !instruction
+        .accounts
+        .iter()
+        .any(|account| account.pubkey == multisig)
+    {
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(multisig, false));
+    }
+    instruction.accounts.extend(
+        approving_signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+    instruction
+}
+
+pub fn create_set_role_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    role: SeatManagerRole,
+    holder: &Pubkey,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetRole.to_vec(),
+            SetRoleParams {
+                role,
+                holder: *holder,
+            }
+            .try_to_vec()
+            .unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_revoke_role_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    role: SeatManagerRole,
+) -> Instruction {
+    create_set_role_instruction(market, authority, role, &Pubkey::default())
+}
+
+pub fn create_set_timelock_delay_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    timelock_delay_in_slots: u64,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetTimelockDelay.to_vec(),
+            timelock_delay_in_slots.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_queue_timelocked_action_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    action: TimelockedAction,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (pending_action, _) = get_pending_action_address(market, &action);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new(pending_action, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [
+            SeatManagerInstruction::QueueTimelockedAction.to_vec(),
+            action.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_cancel_timelocked_action_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    rent_receiver: &Pubkey,
+    action: TimelockedAction,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (pending_action, _) = get_pending_action_address(market, &action);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new(pending_action, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*rent_receiver, false),
+        ],
+        data: [
+            SeatManagerInstruction::CancelTimelockedAction.to_vec(),
+            action.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+/// `action_instruction` is the instruction that the action replaces, e.g. from `create_change_market_fee_recipient_instruction`.
+/// Its authority can be any account, since it does not need to sign.
+pub fn create_execute_timelocked_action_instruction(
+    market: &Pubkey,
+    action: &TimelockedAction,
+    rent_receiver: &Pubkey,
+    action_instruction: Instruction,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    let (pending_action, _) = get_pending_action_address(market, action);
+    let mut accounts = vec![
+        AccountMeta::new(pending_action, false),
+        AccountMeta::new(*rent_receiver, false),
+    ];
+    accounts.extend(
+        action_instruction
+            .accounts
+            .into_iter()
+            .map(|account| AccountMeta {
+                is_signer: false,
+                // Executing the action updates the seat manager's count of pending actions
+                is_writable: account.is_writable || account.pubkey == seat_manager,
+                ..account
+            }),
+    );
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: SeatManagerInstruction::ExecuteTimelockedAction.to_vec(),
+    }
+}
+
+pub fn create_initiate_renounce_capabilities_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    capabilities: &[SeatManagerCapability],
+) -> Instruction {
+    renounce_capabilities_instruction(
+        market,
+        authority,
+        capabilities,
+        SeatManagerInstruction::InitiateRenounceCapabilities,
+    )
+}
+
+/// `capabilities` must match the ones passed to `create_initiate_renounce_capabilities_instruction`
+pub fn create_confirm_renounce_capabilities_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    capabilities: &[SeatManagerCapability],
+) -> Instruction {
+    renounce_capabilities_instruction(
+        market,
+        authority,
+        capabilities,
+        SeatManagerInstruction::ConfirmRenounceCapabilities,
+    )
+}
+
+fn renounce_capabilities_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    capabilities: &[SeatManagerCapability],
+    instruction: SeatManagerInstruction,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            instruction.to_vec(),
+            SeatManagerCapability::to_mask(capabilities)
+                .try_to_vec()
+                .unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+/// `status` must be `PostOnly` or `Paused`
+pub fn create_guardian_pause_market_instruction(
+    market: &Pubkey,
+    guardian: &Pubkey,
+    status: MarketStatus,
+) -> Instruction {
+    let mut instruction = create_change_market_status_instruction(market, guardian, status);
+    instruction.data[0] = SeatManagerInstruction::GuardianPauseMarket as u8;
+    instruction
+}
+
+pub fn create_set_guardian_instruction(
+    market: &Pubkey,
+    authority: &Pubkey,
+    guardian: &Pubkey,
+) -> Instruction {
+    let (seat_manager, _) = get_seat_manager_address(market);
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(seat_manager, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: [
+            SeatManagerInstruction::SetGuardian.to_vec(),
+            guardian.try_to_vec().unwrap(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn create_clear_guardian_instruction(market: &Pubkey, authority: &Pubkey) -> Instruction {
+    create_set_guardian_instruction(market, authority, &Pubkey::default())
+}
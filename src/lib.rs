@@ -1,9 +1,11 @@
 use ellipsis_macros::declare_id;
 use instruction::SeatManagerInstruction;
+use pending_action::TimelockedAction;
 use processor::{
     process_change_market_status, process_claim_market_authority, process_claim_seat,
-    process_claim_seat_manager_authority, process_confirm_renounce_seat_manager_authority,
-    process_designated_market_maker, process_evict_seat, process_name_successor,
+    process_claim_seat_manager_authority, process_claim_seat_with_eviction,
+    process_confirm_renounce_seat_manager_authority, process_designated_market_maker,
+    process_evict_seat, process_name_successor,
 };
 use solana_program::instruction::Instruction;
 use solana_program::msg;
@@ -14,13 +16,41 @@ use solana_program::{
 };
 
 use crate::processor::{
-    process_change_market_fee_recipient, process_name_market_authority_successor,
+    process_apply_for_dmm, process_bid_for_seat, process_cancel_successor,
+    process_cancel_timelocked_action, process_change_market_fee_recipient,
+    process_claim_admin_group_authority, process_claim_seat_and_designate,
+    process_configure_seat_auction, process_confirm_renounce_capabilities,
+    process_create_admin_group, process_create_admin_history, process_create_dmm_registry,
+    process_create_multisig, process_execute_timelocked_action, process_guardian_pause_market,
+    process_heartbeat, process_initiate_renounce_capabilities, process_join_seat_waitlist,
+    process_leave_seat_waitlist, process_name_admin_group_successor,
+    process_name_market_authority_successor, process_queue_timelocked_action, process_resign_dmm,
+    process_review_dmm_application, process_set_dmm_registry, process_set_dust_threshold,
+    process_set_eviction_policy, process_set_guardian, process_set_heartbeat_staleness_window,
+    process_set_launch_phase_end_slot, process_set_permissionless_access,
+    process_set_reclaim_cooldown, process_set_reserved_seats, process_set_role,
+    process_set_timelock_delay, process_settle_seat_auction, process_sync_dmms,
+    process_update_dmm_registry, process_update_multisig, process_withdraw_dmm_application,
+    process_withdraw_treasury,
 };
+pub mod admin_group;
+pub mod admin_history;
+pub mod capability;
+pub mod cpi;
+pub mod dmm_application;
+pub mod dmm_registry;
+pub mod eviction_policy;
 pub mod instruction;
 pub mod instruction_builders;
 pub mod loaders;
+pub mod multisig;
+pub mod pending_action;
 pub mod processor;
+pub mod role;
+pub mod seat_auction;
 pub mod seat_manager;
+pub mod seat_record;
+pub mod seat_waitlist;
 pub mod shank_structs;
 
 #[cfg(not(feature = "no-entrypoint"))]
@@ -40,6 +70,7 @@ security_txt! {
 }
 
 const MAX_DMMS: u64 = 128;
+const MAX_WAITLISTED_TRADERS: u64 = 128;
 
 declare_id!("PSMxQbAoDWDbvd9ezQJgARyq6R9L5kJAasaLDVcZwf1");
 
@@ -69,7 +100,7 @@ pub fn get_seat_manager_seeds(
             seat_manager,
             caller
         );
-        return Err(ProgramError::InvalidInstructionData.into());
+        Err(ProgramError::InvalidInstructionData)
     }
 }
 
@@ -103,7 +134,7 @@ pub fn get_seat_deposit_collector_seeds(
             seat_deposit_collector,
             caller
         );
-        return Err(ProgramError::InvalidInstructionData.into());
+        Err(ProgramError::InvalidInstructionData)
     }
 }
 
@@ -111,9 +142,377 @@ pub fn get_seat_deposit_collector_address(market: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[&market.to_bytes(), b"deposit"], &crate::id())
 }
 
-pub fn get_accounts_for_instruction<'a, 'info>(
+pub fn get_treasury_seeds(
+    market: &Pubkey,
+    treasury: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![market.to_bytes().to_vec(), b"treasury".to_vec()];
+    let (treasury_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if treasury_key == *treasury {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid treasury key, expected: {} found {}.\n{}",
+            treasury_key,
+            treasury,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_treasury_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[&market.to_bytes(), b"treasury"], &crate::id())
+}
+
+pub fn get_seat_waitlist_seeds(
+    market: &Pubkey,
+    seat_waitlist: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![market.to_bytes().to_vec(), b"waitlist".to_vec()];
+    let (seat_waitlist_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if seat_waitlist_key == *seat_waitlist {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid seat waitlist key, expected: {} found {}.\n{}",
+            seat_waitlist_key,
+            seat_waitlist,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_seat_waitlist_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[&market.to_bytes(), b"waitlist"], &crate::id())
+}
+
+pub fn get_seat_auction_seeds(
+    market: &Pubkey,
+    seat_auction: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![market.to_bytes().to_vec(), b"auction".to_vec()];
+    let (seat_auction_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if seat_auction_key == *seat_auction {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid seat auction key, expected: {} found {}.\n{}",
+            seat_auction_key,
+            seat_auction,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_seat_auction_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[&market.to_bytes(), b"auction"], &crate::id())
+}
+
+pub fn get_seat_record_seeds(
+    market: &Pubkey,
+    trader: &Pubkey,
+    seat_record: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![
+        market.to_bytes().to_vec(),
+        trader.to_bytes().to_vec(),
+        b"record".to_vec(),
+    ];
+    let (seat_record_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if seat_record_key == *seat_record {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid seat record key, expected: {} found {}.\n{}",
+            seat_record_key,
+            seat_record,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_seat_record_address(market: &Pubkey, trader: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[&market.to_bytes(), &trader.to_bytes(), b"record"],
+        &crate::id(),
+    )
+}
+
+pub fn get_dmm_application_seeds(
+    market: &Pubkey,
+    trader: &Pubkey,
+    dmm_application: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![
+        market.to_bytes().to_vec(),
+        trader.to_bytes().to_vec(),
+        b"dmm_application".to_vec(),
+    ];
+    let (dmm_application_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if dmm_application_key == *dmm_application {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid DMM application key, expected: {} found {}.\n{}",
+            dmm_application_key,
+            dmm_application,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_dmm_application_address(market: &Pubkey, trader: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[&market.to_bytes(), &trader.to_bytes(), b"dmm_application"],
+        &crate::id(),
+    )
+}
+
+pub fn get_multisig_seeds(
+    market: &Pubkey,
+    multisig: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![market.to_bytes().to_vec(), b"multisig".to_vec()];
+    let (multisig_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if multisig_key == *multisig {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid multisig key, expected: {} found {}.\n{}",
+            multisig_key,
+            multisig,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_multisig_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[&market.to_bytes(), b"multisig"], &crate::id())
+}
+
+pub fn get_admin_history_seeds(
+    market: &Pubkey,
+    admin_history: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![market.to_bytes().to_vec(), b"admin_history".to_vec()];
+    let (admin_history_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if admin_history_key == *admin_history {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid admin history key, expected: {} found {}.\n{}",
+            admin_history_key,
+            admin_history,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_admin_history_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[&market.to_bytes(), b"admin_history"], &crate::id())
+}
+
+pub fn get_admin_group_seeds(
+    creator: &Pubkey,
+    admin_group: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![creator.to_bytes().to_vec(), b"admin_group".to_vec()];
+    let (admin_group_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if admin_group_key == *admin_group {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid admin group key, expected: {} found {}.\n{}",
+            admin_group_key,
+            admin_group,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_admin_group_address(creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[&creator.to_bytes(), b"admin_group"], &crate::id())
+}
+
+pub fn get_dmm_registry_seeds(
+    creator: &Pubkey,
+    dmm_registry: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![creator.to_bytes().to_vec(), b"dmm_registry".to_vec()];
+    let (dmm_registry_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if dmm_registry_key == *dmm_registry {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid DMM registry key, expected: {} found {}.\n{}",
+            dmm_registry_key,
+            dmm_registry,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_dmm_registry_address(creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[&creator.to_bytes(), b"dmm_registry"], &crate::id())
+}
+
+pub fn get_pending_action_seeds(
+    market: &Pubkey,
+    action: &TimelockedAction,
+    pending_action: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let mut seeds = vec![
+        market.to_bytes().to_vec(),
+        b"pending_action".to_vec(),
+        vec![action.discriminant()],
+    ];
+    let (pending_action_key, bump) = Pubkey::find_program_address(
+        seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice(),
+        program_id,
+    );
+    seeds.push(vec![bump]);
+
+    if pending_action_key == *pending_action {
+        Ok(seeds)
+    } else {
+        let caller = std::panic::Location::caller();
+        msg!(
+            "Invalid pending action key, expected: {} found {}.\n{}",
+            pending_action_key,
+            pending_action,
+            caller
+        );
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+pub fn get_pending_action_address(market: &Pubkey, action: &TimelockedAction) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            &market.to_bytes(),
+            b"pending_action",
+            &[action.discriminant()],
+        ],
+        &crate::id(),
+    )
+}
+
+pub fn get_accounts_for_instruction<'info>(
     instruction: &Instruction,
-    accounts: &'a [AccountInfo<'info>],
+    accounts: &[AccountInfo<'info>],
 ) -> Result<Vec<AccountInfo<'info>>, ProgramError> {
     let mut accounts_from_instruction = vec![];
     // This is inefficient, but it also makes life a lot easier
@@ -133,6 +532,18 @@ pub fn get_accounts_for_instruction<'a, 'info>(
     Ok(accounts_from_instruction)
 }
 
+/// Closes a program-owned account by moving its lamports to `receiver` and zeroing its data
+pub fn close_account(account: &AccountInfo, receiver: &AccountInfo) -> ProgramResult {
+    let account_lamports = account.lamports();
+    **receiver.lamports.borrow_mut() = receiver
+        .lamports()
+        .checked_add(account_lamports)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    **account.lamports.borrow_mut() = 0;
+    account.try_borrow_mut_data()?.fill(0);
+    Ok(())
+}
+
 #[cfg(not(feature = "no-entrypoint"))]
 solana_program::entrypoint!(process_instruction);
 
@@ -163,7 +574,7 @@ pub fn process_instruction(
         }
         SeatManagerInstruction::EvictSeat => {
             msg!("SeatManagerInstruction::EvictSeat");
-            process_evict_seat(program_id, accounts)
+            process_evict_seat(program_id, accounts, data)
         }
         SeatManagerInstruction::AddDesignatedMarketMaker => {
             msg!("SeatManagerInstruction::AddDesignatedMarketMaker");
@@ -175,7 +586,7 @@ pub fn process_instruction(
         }
         SeatManagerInstruction::NameSuccessor => {
             msg!("SeatManagerInstruction::NameSuccessor");
-            process_name_successor(program_id, accounts)
+            process_name_successor(program_id, accounts, data)
         }
         SeatManagerInstruction::ClaimSeatManagerAuthority => {
             msg!("SeatManagerInstruction::ClaimSeatManagerAuthority");
@@ -197,5 +608,177 @@ pub fn process_instruction(
             msg!("SeatManagerInstruction::ConfirmRenounceSeatManagerAuthority");
             process_confirm_renounce_seat_manager_authority(program_id, accounts)
         }
+        SeatManagerInstruction::ClaimSeatWithEviction => {
+            msg!("SeatManagerInstruction::ClaimSeatWithEviction");
+            process_claim_seat_with_eviction(program_id, accounts, false)
+        }
+        SeatManagerInstruction::ClaimSeatWithEvictionAuthorized => {
+            msg!("SeatManagerInstruction::ClaimSeatWithEvictionAuthorized");
+            process_claim_seat_with_eviction(program_id, accounts, true)
+        }
+        SeatManagerInstruction::JoinSeatWaitlist => {
+            msg!("SeatManagerInstruction::JoinSeatWaitlist");
+            process_join_seat_waitlist(program_id, accounts)
+        }
+        SeatManagerInstruction::LeaveSeatWaitlist => {
+            msg!("SeatManagerInstruction::LeaveSeatWaitlist");
+            process_leave_seat_waitlist(program_id, accounts)
+        }
+        SeatManagerInstruction::ConfigureSeatAuction => {
+            msg!("SeatManagerInstruction::ConfigureSeatAuction");
+            process_configure_seat_auction(program_id, accounts, data)
+        }
+        SeatManagerInstruction::BidForSeat => {
+            msg!("SeatManagerInstruction::BidForSeat");
+            process_bid_for_seat(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SettleSeatAuction => {
+            msg!("SeatManagerInstruction::SettleSeatAuction");
+            process_settle_seat_auction(program_id, accounts)
+        }
+        SeatManagerInstruction::WithdrawTreasury => {
+            msg!("SeatManagerInstruction::WithdrawTreasury");
+            process_withdraw_treasury(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SetDustThreshold => {
+            msg!("SeatManagerInstruction::SetDustThreshold");
+            process_set_dust_threshold(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SetEvictionPolicy => {
+            msg!("SeatManagerInstruction::SetEvictionPolicy");
+            process_set_eviction_policy(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SetPermissionlessAccess => {
+            msg!("SeatManagerInstruction::SetPermissionlessAccess");
+            process_set_permissionless_access(program_id, accounts, data)
+        }
+        SeatManagerInstruction::Heartbeat => {
+            msg!("SeatManagerInstruction::Heartbeat");
+            process_heartbeat(program_id, accounts)
+        }
+        SeatManagerInstruction::SetHeartbeatStalenessWindow => {
+            msg!("SeatManagerInstruction::SetHeartbeatStalenessWindow");
+            process_set_heartbeat_staleness_window(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SetReclaimCooldown => {
+            msg!("SeatManagerInstruction::SetReclaimCooldown");
+            process_set_reclaim_cooldown(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SetReservedSeats => {
+            msg!("SeatManagerInstruction::SetReservedSeats");
+            process_set_reserved_seats(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SetLaunchPhaseEndSlot => {
+            msg!("SeatManagerInstruction::SetLaunchPhaseEndSlot");
+            process_set_launch_phase_end_slot(program_id, accounts, data)
+        }
+        SeatManagerInstruction::ClaimSeatAndDesignate => {
+            msg!("SeatManagerInstruction::ClaimSeatAndDesignate");
+            process_claim_seat_and_designate(program_id, accounts)
+        }
+        SeatManagerInstruction::SyncDmms => {
+            msg!("SeatManagerInstruction::SyncDmms");
+            process_sync_dmms(program_id, accounts)
+        }
+        SeatManagerInstruction::ApplyForDmm => {
+            msg!("SeatManagerInstruction::ApplyForDmm");
+            process_apply_for_dmm(program_id, accounts)
+        }
+        SeatManagerInstruction::ApproveDmmApplication => {
+            msg!("SeatManagerInstruction::ApproveDmmApplication");
+            process_review_dmm_application(program_id, accounts, true)
+        }
+        SeatManagerInstruction::RejectDmmApplication => {
+            msg!("SeatManagerInstruction::RejectDmmApplication");
+            process_review_dmm_application(program_id, accounts, false)
+        }
+        SeatManagerInstruction::ResignDmm => {
+            msg!("SeatManagerInstruction::ResignDmm");
+            process_resign_dmm(program_id, accounts)
+        }
+        SeatManagerInstruction::CreateMultisig => {
+            msg!("SeatManagerInstruction::CreateMultisig");
+            process_create_multisig(program_id, accounts, data)
+        }
+        SeatManagerInstruction::UpdateMultisig => {
+            msg!("SeatManagerInstruction::UpdateMultisig");
+            process_update_multisig(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SetRole => {
+            msg!("SeatManagerInstruction::SetRole");
+            process_set_role(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SetTimelockDelay => {
+            msg!("SeatManagerInstruction::SetTimelockDelay");
+            process_set_timelock_delay(program_id, accounts, data)
+        }
+        SeatManagerInstruction::QueueTimelockedAction => {
+            msg!("SeatManagerInstruction::QueueTimelockedAction");
+            process_queue_timelocked_action(program_id, accounts, data)
+        }
+        SeatManagerInstruction::CancelTimelockedAction => {
+            msg!("SeatManagerInstruction::CancelTimelockedAction");
+            process_cancel_timelocked_action(program_id, accounts, data)
+        }
+        SeatManagerInstruction::ExecuteTimelockedAction => {
+            msg!("SeatManagerInstruction::ExecuteTimelockedAction");
+            process_execute_timelocked_action(program_id, accounts)
+        }
+        SeatManagerInstruction::CancelSuccessor => {
+            msg!("SeatManagerInstruction::CancelSuccessor");
+            process_cancel_successor(program_id, accounts)
+        }
+        SeatManagerInstruction::InitiateRenounceCapabilities => {
+            msg!("SeatManagerInstruction::InitiateRenounceCapabilities");
+            process_initiate_renounce_capabilities(program_id, accounts, data)
+        }
+        SeatManagerInstruction::ConfirmRenounceCapabilities => {
+            msg!("SeatManagerInstruction::ConfirmRenounceCapabilities");
+            process_confirm_renounce_capabilities(program_id, accounts, data)
+        }
+        SeatManagerInstruction::GuardianPauseMarket => {
+            msg!("SeatManagerInstruction::GuardianPauseMarket");
+            process_guardian_pause_market(program_id, accounts, data)
+        }
+        SeatManagerInstruction::SetGuardian => {
+            msg!("SeatManagerInstruction::SetGuardian");
+            process_set_guardian(program_id, accounts, data)
+        }
+        SeatManagerInstruction::CreateAdminHistory => {
+            msg!("SeatManagerInstruction::CreateAdminHistory");
+            process_create_admin_history(program_id, accounts)
+        }
+        SeatManagerInstruction::CreateAdminGroup => {
+            msg!("SeatManagerInstruction::CreateAdminGroup");
+            process_create_admin_group(program_id, accounts)
+        }
+        SeatManagerInstruction::NameAdminGroupSuccessor => {
+            msg!("SeatManagerInstruction::NameAdminGroupSuccessor");
+            process_name_admin_group_successor(program_id, accounts, data)
+        }
+        SeatManagerInstruction::ClaimAdminGroupAuthority => {
+            msg!("SeatManagerInstruction::ClaimAdminGroupAuthority");
+            process_claim_admin_group_authority(program_id, accounts)
+        }
+        SeatManagerInstruction::CreateDmmRegistry => {
+            msg!("SeatManagerInstruction::CreateDmmRegistry");
+            process_create_dmm_registry(program_id, accounts, data)
+        }
+        SeatManagerInstruction::AddRegistryDmm => {
+            msg!("SeatManagerInstruction::AddRegistryDmm");
+            process_update_dmm_registry(program_id, accounts, data, false)
+        }
+        SeatManagerInstruction::RemoveRegistryDmm => {
+            msg!("SeatManagerInstruction::RemoveRegistryDmm");
+            process_update_dmm_registry(program_id, accounts, data, true)
+        }
+        SeatManagerInstruction::SetDmmRegistry => {
+            msg!("SeatManagerInstruction::SetDmmRegistry");
+            process_set_dmm_registry(program_id, accounts, data)
+        }
+        SeatManagerInstruction::WithdrawDmmApplication => {
+            msg!("SeatManagerInstruction::WithdrawDmmApplication");
+            process_withdraw_dmm_application(program_id, accounts)
+        }
     }
 }
@@ -9,7 +9,21 @@ use phoenix::program::{
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 use spl_associated_token_account::get_associated_token_address;
 
-use crate::{get_seat_manager_seeds, seat_manager::SeatManager};
+use crate::{
+    admin_group::AdminGroup,
+    admin_history::AdminHistory,
+    dmm_application::DmmApplication,
+    dmm_registry::DmmRegistry,
+    get_admin_group_seeds, get_admin_history_seeds, get_dmm_application_seeds,
+    get_dmm_registry_seeds, get_multisig_seeds, get_pending_action_seeds, get_seat_auction_seeds,
+    get_seat_manager_seeds, get_seat_record_seeds, get_seat_waitlist_seeds,
+    multisig::Multisig,
+    pending_action::{PendingAction, TimelockedAction},
+    seat_auction::SeatAuction,
+    seat_manager::SeatManager,
+    seat_record::SeatRecord,
+    seat_waitlist::SeatWaitlist,
+};
 
 pub struct MarketAccount<'a, 'info> {
     pub account: &'a AccountInfo<'info>,
@@ -100,15 +114,13 @@ impl<'a, 'info> SeatManagerAccount<'a, 'info> {
 
     pub fn load(&self) -> Result<Ref<'_, SeatManager>, ProgramError> {
         let data = self.account.try_borrow_data()?;
-        Ok(Ref::map(data, |data| {
-            return SeatManager::load(data).unwrap();
-        }))
+        Ok(Ref::map(data, |data| SeatManager::load(data).unwrap()))
     }
 
     pub fn load_mut(&self) -> Result<RefMut<'_, SeatManager>, ProgramError> {
         let data = self.account.try_borrow_mut_data()?;
         Ok(RefMut::map(data, |data| {
-            return SeatManager::load_mut(data).unwrap();
+            SeatManager::load_mut(data).unwrap()
         }))
     }
 }
@@ -121,6 +133,458 @@ impl<'a, 'info> Deref for SeatManagerAccount<'a, 'info> {
     }
 }
 
+pub struct SeatWaitlistAccount<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub seeds: Vec<Vec<u8>>,
+}
+
+impl<'a, 'info> SeatWaitlistAccount<'a, 'info> {
+    pub fn new_with_market(
+        account: &'a AccountInfo<'info>,
+        market: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        // Assert that the seat waitlist address is correct
+        let seeds = get_seat_waitlist_seeds(market, account.key, &crate::id())?;
+        if !account.data_is_empty() {
+            assert_with_msg(
+                *account.owner == crate::id(),
+                ProgramError::InvalidAccountData,
+                "Seat waitlist must be owned by the seat manager program",
+            )?;
+            let data = account.try_borrow_data()?;
+            let seat_waitlist = SeatWaitlist::load(&data)?;
+            if seat_waitlist.market != *market {
+                msg!("Seat waitlist does not belong to market");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        Ok(Self { account, seeds })
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, SeatWaitlist>, ProgramError> {
+        let data = self.account.try_borrow_data()?;
+        Ok(Ref::map(data, |data| SeatWaitlist::load(data).unwrap()))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, SeatWaitlist>, ProgramError> {
+        let data = self.account.try_borrow_mut_data()?;
+        Ok(RefMut::map(data, |data| {
+            SeatWaitlist::load_mut(data).unwrap()
+        }))
+    }
+}
+
+impl<'a, 'info> Deref for SeatWaitlistAccount<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.account
+    }
+}
+
+pub struct SeatAuctionAccount<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub seeds: Vec<Vec<u8>>,
+}
+
+impl<'a, 'info> SeatAuctionAccount<'a, 'info> {
+    pub fn new_with_market(
+        account: &'a AccountInfo<'info>,
+        market: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        // Assert that the seat auction address is correct
+        let seeds = get_seat_auction_seeds(market, account.key, &crate::id())?;
+        if !account.data_is_empty() {
+            assert_with_msg(
+                *account.owner == crate::id(),
+                ProgramError::InvalidAccountData,
+                "Seat auction must be owned by the seat manager program",
+            )?;
+            let data = account.try_borrow_data()?;
+            let seat_auction = SeatAuction::load(&data)?;
+            if seat_auction.market != *market {
+                msg!("Seat auction does not belong to market");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        Ok(Self { account, seeds })
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, SeatAuction>, ProgramError> {
+        let data = self.account.try_borrow_data()?;
+        Ok(Ref::map(data, |data| SeatAuction::load(data).unwrap()))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, SeatAuction>, ProgramError> {
+        let data = self.account.try_borrow_mut_data()?;
+        Ok(RefMut::map(data, |data| {
+            SeatAuction::load_mut(data).unwrap()
+        }))
+    }
+}
+
+impl<'a, 'info> Deref for SeatAuctionAccount<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.account
+    }
+}
+
+pub struct SeatRecordAccount<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub seeds: Vec<Vec<u8>>,
+}
+
+impl<'a, 'info> SeatRecordAccount<'a, 'info> {
+    pub fn new_with_market_and_trader(
+        account: &'a AccountInfo<'info>,
+        market: &Pubkey,
+        trader: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        // Assert that the seat record address is correct
+        let seeds = get_seat_record_seeds(market, trader, account.key, &crate::id())?;
+        if !account.data_is_empty() {
+            assert_with_msg(
+                *account.owner == crate::id(),
+                ProgramError::InvalidAccountData,
+                "Seat record must be owned by the seat manager program",
+            )?;
+        }
+        Ok(Self { account, seeds })
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, SeatRecord>, ProgramError> {
+        let data = self.account.try_borrow_data()?;
+        Ok(Ref::map(data, |data| SeatRecord::load(data).unwrap()))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, SeatRecord>, ProgramError> {
+        let data = self.account.try_borrow_mut_data()?;
+        Ok(RefMut::map(data, |data| {
+            SeatRecord::load_mut(data).unwrap()
+        }))
+    }
+}
+
+impl<'a, 'info> Deref for SeatRecordAccount<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.account
+    }
+}
+
+pub struct DmmApplicationAccount<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub seeds: Vec<Vec<u8>>,
+}
+
+impl<'a, 'info> DmmApplicationAccount<'a, 'info> {
+    pub fn new_with_market_and_trader(
+        account: &'a AccountInfo<'info>,
+        market: &Pubkey,
+        trader: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        // Assert that the DMM application address is correct
+        let seeds = get_dmm_application_seeds(market, trader, account.key, &crate::id())?;
+        if !account.data_is_empty() {
+            assert_with_msg(
+                *account.owner == crate::id(),
+                ProgramError::InvalidAccountData,
+                "DMM application must be owned by the seat manager program",
+            )?;
+        }
+        Ok(Self { account, seeds })
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, DmmApplication>, ProgramError> {
+        let data = self.account.try_borrow_data()?;
+        Ok(Ref::map(data, |data| DmmApplication::load(data).unwrap()))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, DmmApplication>, ProgramError> {
+        let data = self.account.try_borrow_mut_data()?;
+        Ok(RefMut::map(data, |data| {
+            DmmApplication::load_mut(data).unwrap()
+        }))
+    }
+}
+
+impl<'a, 'info> Deref for DmmApplicationAccount<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.account
+    }
+}
+
+pub struct MultisigAccount<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub seeds: Vec<Vec<u8>>,
+}
+
+impl<'a, 'info> MultisigAccount<'a, 'info> {
+    pub fn new_with_market(
+        account: &'a AccountInfo<'info>,
+        market: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        // Assert that the multisig address is correct
+        let seeds = get_multisig_seeds(market, account.key, &crate::id())?;
+        if !account.data_is_empty() {
+            assert_with_msg(
+                *account.owner == crate::id(),
+                ProgramError::InvalidAccountData,
+                "Multisig must be owned by the seat manager program",
+            )?;
+            let data = account.try_borrow_data()?;
+            let multisig = Multisig::load(&data)?;
+            if multisig.market != *market {
+                msg!("Multisig does not belong to market");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        Ok(Self { account, seeds })
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, Multisig>, ProgramError> {
+        let data = self.account.try_borrow_data()?;
+        Ok(Ref::map(data, |data| Multisig::load(data).unwrap()))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, Multisig>, ProgramError> {
+        let data = self.account.try_borrow_mut_data()?;
+        Ok(RefMut::map(data, |data| Multisig::load_mut(data).unwrap()))
+    }
+}
+
+impl<'a, 'info> Deref for MultisigAccount<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.account
+    }
+}
+
+pub struct AdminGroupAccount<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub seeds: Vec<Vec<u8>>,
+}
+
+impl<'a, 'info> AdminGroupAccount<'a, 'info> {
+    pub fn new_with_creator(
+        account: &'a AccountInfo<'info>,
+        creator: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        // Assert that the admin group address is correct
+        let seeds = get_admin_group_seeds(creator, account.key, &crate::id())?;
+        if !account.data_is_empty() {
+            assert_with_msg(
+                *account.owner == crate::id(),
+                ProgramError::InvalidAccountData,
+                "Admin group must be owned by the seat manager program",
+            )?;
+            let data = account.try_borrow_data()?;
+            let admin_group = AdminGroup::load(&data)?;
+            if admin_group.creator != *creator {
+                msg!("Admin group does not belong to creator");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        Ok(Self { account, seeds })
+    }
+
+    /// Loads an existing admin group, checking its address against the stored creator
+    pub fn new(account: &'a AccountInfo<'info>) -> Result<Self, ProgramError> {
+        assert_with_msg(
+            *account.owner == crate::id(),
+            ProgramError::InvalidAccountData,
+            "Admin group must be owned by the seat manager program",
+        )?;
+        let creator = AdminGroup::load(&account.try_borrow_data()?)?.creator;
+        Self::new_with_creator(account, &creator)
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, AdminGroup>, ProgramError> {
+        let data = self.account.try_borrow_data()?;
+        Ok(Ref::map(data, |data| AdminGroup::load(data).unwrap()))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, AdminGroup>, ProgramError> {
+        let data = self.account.try_borrow_mut_data()?;
+        Ok(RefMut::map(data, |data| {
+            AdminGroup::load_mut(data).unwrap()
+        }))
+    }
+}
+
+impl<'a, 'info> Deref for AdminGroupAccount<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.account
+    }
+}
+
+pub struct DmmRegistryAccount<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub seeds: Vec<Vec<u8>>,
+}
+
+impl<'a, 'info> DmmRegistryAccount<'a, 'info> {
+    pub fn new_with_creator(
+        account: &'a AccountInfo<'info>,
+        creator: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        // Assert that the DMM registry address is correct
+        let seeds = get_dmm_registry_seeds(creator, account.key, &crate::id())?;
+        if !account.data_is_empty() {
+            assert_with_msg(
+                *account.owner == crate::id(),
+                ProgramError::InvalidAccountData,
+                "DMM registry must be owned by the seat manager program",
+            )?;
+            let data = account.try_borrow_data()?;
+            let dmm_registry = DmmRegistry::load(&data)?;
+            if dmm_registry.creator != *creator {
+                msg!("DMM registry does not belong to creator");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        Ok(Self { account, seeds })
+    }
+
+    /// Loads an existing DMM registry, checking its address against the stored creator
+    pub fn new(account: &'a AccountInfo<'info>) -> Result<Self, ProgramError> {
+        assert_with_msg(
+            *account.owner == crate::id(),
+            ProgramError::InvalidAccountData,
+            "DMM registry must be owned by the seat manager program",
+        )?;
+        let creator = DmmRegistry::load(&account.try_borrow_data()?)?.creator;
+        Self::new_with_creator(account, &creator)
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, DmmRegistry>, ProgramError> {
+        let data = self.account.try_borrow_data()?;
+        Ok(Ref::map(data, |data| DmmRegistry::load(data).unwrap()))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, DmmRegistry>, ProgramError> {
+        let data = self.account.try_borrow_mut_data()?;
+        Ok(RefMut::map(data, |data| {
+            DmmRegistry::load_mut(data).unwrap()
+        }))
+    }
+}
+
+impl<'a, 'info> Deref for DmmRegistryAccount<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.account
+    }
+}
+
+pub struct AdminHistoryAccount<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub seeds: Vec<Vec<u8>>,
+}
+
+impl<'a, 'info> AdminHistoryAccount<'a, 'info> {
+    pub fn new_with_market(
+        account: &'a AccountInfo<'info>,
+        market: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        // Assert that the admin history address is correct
+        let seeds = get_admin_history_seeds(market, account.key, &crate::id())?;
+        if !account.data_is_empty() {
+            assert_with_msg(
+                *account.owner == crate::id(),
+                ProgramError::InvalidAccountData,
+                "Admin history must be owned by the seat manager program",
+            )?;
+            let data = account.try_borrow_data()?;
+            let admin_history = AdminHistory::load(&data)?;
+            if admin_history.market != *market {
+                msg!("Admin history does not belong to market");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        Ok(Self { account, seeds })
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, AdminHistory>, ProgramError> {
+        let data = self.account.try_borrow_data()?;
+        Ok(Ref::map(data, |data| AdminHistory::load(data).unwrap()))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, AdminHistory>, ProgramError> {
+        let data = self.account.try_borrow_mut_data()?;
+        Ok(RefMut::map(data, |data| {
+            AdminHistory::load_mut(data).unwrap()
+        }))
+    }
+}
+
+impl<'a, 'info> Deref for AdminHistoryAccount<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.account
+    }
+}
+
+pub struct PendingActionAccount<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub seeds: Vec<Vec<u8>>,
+}
+
+impl<'a, 'info> PendingActionAccount<'a, 'info> {
+    pub fn new_with_market_and_action(
+        account: &'a AccountInfo<'info>,
+        market: &Pubkey,
+        action: &TimelockedAction,
+    ) -> Result<Self, ProgramError> {
+        // Assert that the pending action address is correct
+        let seeds = get_pending_action_seeds(market, action, account.key, &crate::id())?;
+        if !account.data_is_empty() {
+            assert_with_msg(
+                *account.owner == crate::id(),
+                ProgramError::InvalidAccountData,
+                "Pending action must be owned by the seat manager program",
+            )?;
+            let data = account.try_borrow_data()?;
+            let pending_action = PendingAction::load(&data)?;
+            if pending_action.market != *market {
+                msg!("Pending action does not belong to market");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        Ok(Self { account, seeds })
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, PendingAction>, ProgramError> {
+        let data = self.account.try_borrow_data()?;
+        Ok(Ref::map(data, |data| PendingAction::load(data).unwrap()))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, PendingAction>, ProgramError> {
+        let data = self.account.try_borrow_mut_data()?;
+        Ok(RefMut::map(data, |data| {
+            PendingAction::load_mut(data).unwrap()
+        }))
+    }
+}
+
+impl<'a, 'info> Deref for PendingActionAccount<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.account
+    }
+}
+
 pub struct AssociatedTokenAccount<'a, 'info> {
     pub account: &'a AccountInfo<'info>,
     pub is_initialized: bool,
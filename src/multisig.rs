@@ -0,0 +1,95 @@
+use bytemuck::{Pod, Zeroable};
+use phoenix::program::assert_with_msg;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// An M-of-N multisig that can hold the seat manager authority, seeds are [market_address, b"multisig"].
+/// While the authority is the multisig, authority-gated instructions take the multisig account and
+/// at least `threshold` of its signers as extra signing accounts.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct Multisig {
+    pub market: Pubkey,
+    pub threshold: u64,
+    pub num_signers: u64,
+    pub signers: [Pubkey; MAX_MULTISIG_SIGNERS],
+    pub _padding: [u64; 8],
+}
+
+impl Multisig {
+    pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
+        bytemuck::try_from_bytes::<Multisig>(bytes).map_err(|_| {
+            msg!("Failed to load multisig from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn load_mut(bytes: &'_ mut [u8]) -> Result<&'_ mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut::<Multisig>(bytes).map_err(|_| {
+            msg!("Failed to load multisig from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn get_signers(&self) -> &[Pubkey] {
+        &self.signers[..self.num_signers as usize]
+    }
+
+    /// Replaces the signers and threshold. Signers must be unique, and the threshold must be between 1 and the number of signers.
+    pub fn set_signers(&mut self, threshold: u64, signers: &[Pubkey]) -> Result<(), ProgramError> {
+        assert_with_msg(
+            !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+            ProgramError::InvalidInstructionData,
+            &format!(
+                "A multisig must have between 1 and {} signers",
+                MAX_MULTISIG_SIGNERS
+            ),
+        )?;
+        assert_with_msg(
+            threshold > 0 && threshold <= signers.len() as u64,
+            ProgramError::InvalidInstructionData,
+            "Multisig threshold must be between 1 and the number of signers",
+        )?;
+        for (i, signer) in signers.iter().enumerate() {
+            assert_with_msg(
+                !signers[..i].contains(signer),
+                ProgramError::InvalidInstructionData,
+                "Multisig signers must be unique",
+            )?;
+        }
+        self.signers = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+        self.signers[..signers.len()].copy_from_slice(signers);
+        self.num_signers = signers.len() as u64;
+        self.threshold = threshold;
+        Ok(())
+    }
+
+    /// Counts the multisig signers that signed among `accounts`, each signer counted once
+    pub fn count_approvals(&self, accounts: &[AccountInfo]) -> u64 {
+        self.get_signers()
+            .iter()
+            .filter(|signer| accounts.iter().any(|ai| ai.is_signer && ai.key == *signer))
+            .count() as u64
+    }
+}
+
+#[test]
+fn test_multisig_set_signers() {
+    let mut multisig = Multisig::zeroed();
+    let signers = (0..3).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+
+    assert!(multisig.set_signers(0, &signers).is_err());
+    assert!(multisig.set_signers(4, &signers).is_err());
+    assert!(multisig.set_signers(1, &[]).is_err());
+    assert!(multisig
+        .set_signers(2, &[signers[0], signers[1], signers[0]])
+        .is_err());
+    assert!(multisig
+        .set_signers(1, &vec![Pubkey::new_unique(); MAX_MULTISIG_SIGNERS + 1])
+        .is_err());
+
+    multisig.set_signers(2, &signers).unwrap();
+    assert_eq!(multisig.threshold, 2);
+    assert_eq!(multisig.get_signers(), signers.as_slice());
+}
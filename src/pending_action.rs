@@ -0,0 +1,124 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Authority actions that must be queued while the seat manager has a timelock delay
+#[derive(Debug, Copy, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum TimelockedAction {
+    ChangeMarketFeeRecipient {
+        new_fee_recipient: Pubkey,
+    },
+    NameMarketAuthoritySuccessor {
+        successor: Pubkey,
+    },
+    NameSuccessor {
+        successor: Pubkey,
+    },
+    /// Only decreases of the timelock delay need to be queued
+    SetTimelockDelay {
+        timelock_delay_in_slots: u64,
+    },
+}
+
+impl TimelockedAction {
+    /// Each kind of action has its own pending action account, so different kinds can be queued at the same time
+    pub fn discriminant(&self) -> u8 {
+        match self {
+            TimelockedAction::ChangeMarketFeeRecipient { .. } => 0,
+            TimelockedAction::NameMarketAuthoritySuccessor { .. } => 1,
+            TimelockedAction::NameSuccessor { .. } => 2,
+            TimelockedAction::SetTimelockDelay { .. } => 3,
+        }
+    }
+}
+
+/// A queued timelocked action, seeds are [market_address, b"pending_action", action_discriminant].
+/// Anyone can execute it once `execute_after_slot` is reached, and the rent goes back to the payer.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct PendingAction {
+    pub market: Pubkey,
+    /// Discriminant of the `TimelockedAction`
+    pub action: u64,
+    pub execute_after_slot: u64,
+    /// The new fee recipient or successor, unused by `TimelockedAction::SetTimelockDelay`
+    pub target: Pubkey,
+    /// Only used by `TimelockedAction::SetTimelockDelay`
+    pub timelock_delay_in_slots: u64,
+    /// Receives the rent when the action is executed or cancelled
+    pub payer: Pubkey,
+    /// The authority, or the fee admin for fee recipient changes, that queued the action.
+    /// The action can no longer be executed once this key loses its authority or role
+    pub queued_by: Pubkey,
+    pub _padding: [u64; 3],
+}
+
+impl PendingAction {
+    pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
+        bytemuck::try_from_bytes::<PendingAction>(bytes).map_err(|_| {
+            msg!("Failed to load pending action from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn load_mut(bytes: &'_ mut [u8]) -> Result<&'_ mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut::<PendingAction>(bytes).map_err(|_| {
+            msg!("Failed to load pending action from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn get_action(&self) -> Result<TimelockedAction, ProgramError> {
+        match self.action {
+            0 => Ok(TimelockedAction::ChangeMarketFeeRecipient {
+                new_fee_recipient: self.target,
+            }),
+            1 => Ok(TimelockedAction::NameMarketAuthoritySuccessor {
+                successor: self.target,
+            }),
+            2 => Ok(TimelockedAction::NameSuccessor {
+                successor: self.target,
+            }),
+            3 => Ok(TimelockedAction::SetTimelockDelay {
+                timelock_delay_in_slots: self.timelock_delay_in_slots,
+            }),
+            _ => {
+                msg!("Invalid pending action discriminant {}", self.action);
+                Err(ProgramError::InvalidAccountData)
+            }
+        }
+    }
+
+    pub fn set_action(&mut self, action: TimelockedAction) {
+        self.action = action.discriminant() as u64;
+        match action {
+            TimelockedAction::ChangeMarketFeeRecipient {
+                new_fee_recipient: target,
+            }
+            | TimelockedAction::NameMarketAuthoritySuccessor { successor: target }
+            | TimelockedAction::NameSuccessor { successor: target } => self.target = target,
+            TimelockedAction::SetTimelockDelay {
+                timelock_delay_in_slots,
+            } => self.timelock_delay_in_slots = timelock_delay_in_slots,
+        }
+    }
+}
+
+#[test]
+fn test_pending_action_round_trip() {
+    let target = Pubkey::new_unique();
+    for action in [
+        TimelockedAction::ChangeMarketFeeRecipient {
+            new_fee_recipient: target,
+        },
+        TimelockedAction::NameMarketAuthoritySuccessor { successor: target },
+        TimelockedAction::NameSuccessor { successor: target },
+        TimelockedAction::SetTimelockDelay {
+            timelock_delay_in_slots: 100,
+        },
+    ] {
+        let mut pending_action = PendingAction::zeroed();
+        pending_action.set_action(action);
+        assert_eq!(pending_action.get_action().unwrap(), action);
+    }
+}
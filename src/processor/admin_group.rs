@@ -0,0 +1,126 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Program, Signer},
+    system_utils::create_account,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    admin_group::AdminGroup, loaders::AdminGroupAccount,
+    seat_manager::DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS,
+};
+
+use super::is_signer_or_multisig_approved;
+
+/// Like `assert_authority_approved`, but doesn't accept another admin group, so that groups can't be nested
+fn assert_admin_group_authority_approved(
+    authority_ai: &AccountInfo,
+    authority: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    assert_with_msg(
+        authority_ai.key == authority,
+        ProgramError::InvalidArgument,
+        &format!(
+            "Expected admin group authority {}, found {}",
+            authority, authority_ai.key
+        ),
+    )?;
+    assert_with_msg(
+        is_signer_or_multisig_approved(authority, accounts)?,
+        ProgramError::MissingRequiredSignature,
+        "The admin group authority must sign, or a multisig authority must be approved by its threshold of signers",
+    )
+}
+
+pub fn process_create_admin_group(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let creator = Signer::new(&accounts[1])?;
+    let admin_group = AdminGroupAccount::new_with_creator(&accounts[0], creator.key)?;
+    let payer = Signer::new_payer(&accounts[2])?;
+    let system_program = Program::new(&accounts[3], &system_program::id())?;
+
+    assert_with_msg(
+        admin_group.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "Admin group already exists for this creator",
+    )?;
+
+    msg!("Creating and initializing admin group account");
+    create_account(
+        &payer,
+        &admin_group,
+        &system_program,
+        program_id,
+        &Rent::get()?,
+        size_of::<AdminGroup>() as u64,
+        admin_group.seeds.clone(),
+    )?;
+    let mut admin_group = admin_group.load_mut()?;
+    admin_group.creator = *creator.key;
+    admin_group.authority = *creator.key;
+    admin_group.successor = *creator.key;
+    Ok(())
+}
+
+/// The successor claims the admin group authority with `ClaimAdminGroupAuthority`. Naming the current authority cancels the nomination.
+/// The instruction data is the successor and an optional expiry slot. Without one, the nomination expires after the default window
+pub fn process_name_admin_group_successor(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let admin_group = AdminGroupAccount::new(&accounts[0])?;
+    let authority = admin_group.load()?.authority;
+    assert_admin_group_authority_approved(&accounts[1], &authority, accounts)?;
+
+    let (successor_bytes, expiry_slot_bytes) = data.split_at(data.len().min(32));
+    let successor = Pubkey::try_from_slice(successor_bytes)?;
+    let expiry_slot = if successor == authority {
+        0
+    } else {
+        let current_slot = Clock::get()?.slot;
+        let expiry_slot = if expiry_slot_bytes.is_empty() {
+            current_slot + DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS
+        } else {
+            u64::try_from_slice(expiry_slot_bytes)?
+        };
+        assert_with_msg(
+            expiry_slot > current_slot,
+            ProgramError::InvalidArgument,
+            "The successor nomination must expire after the current slot",
+        )?;
+        expiry_slot
+    };
+    msg!("Naming {} as the admin group successor", successor);
+    let mut admin_group = admin_group.load_mut()?;
+    admin_group.successor = successor;
+    admin_group.successor_expiry_slot = expiry_slot;
+    Ok(())
+}
+
+/// Rotates the authority of every seat manager whose authority is the admin group
+pub fn process_claim_admin_group_authority(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let admin_group = AdminGroupAccount::new(&accounts[0])?;
+    // The successor can be a multisig, approved by its signers
+    let successor = admin_group.load()?.successor;
+    assert_admin_group_authority_approved(&accounts[1], &successor, accounts)?;
+    assert_with_msg(
+        !admin_group.load()?.is_successor_expired(Clock::get()?.slot),
+        ProgramError::InvalidArgument,
+        "The successor nomination has expired",
+    )?;
+
+    let mut admin_group = admin_group.load_mut()?;
+    admin_group.authority = successor;
+    admin_group.successor_expiry_slot = 0;
+    Ok(())
+}
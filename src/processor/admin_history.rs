@@ -0,0 +1,96 @@
+use std::mem::size_of;
+
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Program, Signer},
+    system_utils::create_account,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    admin_history::{AdminActionKind, AdminActionRecord, AdminHistory},
+    get_admin_history_address,
+    loaders::{AdminHistoryAccount, MarketAccount, SeatManagerAccount},
+};
+
+use super::assert_authority_approved;
+
+/// Appends the action to the market's admin history, if the seat manager has one.
+/// The admin history account can be anywhere in `accounts`, and must be supplied once the history is enabled.
+pub fn record_admin_action(
+    seat_manager: &SeatManagerAccount,
+    accounts: &[AccountInfo],
+    signer: &Pubkey,
+    kind: AdminActionKind,
+    key: &Pubkey,
+    value: u64,
+) -> ProgramResult {
+    let market = {
+        let seat_manager = seat_manager.load()?;
+        if !seat_manager.is_admin_history_enabled() {
+            return Ok(());
+        }
+        seat_manager.market
+    };
+    let admin_history_key = get_admin_history_address(&market).0;
+    let admin_history = accounts
+        .iter()
+        .find(|ai| *ai.key == admin_history_key)
+        .ok_or_else(|| {
+            msg!("The admin history account must be supplied to record admin actions");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+    let admin_history = AdminHistoryAccount::new_with_market(admin_history, &market)?;
+    admin_history.load_mut()?.push(AdminActionRecord {
+        kind: kind as u64,
+        signer: *signer,
+        slot: Clock::get()?.slot,
+        key: *key,
+        value,
+    });
+    Ok(())
+}
+
+pub fn process_create_admin_history(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let admin_history = AdminHistoryAccount::new_with_market(&accounts[2], market_ai.key)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[3], &seat_manager.load()?.authority, accounts)?;
+    let payer = Signer::new_payer(&accounts[4])?;
+    let system_program = Program::new(&accounts[5], &system_program::id())?;
+
+    assert_with_msg(
+        admin_history.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "Admin history already exists for this market",
+    )?;
+
+    msg!("Creating and initializing admin history account");
+    create_account(
+        &payer,
+        &admin_history,
+        &system_program,
+        program_id,
+        &Rent::get()?,
+        size_of::<AdminHistory>() as u64,
+        admin_history.seeds.clone(),
+    )?;
+    admin_history.load_mut()?.market = *market_ai.key;
+    seat_manager.load_mut()?.admin_history_enabled = 1;
+
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[3].key,
+        AdminActionKind::CreateAdminHistory,
+        &Pubkey::default(),
+        0,
+    )
+}
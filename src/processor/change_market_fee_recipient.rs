@@ -2,7 +2,7 @@ use std::mem::size_of;
 
 use phoenix::{
     program::{
-        checkers::Signer, create_change_fee_recipient_instruction, create_collect_fees_instruction,
+        create_change_fee_recipient_instruction, create_collect_fees_instruction,
         load_with_dispatch, MarketHeader,
     },
     quantities::WrapperU64,
@@ -13,8 +13,16 @@ use solana_program::{
 };
 
 use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
     get_accounts_for_instruction,
     loaders::{MarketAccount, SeatManagerAccount},
+    role::SeatManagerRole,
+};
+
+use super::{
+    assert_capability_not_renounced, assert_not_timelocked, assert_role_approved,
+    record_admin_action,
 };
 
 pub fn process_change_market_fee_recipient(
@@ -24,9 +32,37 @@ pub fn process_change_market_fee_recipient(
     // Get Accounts
     let market_ai = MarketAccount::new(&accounts[2])?;
     let seat_manager = SeatManagerAccount::new_with_market(&accounts[3], market_ai.key)?;
-    // Checks that the seat_manager_authority is the valid authority for the seat_manager and a signer
-    let _seat_manager_authority =
-        Signer::new_with_key(&accounts[4], &seat_manager.load()?.authority)?;
+    // Checks that the signer is the seat manager's authority or the fee admin, and that it approved
+    assert_role_approved(
+        &accounts[4],
+        &*seat_manager.load()?,
+        SeatManagerRole::FeeAdmin,
+        accounts,
+    )?;
+    assert_not_timelocked(&*seat_manager.load()?)?;
+
+    change_market_fee_recipient(&market_ai, &seat_manager, accounts)?;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[4].key,
+        AdminActionKind::ChangeMarketFeeRecipient,
+        accounts[7].key,
+        0,
+    )
+}
+
+/// Collects any unclaimed fees for the current fee recipient and changes the fee recipient to account 7.
+/// The accounts follow the `ChangeMarketFeeRecipient` layout.
+pub(crate) fn change_market_fee_recipient(
+    market_ai: &MarketAccount,
+    seat_manager: &SeatManagerAccount,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    assert_capability_not_renounced(
+        &*seat_manager.load()?,
+        SeatManagerCapability::ChangeFeeRecipient,
+    )?;
     let current_fee_recipient_quote_token_ai = &accounts[5];
     let new_fee_recipient = &accounts[7];
 
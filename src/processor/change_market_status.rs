@@ -1,14 +1,23 @@
 use borsh::BorshDeserialize;
 use phoenix::program::{
-    checkers::Signer, create_change_market_status_instruction, status::MarketStatus,
+    assert_with_msg, create_change_market_status_instruction, status::MarketStatus,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed, pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
+    program_error::ProgramError, pubkey::Pubkey,
 };
 
 use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
     get_accounts_for_instruction,
     loaders::{MarketAccount, SeatManagerAccount},
+    role::SeatManagerRole,
+};
+
+use super::{
+    assert_authority_approved, assert_capability_not_renounced, assert_role_approved,
+    record_admin_action,
 };
 
 pub fn process_change_market_status(
@@ -18,10 +27,74 @@ pub fn process_change_market_status(
 ) -> ProgramResult {
     let market_ai = MarketAccount::new(&accounts[2])?;
     let seat_manager = SeatManagerAccount::new_with_market(&accounts[3], market_ai.key)?;
-    // Checks that _authority is the valid authority for the seat_manager and a signer
-    let _authority = Signer::new_with_key(&accounts[4], &seat_manager.load()?.authority)?;
+    // Checks that the signer is the seat manager's authority or the status operator, and that it approved
+    assert_role_approved(
+        &accounts[4],
+        &*seat_manager.load()?,
+        SeatManagerRole::StatusOperator,
+        accounts,
+    )?;
+
+    let status = MarketStatus::try_from_slice(data)?;
+    change_market_status(&market_ai, &seat_manager, status, accounts)?;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[4].key,
+        AdminActionKind::ChangeMarketStatus,
+        &Pubkey::default(),
+        status as u64,
+    )
+}
+
+/// The guardian can only move the market to `PostOnly` or `Paused`, never re-activate it.
+/// Once the authority is renounced, nobody could undo a pause, so the guardian can no longer act
+pub fn process_guardian_pause_market(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[2])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[3], market_ai.key)?;
+    assert_with_msg(
+        seat_manager.load()?.authority != Pubkey::default(),
+        ProgramError::InvalidAccountData,
+        "The seat manager authority has been renounced",
+    )?;
+    let guardian = seat_manager.load()?.get_guardian().ok_or_else(|| {
+        msg!("The seat manager has no guardian");
+        ProgramError::InvalidAccountData
+    })?;
+    assert_authority_approved(&accounts[4], &guardian, accounts)?;
 
     let status = MarketStatus::try_from_slice(data)?;
+    assert_with_msg(
+        matches!(status, MarketStatus::PostOnly | MarketStatus::Paused),
+        ProgramError::InvalidInstructionData,
+        "The guardian can only move the market to PostOnly or Paused",
+    )?;
+    change_market_status(&market_ai, &seat_manager, status, accounts)?;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        &guardian,
+        AdminActionKind::GuardianPauseMarket,
+        &Pubkey::default(),
+        status as u64,
+    )
+}
+
+/// The accounts follow the `ChangeMarketStatus` layout
+fn change_market_status(
+    market_ai: &MarketAccount,
+    seat_manager: &SeatManagerAccount,
+    status: MarketStatus,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    assert_capability_not_renounced(
+        &*seat_manager.load()?,
+        SeatManagerCapability::ChangeMarketStatus,
+    )?;
     let change_market_status_instruction =
         create_change_market_status_instruction(seat_manager.key, market_ai.key, status);
 
@@ -36,3 +109,27 @@ pub fn process_change_market_status(
             .as_slice()],
     )
 }
+
+/// Assigns the guardian, or clears it with the default pubkey
+pub fn process_set_guardian(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let guardian = Pubkey::try_from_slice(data)?;
+    msg!("Setting the guardian to {}", guardian);
+    seat_manager.load_mut()?.guardian = guardian;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::SetGuardian,
+        &guardian,
+        0,
+    )
+}
@@ -1,15 +1,78 @@
-use phoenix::program::checkers::Signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use borsh::BorshDeserialize;
+use phoenix::program::assert_with_msg;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
 
-use crate::loaders::SeatManagerAccount;
+use crate::{
+    admin_history::AdminActionKind, capability::SeatManagerCapability, loaders::SeatManagerAccount,
+    seat_manager::DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS,
+};
 
-pub fn process_name_successor(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+use super::{
+    assert_authority_approved, assert_capability_not_renounced, assert_not_timelocked,
+    record_admin_action,
+};
+
+/// The instruction data is an optional expiry slot. Without one, the nomination expires after the default window
+pub fn process_name_successor(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
     let seat_manager = SeatManagerAccount::new(&accounts[0])?;
-    let _authority = Signer::new_with_key(&accounts[1], &seat_manager.load()?.authority)?;
+    assert_authority_approved(&accounts[1], &seat_manager.load()?.authority, accounts)?;
+    assert_not_timelocked(&*seat_manager.load()?)?;
     let successor_ai = &accounts[2];
+    // Naming the default pubkey initiates renouncing the authority entirely, which stays possible
+    if *successor_ai.key != Pubkey::default() {
+        assert_capability_not_renounced(
+            &*seat_manager.load()?,
+            SeatManagerCapability::NameSuccessor,
+        )?;
+    }
+
+    let current_slot = Clock::get()?.slot;
+    let expiry_slot = if data.is_empty() {
+        current_slot + DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS
+    } else {
+        u64::try_from_slice(data)?
+    };
+    assert_with_msg(
+        expiry_slot > current_slot,
+        ProgramError::InvalidArgument,
+        "The successor nomination must expire after the current slot",
+    )?;
+
+    seat_manager
+        .load_mut()?
+        .set_successor(successor_ai.key, expiry_slot);
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[1].key,
+        AdminActionKind::NameSuccessor,
+        successor_ai.key,
+        expiry_slot,
+    )
+}
+
+/// Resets the successor to the current authority, withdrawing any pending nomination or renunciation
+pub fn process_cancel_successor(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let seat_manager = SeatManagerAccount::new(&accounts[0])?;
+    let authority = seat_manager.load()?.authority;
+    assert_authority_approved(&accounts[1], &authority, accounts)?;
 
-    seat_manager.load_mut()?.successor = *successor_ai.key;
-    Ok(())
+    seat_manager.load_mut()?.set_successor(&authority, 0);
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[1].key,
+        AdminActionKind::CancelSuccessor,
+        &Pubkey::default(),
+        0,
+    )
 }
 
 pub fn process_claim_seat_manager_authority(
@@ -17,8 +80,30 @@ pub fn process_claim_seat_manager_authority(
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let seat_manager = SeatManagerAccount::new(&accounts[0])?;
-    let successor = Signer::new_with_key(&accounts[1], &seat_manager.load()?.successor)?;
+    // The successor can be a multisig, approved by its signers
+    let successor = seat_manager.load()?.successor;
+    assert_authority_approved(&accounts[1], &successor, accounts)?;
+    // Also stops nominations made before the capability was renounced
+    assert_capability_not_renounced(&*seat_manager.load()?, SeatManagerCapability::NameSuccessor)?;
+    assert_with_msg(
+        !seat_manager
+            .load()?
+            .is_successor_expired(Clock::get()?.slot),
+        ProgramError::InvalidArgument,
+        "The successor nomination has expired",
+    )?;
 
-    seat_manager.load_mut()?.authority = *successor.key;
-    Ok(())
+    {
+        let mut seat_manager = seat_manager.load_mut()?;
+        seat_manager.authority = successor;
+        seat_manager.successor_expiry_slot = 0;
+    }
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        &successor,
+        AdminActionKind::ClaimSeatManagerAuthority,
+        &Pubkey::default(),
+        0,
+    )
 }
@@ -16,7 +16,6 @@ use crate::{
     get_accounts_for_instruction, get_seat_deposit_collector_address,
     loaders::{MarketAccount, SeatManagerAccount},
     seat_manager::SeatManager,
-    MAX_DMMS,
 };
 
 pub fn process_claim_market_authority(
@@ -31,6 +30,7 @@ pub fn process_claim_market_authority(
         &get_seat_deposit_collector_address(market_ai.key).0,
     )?;
     let system_program = Program::new(&accounts[6], &system_program::id())?;
+    let market_size_params;
     {
         let market_bytes = market_ai.data.borrow();
         let (header_bytes, market_data) = market_bytes.split_at(size_of::<MarketHeader>());
@@ -40,24 +40,21 @@ pub fn process_claim_market_authority(
                 ProgramError::InvalidAccountData
             })?;
 
+        // Any market size with a seat for every resting order plus one is supported, the seat manager derives its DMM capacity from the size params
         let MarketSizeParams {
             bids_size,
             asks_size,
             num_seats,
         } = market_header.market_size_params;
-
         assert_with_msg(
-            num_seats == bids_size + asks_size + 1 + MAX_DMMS,
+            num_seats > bids_size + asks_size,
             ProgramError::InvalidAccountData,
             &format!(
-                "Invalid market size params, bids: {} asks: {} seats: {}.
-                Market must have exactly {} more seats than bids and asks",
-                bids_size,
-                asks_size,
-                num_seats,
-                MAX_DMMS + 1
+                "Invalid market size params, market must have at least {} seats",
+                bids_size + asks_size + 1
             ),
         )?;
+        market_size_params = market_header.market_size_params;
 
         assert_with_msg(
             market_header.successor == *seat_manager.key,
@@ -76,7 +73,7 @@ pub fn process_claim_market_authority(
 
         // Assert that the seat deposit collector account has sufficient lamports equal to the rent of 2 token accounts per trader times the number of existing seats on the market.
         // This is required for seat eviction.
-        let market = load_with_dispatch(&market_header.market_size_params, &market_data)?.inner;
+        let market = load_with_dispatch(&market_header.market_size_params, market_data)?.inner;
         let existing_seats = market.get_registered_traders().len();
         let required_deposits = existing_seats as u64
             * Rent::get()?.minimum_balance(spl_token::state::Account::LEN)
@@ -110,6 +107,7 @@ pub fn process_claim_market_authority(
         // The payer of this instruction starts out as the seat manager authority
         seat_manager.authority = *payer.key;
         seat_manager.successor = *payer.key;
+        seat_manager.set_capacity(&market_size_params);
     } else {
         let mut seat_manager_struct = seat_manager.load_mut()?;
         assert_with_msg(
@@ -126,6 +124,7 @@ pub fn process_claim_market_authority(
             ),
         )?;
         seat_manager_struct.clear_all_dmms();
+        seat_manager_struct.set_capacity(&market_size_params);
     }
 
     let claim_authority_instruction =
@@ -1,30 +1,41 @@
+use std::mem::size_of;
+
 use phoenix::program::{
     assert_with_msg,
-    checkers::{Signer, PDA},
+    checkers::{Program, Signer, PDA},
     create_change_seat_status_instruction, create_request_seat_authorized_instruction,
-    get_seat_address,
+    get_seat_address, load_with_dispatch,
     status::SeatApprovalStatus,
-    Seat,
+    system_utils::create_account,
+    MarketHeader, Seat,
 };
 use solana_program::{
     account_info::AccountInfo,
+    clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
-    system_instruction,
+    system_instruction, system_program,
     sysvar::Sysvar,
 };
 
 use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
     get_accounts_for_instruction, get_seat_deposit_collector_address,
-    loaders::{MarketAccount, SeatManagerAccount},
+    loaders::{MarketAccount, SeatManagerAccount, SeatRecordAccount},
+    role::SeatManagerRole,
+    seat_record::SeatRecord,
 };
 
+use super::{assert_capability_not_renounced, is_role_approved, record_admin_action};
+
 pub fn process_claim_seat(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     authorized: bool,
 ) -> ProgramResult {
@@ -37,7 +48,7 @@ pub fn process_claim_seat(
     if !seat_ai.data_is_empty() {
         // If the seat is already Approved and exists on the market, we can return Ok(())
         let seat_data = &seat_ai.try_borrow_data()?;
-        let seat_struct = bytemuck::from_bytes::<Seat>(&seat_data);
+        let seat_struct = bytemuck::from_bytes::<Seat>(seat_data);
         if SeatApprovalStatus::from(seat_struct.approval_status) == SeatApprovalStatus::Approved {
             return Ok(());
         }
@@ -56,14 +67,180 @@ pub fn process_claim_seat(
             ProgramError::MissingRequiredSignature,
             "Trader must sign",
         )?;
+        assert_with_msg(
+            !seat_manager.load()?.is_permissionless_claims_disabled(),
+            ProgramError::InvalidArgument,
+            "Permissionless seat claims are disabled",
+        )?;
+        assert_not_in_launch_phase(&seat_manager, trader_ai)?;
+        assert_non_dmm_seat_available(&market_ai, &seat_manager)?;
+        if seat_manager.load()?.reclaim_cooldown_in_slots > 0 {
+            let system_program = Program::new(&accounts[8], &system_program::id())?;
+            let seat_record_ai = accounts.get(9).ok_or_else(|| {
+                msg!("The seat record must be supplied while the seat manager has a re-claim cooldown");
+                ProgramError::NotEnoughAccountKeys
+            })?;
+            check_reclaim_cooldown(
+                program_id,
+                &market_ai,
+                &seat_manager,
+                trader_ai,
+                seat_record_ai,
+                &payer,
+                &system_program,
+            )?;
+        }
     } else {
         assert_with_msg(
-            *payer.key == seat_manager.load()?.authority,
+            is_role_approved(&*seat_manager.load()?, SeatManagerRole::AuthorizedClaimer, accounts)?,
             ProgramError::MissingRequiredSignature,
-            "If authorized, the payer must be the seat manager's authority",
+            "If authorized, the payer must be the seat manager's authority or authorized claimer, or a multisig holding either must be approved by its signers",
         )?;
+        assert_capability_not_renounced(
+            &*seat_manager.load()?,
+            SeatManagerCapability::AuthorizedClaims,
+        )?;
+        record_admin_action(
+            &seat_manager,
+            accounts,
+            payer.key,
+            AdminActionKind::ClaimSeatAuthorized,
+            trader_ai.key,
+            0,
+        )?;
+    }
+
+    claim_seat_cpi(
+        &market_ai,
+        &seat_manager,
+        &seat_deposit_collector,
+        trader_ai,
+        &seat_ai,
+        &payer,
+        accounts,
+    )
+}
+
+/// Fails during the seat manager's launch phase, unless the trader was pre-registered as a DMM.
+pub fn assert_not_in_launch_phase(
+    seat_manager: &SeatManagerAccount,
+    trader_ai: &AccountInfo,
+) -> ProgramResult {
+    let seat_manager = seat_manager.load()?;
+    assert_with_msg(
+        !seat_manager.is_in_launch_phase(Clock::get()?.slot)
+            || seat_manager.contains(trader_ai.key),
+        ProgramError::InvalidArgument,
+        &format!(
+            "Only DMMs can claim seats until the launch phase ends at slot {}",
+            seat_manager.launch_phase_end_slot
+        ),
+    )
+}
+
+/// Fails if the non-DMM traders on the market already hold every seat that is not reserved by the seat manager.
+pub fn assert_non_dmm_seat_available(
+    market_ai: &AccountInfo,
+    seat_manager: &SeatManagerAccount,
+) -> ProgramResult {
+    let seat_manager = seat_manager.load()?;
+    if seat_manager.num_reserved_seats == 0 {
+        return Ok(());
     }
+    let market_bytes = market_ai.data.borrow();
+    let (header_bytes, market_bytes) = market_bytes.split_at(size_of::<MarketHeader>());
+    let market_header = bytemuck::try_from_bytes::<MarketHeader>(header_bytes).map_err(|_| {
+        msg!("Invalid market header data");
+        ProgramError::InvalidAccountData
+    })?;
+    let market = load_with_dispatch(&market_header.market_size_params, market_bytes)?.inner;
+    let registered_traders = market.get_registered_traders();
 
+    let num_registered_dmms =
+        seat_manager.get_num_registered_dmms(|dmm| registered_traders.get(dmm).is_some());
+    let num_non_dmm_traders = registered_traders.len() - num_registered_dmms;
+    assert_with_msg(
+        num_non_dmm_traders < seat_manager.get_non_dmm_seat_cap(registered_traders.capacity()),
+        ProgramError::InvalidArgument,
+        &format!(
+            "All {} non-reserved seats are taken, the remaining seats are reserved",
+            seat_manager.get_non_dmm_seat_cap(registered_traders.capacity())
+        ),
+    )
+}
+
+/// Fails if the trader was evicted within the seat manager's re-claim cooldown.
+/// The seat record is created if it does not exist, so that the trader's next eviction can be recorded.
+pub fn check_reclaim_cooldown<'info>(
+    program_id: &Pubkey,
+    market_ai: &AccountInfo<'info>,
+    seat_manager: &SeatManagerAccount<'_, 'info>,
+    trader_ai: &AccountInfo<'info>,
+    seat_record_ai: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> ProgramResult {
+    let seat_record = SeatRecordAccount::new_with_market_and_trader(
+        seat_record_ai,
+        market_ai.key,
+        trader_ai.key,
+    )?;
+    if seat_record.data_is_empty() {
+        return create_seat_record(
+            program_id,
+            market_ai,
+            trader_ai,
+            &seat_record,
+            payer,
+            system_program,
+        );
+    }
+
+    let is_in_reclaim_cooldown = seat_record.load()?.is_in_reclaim_cooldown(
+        Clock::get()?.slot,
+        seat_manager.load()?.reclaim_cooldown_in_slots,
+    );
+    assert_with_msg(
+        !is_in_reclaim_cooldown,
+        ProgramError::InvalidArgument,
+        "Trader was recently evicted and cannot claim a seat until the re-claim cooldown ends",
+    )
+}
+
+pub fn create_seat_record<'info>(
+    program_id: &Pubkey,
+    market_ai: &AccountInfo<'info>,
+    trader_ai: &AccountInfo<'info>,
+    seat_record: &SeatRecordAccount<'_, 'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> ProgramResult {
+    msg!("Creating and initializing seat record account");
+    create_account(
+        payer,
+        seat_record,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        size_of::<SeatRecord>() as u64,
+        seat_record.seeds.clone(),
+    )?;
+    let mut seat_record = seat_record.load_mut()?;
+    seat_record.market = *market_ai.key;
+    seat_record.trader = *trader_ai.key;
+    Ok(())
+}
+
+/// Requests a seat for the trader if one does not exist, collects the seat deposit from the payer and approves the seat.
+pub fn claim_seat_cpi(
+    market_ai: &AccountInfo,
+    seat_manager: &SeatManagerAccount,
+    seat_deposit_collector: &AccountInfo,
+    trader_ai: &AccountInfo,
+    seat_ai: &AccountInfo,
+    payer: &AccountInfo,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
     if seat_ai.data_is_empty() {
         let request_seat_instruction = create_request_seat_authorized_instruction(
             seat_manager.key,
@@ -0,0 +1,84 @@
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Signer, PDA},
+    get_seat_address,
+    status::SeatApprovalStatus,
+    Seat,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
+    get_seat_deposit_collector_address,
+    loaders::{MarketAccount, SeatManagerAccount},
+    role::SeatManagerRole,
+};
+
+use super::{
+    assert_capability_not_renounced, claim_seat_cpi, is_role_approved, record_admin_action,
+};
+
+pub fn process_claim_seat_and_designate(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[2])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[3], market_ai.key)?;
+    let seat_deposit_collector = PDA::new(
+        &accounts[4],
+        &get_seat_deposit_collector_address(market_ai.key).0,
+    )?;
+    let trader_ai = &accounts[5];
+    // The authority or DMM manager pays for the seat. A multisig is approved by its signers, one of whom pays
+    let authority = Signer::new_payer(&accounts[6])?;
+    assert_with_msg(
+        is_role_approved(&*seat_manager.load()?, SeatManagerRole::DmmManager, accounts)?,
+        ProgramError::MissingRequiredSignature,
+        "The seat manager authority or DMM manager must approve claiming the seat and designating the trader",
+    )?;
+    assert_capability_not_renounced(&*seat_manager.load()?, SeatManagerCapability::AddDmms)?;
+    let seat_ai = PDA::new(
+        &accounts[7],
+        &get_seat_address(market_ai.key, trader_ai.key).0,
+    )?;
+
+    seat_manager
+        .load_mut()?
+        .insert(trader_ai.key)
+        .ok_or_else(|| {
+            msg!("Failed to add trader as DMM");
+            ProgramError::InvalidAccountData
+        })?;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        authority.key,
+        AdminActionKind::ClaimSeatAndDesignate,
+        trader_ai.key,
+        0,
+    )?;
+
+    // A trader that already holds a seat only needs to be designated
+    let is_approved = !seat_ai.data_is_empty() && {
+        let seat_data = &seat_ai.try_borrow_data()?;
+        let seat_struct = bytemuck::from_bytes::<Seat>(seat_data);
+        SeatApprovalStatus::from(seat_struct.approval_status) == SeatApprovalStatus::Approved
+    };
+    if is_approved {
+        return Ok(());
+    }
+
+    claim_seat_cpi(
+        &market_ai,
+        &seat_manager,
+        &seat_deposit_collector,
+        trader_ai,
+        &seat_ai,
+        &authority,
+        accounts,
+    )
+}
@@ -0,0 +1,127 @@
+use phoenix::program::{
+    assert_with_msg, checkers::PDA, get_seat_address, status::SeatApprovalStatus, Seat,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    admin_history::AdminActionKind, capability::SeatManagerCapability, role::SeatManagerRole,
+};
+
+use super::{
+    assert_capability_not_renounced, assert_non_dmm_seat_available, assert_not_in_launch_phase,
+    check_reclaim_cooldown, claim_seat_cpi, evict_trader_if_eligible, is_role_approved,
+    record_admin_action, EvictSeatContext,
+};
+
+pub fn process_claim_seat_with_eviction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authorized: bool,
+) -> ProgramResult {
+    let ctx = EvictSeatContext::load(accounts)?;
+    let trader_ai = &accounts[13];
+    let seat_ai = PDA::new(
+        &accounts[14],
+        &get_seat_address(ctx.market_ai.key, trader_ai.key).0,
+    )?;
+
+    if !authorized {
+        assert_with_msg(
+            trader_ai.is_signer,
+            ProgramError::MissingRequiredSignature,
+            "Trader must sign",
+        )?;
+        assert_with_msg(
+            ctx.seat_manager.load()?.num_waitlisted_traders == 0,
+            ProgramError::InvalidArgument,
+            "Freed seats are reserved for waitlisted traders",
+        )?;
+        assert_with_msg(
+            !ctx.seat_manager.load()?.is_permissionless_claims_disabled(),
+            ProgramError::InvalidArgument,
+            "Permissionless seat claims are disabled",
+        )?;
+        assert_not_in_launch_phase(&ctx.seat_manager, trader_ai)?;
+        if ctx.seat_manager.load()?.reclaim_cooldown_in_slots > 0 {
+            let seat_record_ai = accounts.get(15 + ctx.trader_accounts_len()).ok_or_else(|| {
+                msg!("The claiming trader's seat record must be supplied while the seat manager has a re-claim cooldown");
+                ProgramError::NotEnoughAccountKeys
+            })?;
+            check_reclaim_cooldown(
+                program_id,
+                &ctx.market_ai,
+                &ctx.seat_manager,
+                trader_ai,
+                seat_record_ai,
+                &ctx.signer,
+                &ctx.system_program,
+            )?;
+        }
+    } else {
+        assert_with_msg(
+            ctx.is_fully_authorized
+                && is_role_approved(
+                    &*ctx.seat_manager.load()?,
+                    SeatManagerRole::AuthorizedClaimer,
+                    accounts,
+                )?,
+            ProgramError::MissingRequiredSignature,
+            "If authorized, the signer must be the seat manager's authority, or hold both the privileged evictor and authorized claimer roles",
+        )?;
+        assert_capability_not_renounced(
+            &*ctx.seat_manager.load()?,
+            SeatManagerCapability::AuthorizedClaims,
+        )?;
+        record_admin_action(
+            &ctx.seat_manager,
+            accounts,
+            ctx.signer.key,
+            AdminActionKind::ClaimSeatWithEvictionAuthorized,
+            trader_ai.key,
+            0,
+        )?;
+    }
+
+    if !seat_ai.data_is_empty() {
+        let seat_data = &seat_ai.try_borrow_data()?;
+        let seat_struct = bytemuck::from_bytes::<Seat>(seat_data);
+        assert_with_msg(
+            SeatApprovalStatus::from(seat_struct.approval_status) != SeatApprovalStatus::Approved,
+            ProgramError::InvalidArgument,
+            "Trader already has an approved seat",
+        )?;
+    }
+
+    let evicted_trader_accounts = ctx.load_trader_accounts(accounts[15..].iter())?;
+    assert_with_msg(
+        evicted_trader_accounts.trader.key != trader_ai.key,
+        ProgramError::InvalidArgument,
+        "Trader cannot evict their own seat",
+    )?;
+
+    // The eviction must succeed under the same rules as EvictSeat, otherwise the claim would not have a free seat to fill
+    let outcome = evict_trader_if_eligible(program_id, &ctx, evicted_trader_accounts, accounts)?;
+    assert_with_msg(
+        outcome.is_evicted(),
+        ProgramError::InvalidArgument,
+        "Seat is not eligible for eviction",
+    )?;
+    // Checked once the seat is freed, so that a non-DMM trader can take a non-DMM trader's seat at the cap,
+    // but cannot refill a seat beyond it
+    if !authorized {
+        assert_non_dmm_seat_available(&ctx.market_ai, &ctx.seat_manager)?;
+    }
+
+    claim_seat_cpi(
+        &ctx.market_ai,
+        &ctx.seat_manager,
+        &ctx.seat_deposit_collector,
+        trader_ai,
+        &seat_ai,
+        &ctx.signer,
+        accounts,
+    )
+}
@@ -1,16 +1,18 @@
-use crate::loaders::SeatManagerAccount;
-use phoenix::program::{assert_with_msg, checkers::Signer};
+use crate::{admin_history::AdminActionKind, loaders::SeatManagerAccount, role::NUM_ROLES};
+use phoenix::program::assert_with_msg;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
+use super::{assert_authority_approved, record_admin_action};
+
 pub fn process_confirm_renounce_seat_manager_authority(
     _program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let seat_manager = SeatManagerAccount::new(&accounts[0])?;
-    let _authority = Signer::new_with_key(&accounts[1], &seat_manager.load()?.authority)?;
+    assert_authority_approved(&accounts[1], &seat_manager.load()?.authority, accounts)?;
 
     let immutable_authority = Pubkey::default();
     assert_with_msg(
@@ -19,7 +21,27 @@ pub fn process_confirm_renounce_seat_manager_authority(
         "The successor to the seat manager authority must be the system program to renounce the seat manager authority. Initiate the renounce process by setting the succesor to the system program.",
     )?;
 
-    seat_manager.load_mut()?.authority = immutable_authority;
+    // Queued actions would otherwise let anyone act on behalf of the renounced authority
+    assert_with_msg(
+        seat_manager.load()?.num_pending_actions == 0,
+        ProgramError::InvalidArgument,
+        "Cancel or execute the pending timelocked actions before renouncing the seat manager authority",
+    )?;
+
+    {
+        let mut seat_manager = seat_manager.load_mut()?;
+        seat_manager.authority = immutable_authority;
+        // Delegated roles and the guardian would otherwise keep administering the market
+        seat_manager.roles = [Pubkey::default(); NUM_ROLES];
+        seat_manager.guardian = Pubkey::default();
+    }
 
-    Ok(())
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[1].key,
+        AdminActionKind::ConfirmRenounceSeatManagerAuthority,
+        &Pubkey::default(),
+        0,
+    )
 }
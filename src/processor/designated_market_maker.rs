@@ -2,11 +2,18 @@ use std::mem::size_of;
 
 use phoenix::program::{checkers::Signer, dispatch_market, MarketHeader};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
-use crate::loaders::{MarketAccount, SeatManagerAccount};
+use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
+    loaders::{MarketAccount, SeatManagerAccount},
+    role::SeatManagerRole,
+};
+
+use super::{assert_capability_not_renounced, assert_role_approved, record_admin_action};
 
 pub fn process_designated_market_maker(
     _program_id: &Pubkey,
@@ -16,9 +23,65 @@ pub fn process_designated_market_maker(
     let market_ai = MarketAccount::new_with_checked_discriminant(&accounts[0])?;
     let seat_manager_ai = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
     let trader_ai = &accounts[2];
-    // Checks that _authority is the valid authority for the seat_manager and a signer
-    let _authority = Signer::new_with_key(&accounts[3], &seat_manager_ai.load()?.authority)?;
+    // Checks that the signer is the seat manager's authority or the DMM manager, and that it approved
+    assert_role_approved(
+        &accounts[3],
+        &*seat_manager_ai.load()?,
+        SeatManagerRole::DmmManager,
+        accounts,
+    )?;
+
+    let kind = if !remove {
+        add_designated_market_maker(&market_ai, &seat_manager_ai, trader_ai.key)?;
+        AdminActionKind::AddDmm
+    } else {
+        assert_capability_not_renounced(
+            &*seat_manager_ai.load()?,
+            SeatManagerCapability::RemoveDmms,
+        )?;
+        // A DMM pre-registered during the launch phase can be removed before it claims its seat
+        assert_trader_has_seat(&market_ai, &seat_manager_ai, trader_ai.key)?;
+        seat_manager_ai
+            .load_mut()?
+            .remove(trader_ai.key)
+            .ok_or_else(|| {
+                msg!("Failed to remove trader as DMM, since they are not a DMM");
+                ProgramError::InvalidAccountData
+            })?;
+        AdminActionKind::RemoveDmm
+    };
+    record_admin_action(
+        &seat_manager_ai,
+        accounts,
+        accounts[3].key,
+        kind,
+        trader_ai.key,
+        0,
+    )
+}
 
+/// Adds the trader as a DMM. The trader must have a seat on the market, unless the market is in its launch phase.
+pub(crate) fn add_designated_market_maker(
+    market_ai: &MarketAccount,
+    seat_manager_ai: &SeatManagerAccount,
+    trader: &Pubkey,
+) -> ProgramResult {
+    assert_capability_not_renounced(&*seat_manager_ai.load()?, SeatManagerCapability::AddDmms)?;
+    assert_trader_has_seat(market_ai, seat_manager_ai, trader)?;
+    seat_manager_ai.load_mut()?.insert(trader).ok_or_else(|| {
+        msg!("Failed to add trader as DMM");
+        ProgramError::InvalidAccountData
+    })?;
+    Ok(())
+}
+
+/// DMMs are added and removed by the authority only while they have a seat, except during the launch phase
+/// when they can be pre-registered before they claim their seats.
+fn assert_trader_has_seat(
+    market_ai: &MarketAccount,
+    seat_manager_ai: &SeatManagerAccount,
+    trader: &Pubkey,
+) -> ProgramResult {
     let market_bytes = market_ai.data.borrow();
     let (header_bytes, market_bytes) = market_bytes.split_at(size_of::<MarketHeader>());
     let market_header = bytemuck::try_from_bytes::<MarketHeader>(header_bytes).map_err(|_| {
@@ -28,28 +91,27 @@ pub fn process_designated_market_maker(
     let market =
         dispatch_market::load_with_dispatch(&market_header.market_size_params, market_bytes)?.inner;
 
-    let registered_traders = market.get_registered_traders();
-    if registered_traders.contains(trader_ai.key) {
-        if !remove {
-            seat_manager_ai
-                .load_mut()?
-                .insert(trader_ai.key)
-                .ok_or_else(|| {
-                    msg!("Failed to add trader as DMM");
-                    ProgramError::InvalidAccountData
-                })?;
-        } else {
-            seat_manager_ai
-                .load_mut()?
-                .remove(trader_ai.key)
-                .ok_or_else(|| {
-                    msg!("Failed to remove trader as DMM, since they are not a DMM");
-                    ProgramError::InvalidAccountData
-                })?;
-        }
-    } else {
+    let is_in_launch_phase = seat_manager_ai
+        .load()?
+        .is_in_launch_phase(Clock::get()?.slot);
+    if !market.get_registered_traders().contains(trader) && !is_in_launch_phase {
         msg!("Trader must have a seat on the market");
         return Err(ProgramError::InvalidAccountData);
     }
     Ok(())
 }
+
+pub fn process_resign_dmm(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let market_ai = MarketAccount::new_with_checked_discriminant(&accounts[0])?;
+    let seat_manager_ai = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let trader = Signer::new(&accounts[2])?;
+
+    seat_manager_ai
+        .load_mut()?
+        .remove(trader.key)
+        .ok_or_else(|| {
+            msg!("Failed to resign as DMM, since the trader is not a DMM");
+            ProgramError::InvalidAccountData
+        })?;
+    Ok(())
+}
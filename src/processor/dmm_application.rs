@@ -0,0 +1,125 @@
+use std::mem::size_of;
+
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Program, Signer},
+    system_utils::create_account,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    close_account,
+    dmm_application::DmmApplication,
+    loaders::{DmmApplicationAccount, MarketAccount, SeatManagerAccount},
+    role::SeatManagerRole,
+};
+
+use super::{add_designated_market_maker, assert_role_approved, record_admin_action};
+
+pub fn process_apply_for_dmm(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let market_ai = MarketAccount::new_with_checked_discriminant(&accounts[0])?;
+    let seat_manager_ai = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let trader = Signer::new_payer(&accounts[3])?;
+    let dmm_application =
+        DmmApplicationAccount::new_with_market_and_trader(&accounts[2], market_ai.key, trader.key)?;
+    let system_program = Program::new(&accounts[4], &system_program::id())?;
+
+    assert_with_msg(
+        !seat_manager_ai.load()?.contains(trader.key),
+        ProgramError::InvalidArgument,
+        "Trader is already a designated market maker",
+    )?;
+    assert_with_msg(
+        dmm_application.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "Trader already has a pending DMM application",
+    )?;
+
+    msg!("Creating DMM application for {}", trader.key);
+    create_account(
+        &trader,
+        &dmm_application,
+        &system_program,
+        program_id,
+        &Rent::get()?,
+        size_of::<DmmApplication>() as u64,
+        dmm_application.seeds.clone(),
+    )?;
+    let mut dmm_application = dmm_application.load_mut()?;
+    dmm_application.market = *market_ai.key;
+    dmm_application.trader = *trader.key;
+    dmm_application.application_slot = Clock::get()?.slot;
+    Ok(())
+}
+
+pub fn process_withdraw_dmm_application(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new_with_checked_discriminant(&accounts[0])?;
+    let trader = Signer::new(&accounts[2])?;
+    let dmm_application =
+        DmmApplicationAccount::new_with_market_and_trader(&accounts[1], market_ai.key, trader.key)?;
+
+    assert_with_msg(
+        !dmm_application.data_is_empty(),
+        ProgramError::UninitializedAccount,
+        "Trader does not have a pending DMM application",
+    )?;
+
+    msg!("Withdrawing DMM application for {}", trader.key);
+    // Close the application and refund the rent to the trader
+    close_account(&dmm_application, &trader)
+}
+
+pub fn process_review_dmm_application(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    approve: bool,
+) -> ProgramResult {
+    let market_ai = MarketAccount::new_with_checked_discriminant(&accounts[0])?;
+    let seat_manager_ai = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let trader_ai = &accounts[3];
+    let dmm_application = DmmApplicationAccount::new_with_market_and_trader(
+        &accounts[2],
+        market_ai.key,
+        trader_ai.key,
+    )?;
+    // Checks that the signer is the seat manager's authority or the DMM manager, and that it approved
+    assert_role_approved(
+        &accounts[4],
+        &*seat_manager_ai.load()?,
+        SeatManagerRole::DmmManager,
+        accounts,
+    )?;
+
+    assert_with_msg(
+        !dmm_application.data_is_empty(),
+        ProgramError::UninitializedAccount,
+        "Trader does not have a pending DMM application",
+    )?;
+
+    let kind = if approve {
+        msg!("Approving DMM application for {}", trader_ai.key);
+        add_designated_market_maker(&market_ai, &seat_manager_ai, trader_ai.key)?;
+        AdminActionKind::ApproveDmmApplication
+    } else {
+        msg!("Rejecting DMM application for {}", trader_ai.key);
+        AdminActionKind::RejectDmmApplication
+    };
+    record_admin_action(
+        &seat_manager_ai,
+        accounts,
+        accounts[4].key,
+        kind,
+        trader_ai.key,
+        0,
+    )?;
+
+    // Close the application and refund the rent to the trader
+    close_account(&dmm_application, trader_ai)
+}
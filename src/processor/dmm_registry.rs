@@ -0,0 +1,134 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Program, Signer},
+    system_utils::create_account,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
+    dmm_registry::DmmRegistry,
+    loaders::{DmmRegistryAccount, MarketAccount, SeatManagerAccount},
+    role::SeatManagerRole,
+};
+
+use super::{
+    assert_authority_approved, assert_capability_not_renounced, assert_role_approved,
+    record_admin_action,
+};
+
+pub fn process_create_dmm_registry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let creator = Signer::new(&accounts[1])?;
+    let dmm_registry = DmmRegistryAccount::new_with_creator(&accounts[0], creator.key)?;
+    let payer = Signer::new_payer(&accounts[2])?;
+    let system_program = Program::new(&accounts[3], &system_program::id())?;
+
+    let authority = Pubkey::try_from_slice(data)?;
+    assert_with_msg(
+        dmm_registry.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "DMM registry already exists for this creator",
+    )?;
+
+    msg!("Creating and initializing DMM registry account");
+    create_account(
+        &payer,
+        &dmm_registry,
+        &system_program,
+        program_id,
+        &Rent::get()?,
+        size_of::<DmmRegistry>() as u64,
+        dmm_registry.seeds.clone(),
+    )?;
+    let mut dmm_registry = dmm_registry.load_mut()?;
+    dmm_registry.creator = *creator.key;
+    dmm_registry.authority = authority;
+    Ok(())
+}
+
+/// Adding a DMM to the registry protects them on every linked market, whether or not they have a seat there
+pub fn process_update_dmm_registry(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+    remove: bool,
+) -> ProgramResult {
+    let dmm_registry = DmmRegistryAccount::new(&accounts[0])?;
+    let authority = dmm_registry.load()?.authority;
+    assert_authority_approved(&accounts[1], &authority, accounts)?;
+
+    let trader = Pubkey::try_from_slice(data)?;
+    let mut dmm_registry = dmm_registry.load_mut()?;
+    if !remove {
+        msg!("Adding {} to the DMM registry", trader);
+        dmm_registry.insert(&trader).ok_or_else(|| {
+            msg!("Failed to add trader to the DMM registry");
+            ProgramError::InvalidArgument
+        })?;
+    } else {
+        msg!("Removing {} from the DMM registry", trader);
+        dmm_registry.remove(&trader).ok_or_else(|| {
+            msg!("Failed to remove trader from the DMM registry, since they are not in it");
+            ProgramError::InvalidArgument
+        })?;
+    }
+    Ok(())
+}
+
+/// Links the seat manager to a DMM registry, or unlinks it if the registry is the default pubkey.
+/// Linking a registry adds its DMMs and unlinking removes them, so each is gated by the matching capability.
+pub fn process_set_dmm_registry(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    // Checks that the signer is the seat manager's authority or the DMM manager, and that it approved
+    assert_role_approved(
+        &accounts[2],
+        &*seat_manager.load()?,
+        SeatManagerRole::DmmManager,
+        accounts,
+    )?;
+
+    let dmm_registry = Pubkey::try_from_slice(data)?;
+    if seat_manager.load()?.get_dmm_registry().is_some() {
+        assert_capability_not_renounced(&*seat_manager.load()?, SeatManagerCapability::RemoveDmms)?;
+    }
+    if dmm_registry != Pubkey::default() {
+        assert_capability_not_renounced(&*seat_manager.load()?, SeatManagerCapability::AddDmms)?;
+        let dmm_registry_ai = accounts.get(3).ok_or_else(|| {
+            msg!("The DMM registry account must be supplied to link it");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        assert_with_msg(
+            *dmm_registry_ai.key == dmm_registry,
+            ProgramError::InvalidArgument,
+            "DMM registry account does not match the instruction data",
+        )?;
+        DmmRegistryAccount::new(dmm_registry_ai)?;
+    }
+
+    msg!("Setting the DMM registry to {}", dmm_registry);
+    seat_manager.load_mut()?.dmm_registry = dmm_registry;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::SetDmmRegistry,
+        &dmm_registry,
+        0,
+    )
+}
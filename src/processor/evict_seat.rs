@@ -1,11 +1,24 @@
-use std::{mem::size_of, slice::Iter};
+use std::mem::size_of;
 
 use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
+    dmm_registry::DmmRegistry,
+    eviction_policy::EvictionPrivileges,
     get_accounts_for_instruction, get_seat_deposit_collector_address,
-    get_seat_deposit_collector_seeds,
-    loaders::{AssociatedTokenAccount, BackupTokenAccount, MarketAccount, SeatManagerAccount},
+    get_seat_deposit_collector_seeds, get_seat_record_address, get_seat_waitlist_address,
+    loaders::{
+        AssociatedTokenAccount, BackupTokenAccount, DmmRegistryAccount, MarketAccount,
+        SeatManagerAccount, SeatRecordAccount, SeatWaitlistAccount,
+    },
+    role::SeatManagerRole,
+    seat_manager::SeatManager,
+    seat_record::SeatRecord,
 };
-use itertools::{Chunk, Itertools};
+
+use super::{approve_next_waitlisted_trader, is_role_approved, record_admin_action};
+use borsh::BorshDeserialize;
+use itertools::Itertools;
 use phoenix::{
     program::{
         assert_with_msg,
@@ -14,29 +27,32 @@ use phoenix::{
         status::SeatApprovalStatus,
         MarketHeader, MarketSizeParams,
     },
+    quantities::WrapperU64,
     state::TraderState,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, program_pack::Pack, pubkey::Pubkey, rent::Rent,
-    system_instruction, system_program, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+    rent::Rent, system_instruction, system_program, sysvar::Sysvar,
 };
 use spl_associated_token_account::instruction::create_associated_token_account;
 
-struct TraderAccountsContext<'a, 'info> {
-    trader: &'a AccountInfo<'info>,
+pub struct TraderAccountsContext<'a, 'info> {
+    pub trader: &'a AccountInfo<'info>,
     _seat: &'a AccountInfo<'info>,
     base_associated_token_account: AssociatedTokenAccount<'a, 'info>,
     quote_associated_token_account: AssociatedTokenAccount<'a, 'info>,
     backup_base_token_account: BackupTokenAccount<'a, 'info>,
     backup_quote_token_account: BackupTokenAccount<'a, 'info>,
+    /// Only supplied when the seat manager requires seat records
+    seat_record: Option<SeatRecordAccount<'a, 'info>>,
 }
 
 impl<'a, 'info> TraderAccountsContext<'a, 'info> {
-    pub fn load_from_chunk_iter(
+    pub fn load_from_iter(
         base_mint: &Pubkey,
         quote_mint: &Pubkey,
-        mut account_iter: Chunk<'a, Iter<'a, AccountInfo<'info>>>,
+        mut account_iter: impl Iterator<Item = &'a AccountInfo<'info>>,
     ) -> Result<Self, ProgramError> {
         let trader = account_iter.next().ok_or_else(|| {
             msg!("Missing trader account");
@@ -77,198 +93,517 @@ impl<'a, 'info> TraderAccountsContext<'a, 'info> {
                     ProgramError::NotEnoughAccountKeys
                 })
                 .and_then(|ai| BackupTokenAccount::new(ai, quote_mint, &trader_key))?,
+            seat_record: None,
         })
     }
 }
 
-pub fn process_evict_seat(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let market_ai = MarketAccount::new(&accounts[2])?;
-    let seat_manager = SeatManagerAccount::new_with_market(&accounts[3], market_ai.key)?;
-    let seat_deposit_collector = PDA::new(
-        &accounts[4],
-        &get_seat_deposit_collector_address(market_ai.key).0,
-    )?;
-    let system_program = Program::new(&accounts[11], &system_program::id())?;
-    let signer = Signer::new(&accounts[12])?;
-
-    // Assert valid mint accounts and signer
-    let base_mint_ai = MintAccountInfo::new(&accounts[5])?;
-    let quote_mint_ai = MintAccountInfo::new(&accounts[6])?;
-
-    // Retrieve seat manager seeds and check if signer is authorized
-    let is_fully_authorized = *signer.key == seat_manager.load()?.authority;
-
-    // Get market parameters to perform checks
-    let (base_mint, quote_mint, market_size_params, has_eviction_privileges) = {
-        let market_bytes = market_ai.data.borrow();
-        let (header_bytes, market_bytes) = market_bytes.split_at(size_of::<MarketHeader>());
-        let market_header =
-            bytemuck::try_from_bytes::<MarketHeader>(header_bytes).map_err(|_| {
-                msg!("Invalid market header data");
-                ProgramError::InvalidAccountData
-            })?;
-        let (base_mint, quote_mint) = (
-            market_header.base_params.mint_key,
-            market_header.quote_params.mint_key,
-        );
-        let market =
-            dispatch_market::load_with_dispatch(&market_header.market_size_params, market_bytes)?
-                .inner;
-
-        let registered_traders = market.get_registered_traders();
-
-        // When this boolean is true, signer has the privilege to evict any seat with 0 locked base lots and 0 locked quote lots
-        let has_eviction_privileges =
-            registered_traders.capacity() == registered_traders.len();
+/// The accounts and market parameters shared by every eviction in an instruction.
+/// Accounts 0 through 12 follow the `EvictSeat` layout.
+/// If the seat manager has a heartbeat staleness window or a re-claim cooldown, each evicted trader's accounts end with their seat record.
+pub struct EvictSeatContext<'a, 'info> {
+    pub market_ai: MarketAccount<'a, 'info>,
+    pub seat_manager: SeatManagerAccount<'a, 'info>,
+    pub seat_deposit_collector: PDA<'a, 'info>,
+    pub system_program: Program<'a, 'info>,
+    pub signer: Signer<'a, 'info>,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub market_size_params: MarketSizeParams,
+    pub is_market_full: bool,
+    pub eviction_privileges: EvictionPrivileges,
+    pub is_fully_authorized: bool,
+    pub heartbeat_staleness_window_in_slots: u64,
+    pub requires_seat_records: bool,
+    /// Seats that are not empty can only be evicted if the trader's heartbeat is stale
+    pub requires_stale_heartbeat: bool,
+    /// The seat manager's linked DMM registry, which can be anywhere in the accounts
+    pub dmm_registry: Option<DmmRegistryAccount<'a, 'info>>,
+}
 
-        assert_with_msg(
-            base_mint_ai.info.key == &base_mint,
-            ProgramError::InvalidAccountData,
-            "Base mint mismatch",
+impl<'a, 'info> EvictSeatContext<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let market_ai = MarketAccount::new(&accounts[2])?;
+        let seat_manager = SeatManagerAccount::new_with_market(&accounts[3], market_ai.key)?;
+        let seat_deposit_collector = PDA::new(
+            &accounts[4],
+            &get_seat_deposit_collector_address(market_ai.key).0,
         )?;
+        let system_program = Program::new(&accounts[11], &system_program::id())?;
+        let signer = Signer::new(&accounts[12])?;
+
+        // Assert valid mint accounts and signer
+        let base_mint_ai = MintAccountInfo::new(&accounts[5])?;
+        let quote_mint_ai = MintAccountInfo::new(&accounts[6])?;
+
+        // Retrieve seat manager seeds and check if signer is authorized.
+        // Once privileged eviction is renounced, the authority evicts under the permissionless rules
+        let is_fully_authorized = is_role_approved(
+            &*seat_manager.load()?,
+            SeatManagerRole::PrivilegedEvictor,
+            accounts,
+        )? && !seat_manager
+            .load()?
+            .is_renounced(SeatManagerCapability::PrivilegedEviction);
         assert_with_msg(
-            quote_mint_ai.info.key == &quote_mint,
-            ProgramError::InvalidAccountData,
-            "Quote mint mismatch",
+            is_fully_authorized || !seat_manager.load()?.is_permissionless_eviction_disabled(),
+            ProgramError::MissingRequiredSignature,
+            "Permissionless eviction is disabled, the signer must be the seat manager's authority",
         )?;
-        (
+
+        let dmm_registry_key = seat_manager.load()?.get_dmm_registry();
+        let dmm_registry = dmm_registry_key
+            .map(|dmm_registry_key| {
+                accounts
+                    .iter()
+                    .find(|ai| *ai.key == dmm_registry_key)
+                    .ok_or_else(|| {
+                        msg!("The linked DMM registry must be supplied");
+                        ProgramError::NotEnoughAccountKeys
+                    })
+                    .and_then(DmmRegistryAccount::new)
+            })
+            .transpose()?;
+
+        let (heartbeat_staleness_window_in_slots, requires_seat_records) = {
+            let seat_manager = seat_manager.load()?;
+            (
+                seat_manager.heartbeat_staleness_window_in_slots,
+                seat_manager.requires_seat_records(),
+            )
+        };
+
+        // Get market parameters to perform checks
+        let (base_mint, quote_mint, market_size_params, is_market_full, eviction_privileges) = {
+            let market_bytes = market_ai.data.borrow();
+            let (header_bytes, market_bytes) = market_bytes.split_at(size_of::<MarketHeader>());
+            let market_header =
+                bytemuck::try_from_bytes::<MarketHeader>(header_bytes).map_err(|_| {
+                    msg!("Invalid market header data");
+                    ProgramError::InvalidAccountData
+                })?;
+            let (base_mint, quote_mint) = (
+                market_header.base_params.mint_key,
+                market_header.quote_params.mint_key,
+            );
+            let market = dispatch_market::load_with_dispatch(
+                &market_header.market_size_params,
+                market_bytes,
+            )?
+            .inner;
+
+            let registered_traders = market.get_registered_traders();
+
+            let is_market_full = registered_traders.capacity() == registered_traders.len();
+
+            // The seat manager's eviction policy decides which seats the signer can evict
+            let eviction_privileges = {
+                let seat_manager = seat_manager.load()?;
+                let num_registered_dmms = seat_manager
+                    .get_num_registered_dmms(|dmm| registered_traders.get(dmm).is_some());
+                seat_manager.get_eviction_privileges(
+                    is_fully_authorized,
+                    registered_traders.len(),
+                    registered_traders.capacity(),
+                    num_registered_dmms,
+                )
+            };
+
+            assert_with_msg(
+                base_mint_ai.info.key == &base_mint,
+                ProgramError::InvalidAccountData,
+                "Base mint mismatch",
+            )?;
+            assert_with_msg(
+                quote_mint_ai.info.key == &quote_mint,
+                ProgramError::InvalidAccountData,
+                "Quote mint mismatch",
+            )?;
+            (
+                base_mint,
+                quote_mint,
+                market_header.market_size_params,
+                is_market_full,
+                eviction_privileges,
+            )
+        };
+
+        Ok(Self {
+            market_ai,
+            seat_manager,
+            seat_deposit_collector,
+            system_program,
+            signer,
             base_mint,
             quote_mint,
-            market_header.market_size_params,
-            has_eviction_privileges,
-        )
-    };
+            market_size_params,
+            is_market_full,
+            eviction_privileges,
+            is_fully_authorized,
+            heartbeat_staleness_window_in_slots,
+            requires_seat_records,
+            // Heartbeats protect active seats from the authority as well once the market is full
+            requires_stale_heartbeat: heartbeat_staleness_window_in_slots > 0
+                && (!is_fully_authorized || is_market_full),
+            dmm_registry,
+        })
+    }
 
-    // Perform eviction for trader(s)
-    for trader_accounts in &accounts[13..].iter().chunks(6) {
-        let TraderAccountsContext {
-            trader: trader_ai,
-            _seat,
-            base_associated_token_account,
-            quote_associated_token_account,
-            backup_base_token_account,
-            backup_quote_token_account,
-        } = TraderAccountsContext::load_from_chunk_iter(&base_mint, &quote_mint, trader_accounts)?;
-
-        // Check if trader is a DMM; if so, continue (cannot evict a DMM)
-        if seat_manager.load()?.contains(trader_ai.key) {
-            continue;
+    /// Whether the trader is in the seat manager's DMMs or in its linked DMM registry
+    pub fn is_dmm(&self, trader: &Pubkey) -> Result<bool, ProgramError> {
+        let seat_manager = self.seat_manager.load()?;
+        if seat_manager.contains(trader) {
+            return Ok(true);
+        }
+        match self.dmm_registry.as_ref() {
+            Some(dmm_registry) if seat_manager.honours_dmm_registry() => {
+                Ok(dmm_registry.load()?.contains(trader))
+            }
+            _ => Ok(false),
         }
+    }
 
-        // Retrieve trader state if available and ensure no lots are locked before performing eviction-related actions
-        if let Some(trader_state) =
-            retrieve_trader_state(&market_ai, &market_size_params, trader_ai)?
+    /// Traders whose seat records, supplied anywhere in the accounts, show a recent heartbeat.
+    /// Seat records are only read at their expected addresses, and are ignored if heartbeats do not protect seats
+    pub fn get_traders_with_recent_heartbeat(
+        &self,
+        accounts: &[AccountInfo],
+    ) -> Result<Vec<Pubkey>, ProgramError> {
+        if !self.requires_stale_heartbeat {
+            return Ok(vec![]);
+        }
+        let current_slot = Clock::get()?.slot;
+        let mut traders = vec![];
+        for ai in accounts
+            .iter()
+            .filter(|ai| *ai.owner == crate::id() && ai.data_len() == size_of::<SeatRecord>())
         {
-            // If a trader's seat has 0 locked base lots, 0 locked quote lots, 0 free base lots, and 0 free quote lots, then anyone can remove it
-            let seat_is_empty = trader_state.base_lots_locked == 0
-                && trader_state.quote_lots_locked == 0
-                && trader_state.base_lots_free == 0
-                && trader_state.quote_lots_free == 0;
-
-            let can_evict_trader = if has_eviction_privileges || is_fully_authorized {
-                trader_state.base_lots_locked == 0 && trader_state.quote_lots_locked == 0
-            } else {
-                seat_is_empty
-            };
+            let seat_record = *SeatRecord::load(&ai.try_borrow_data()?)?;
+            if seat_record.market == *self.market_ai.key
+                && *ai.key == get_seat_record_address(&seat_record.market, &seat_record.trader).0
+                && !seat_record.is_stale(current_slot, self.heartbeat_staleness_window_in_slots)
+            {
+                traders.push(seat_record.trader);
+            }
+        }
+        Ok(traders)
+    }
 
-            if can_evict_trader {
-                // Change seat status
-                change_seat_status_not_approved_cpi(
-                    &market_ai,
-                    &seat_manager,
-                    trader_ai,
-                    accounts,
-                    seat_manager.seeds.clone(),
-                )?;
+    /// The number of accounts supplied for each evicted trader
+    pub fn trader_accounts_len(&self) -> usize {
+        if self.requires_seat_records {
+            7
+        } else {
+            6
+        }
+    }
 
-                // Check ATAs for base and quote; if doesn't exist, create ATAs, using the deposit collected at the time of claim seat.
-                // If ATAs are already created and belong to the traders, then refund the deposit collected at the time of claim seat back to the trader
-                // In the edge case that the ATA is created but does not belong to the trader, refund the deposit to the signer, the likely creator and rent-payer of the backup token accounts.
-                let seat_deposit_collector_seeds = get_seat_deposit_collector_seeds(
-                    market_ai.key,
-                    seat_deposit_collector.key,
-                    program_id,
-                )?;
-                let mut total_trader_refund = 0;
-                let mut total_signer_refund = 0;
-
-                for (associated_token_account, token_mint) in [
-                    (&base_associated_token_account, base_mint),
-                    (&quote_associated_token_account, quote_mint),
-                ] {
-                    // Calculate appropriate refund amounts and recipients based on ATA context
-                    let (trader_refund, signer_refund) = get_trader_and_signer_refund_amounts(
-                        associated_token_account.is_initialized,
-                        associated_token_account.has_expected_owner,
-                    )?;
-
-                    total_trader_refund += trader_refund;
-                    total_signer_refund += signer_refund;
-
-                    create_ata_if_needed(
-                        trader_ai,
-                        &token_mint,
-                        associated_token_account,
-                        &seat_deposit_collector,
-                        seat_deposit_collector_seeds.clone(),
-                        accounts,
-                    )?;
-                }
-
-                let minimum_rent_for_token_account =
-                    Rent::get()?.minimum_balance(spl_token::state::Account::LEN);
-
-                assert_with_msg(
-                    total_trader_refund + total_signer_refund <= minimum_rent_for_token_account * 2,
-                    ProgramError::InvalidAccountData,
-                    "Total refund cannot exceed rent for two token accounts. Check token account inputs."
-                )?;
+    pub fn load_trader_accounts(
+        &self,
+        mut account_iter: impl Iterator<Item = &'a AccountInfo<'info>>,
+    ) -> Result<TraderAccountsContext<'a, 'info>, ProgramError> {
+        let mut trader_accounts = TraderAccountsContext::load_from_iter(
+            &self.base_mint,
+            &self.quote_mint,
+            account_iter.by_ref(),
+        )?;
+        if self.requires_seat_records {
+            trader_accounts.seat_record = Some(
+                account_iter
+                    .next()
+                    .ok_or_else(|| {
+                        msg!("Missing seat record account");
+                        ProgramError::NotEnoughAccountKeys
+                    })
+                    .and_then(|ai| {
+                        SeatRecordAccount::new_with_market_and_trader(
+                            ai,
+                            self.market_ai.key,
+                            trader_accounts.trader.key,
+                        )
+                    })?,
+            );
+        }
+        Ok(trader_accounts)
+    }
+}
 
-                // Handle refunds if any to trader and signer
-                handle_refund(
-                    total_trader_refund,
-                    trader_ai,
-                    &seat_deposit_collector,
-                    seat_deposit_collector_seeds.clone(),
-                    &system_program,
-                )?;
-                handle_refund(
-                    total_signer_refund,
-                    &signer,
-                    &seat_deposit_collector,
-                    seat_deposit_collector_seeds,
-                    &system_program,
-                )?;
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EvictionOutcome {
+    /// The trader is a DMM, has no seat, or the signer's eviction privileges do not cover the seat
+    NotEvicted,
+    /// The seat had no locked lots, and no free lots beyond the seat manager's dust threshold
+    EvictedEmptySeat,
+    /// The seat had no locked lots, but still had free lots above the dust threshold that were returned to the trader
+    EvictedNonEmptySeat,
+}
+
+impl EvictionOutcome {
+    pub fn is_evicted(&self) -> bool {
+        *self != EvictionOutcome::NotEvicted
+    }
+}
+
+/// The instruction data is the number of evicted traders. Instructions without it predate the accounts that can follow
+/// the evicted traders, so every account after the signer belongs to an evicted trader and they are only accepted
+/// while the seat manager requires none of those accounts.
+pub fn process_evict_seat(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let ctx = EvictSeatContext::load(accounts)?;
+
+    // The seat waitlist and the seats and accounts of the waitlisted traders can follow the trader accounts.
+    // While traders are waitlisted, every freed seat must go to the front of the waitlist.
+    // A multisig or admin group and their signers, the admin history and the DMM registry can come anywhere after.
+    let trader_accounts_end = if data.is_empty() {
+        assert_no_accounts_after_traders_required(&ctx)?;
+        accounts.len()
+    } else {
+        let num_traders = u8::try_from_slice(data)? as usize;
+        13 + num_traders * ctx.trader_accounts_len()
+    };
+    assert_with_msg(
+        trader_accounts_end <= accounts.len()
+            && (trader_accounts_end - 13) % ctx.trader_accounts_len() == 0,
+        ProgramError::NotEnoughAccountKeys,
+        "Each evicted trader must have a complete set of trader accounts",
+    )?;
+    let seat_waitlist_key = get_seat_waitlist_address(ctx.market_ai.key).0;
+    let seat_waitlist = accounts
+        .get(trader_accounts_end)
+        .filter(|ai| *ai.key == seat_waitlist_key)
+        .map(|ai| SeatWaitlistAccount::new_with_market(ai, ctx.market_ai.key))
+        .transpose()?;
+    assert_with_msg(
+        ctx.seat_manager.load()?.num_waitlisted_traders == 0 || seat_waitlist.is_some(),
+        ProgramError::NotEnoughAccountKeys,
+        "The seat waitlist must be supplied while traders are waitlisted",
+    )?;
 
-                // Evict seat for trader
-                let evict_seat_cpi_context = EvictSeatCpiContext {
-                    base_mint,
-                    quote_mint,
-                    base_ata_owner_match: base_associated_token_account.has_expected_owner,
-                    quote_ata_owner_match: quote_associated_token_account.has_expected_owner,
-                    seat_manager_signer_seeds: seat_manager.seeds.clone(),
-                };
-                evict_seat_cpi(
-                    &seat_manager,
-                    &market_ai,
-                    trader_ai,
-                    &backup_base_token_account,
-                    &backup_quote_token_account,
+    // Perform eviction for trader(s)
+    for trader_accounts in &accounts[13..trader_accounts_end]
+        .iter()
+        .chunks(ctx.trader_accounts_len())
+    {
+        let trader_accounts = ctx.load_trader_accounts(trader_accounts)?;
+        let trader = *trader_accounts.trader.key;
+
+        let outcome = evict_trader_if_eligible(program_id, &ctx, trader_accounts, accounts)?;
+        if ctx.is_fully_authorized && outcome.is_evicted() {
+            record_admin_action(
+                &ctx.seat_manager,
+                accounts,
+                ctx.signer.key,
+                AdminActionKind::PrivilegedEviction,
+                &trader,
+                0,
+            )?;
+        }
+
+        if let Some(seat_waitlist) = seat_waitlist.as_ref() {
+            if outcome.is_evicted() {
+                approve_next_waitlisted_trader(
+                    program_id,
+                    &ctx.market_ai,
+                    &ctx.seat_manager,
+                    &ctx.seat_deposit_collector,
+                    &ctx.system_program,
+                    seat_waitlist,
                     accounts,
-                    &evict_seat_cpi_context,
                 )?;
-
-                // If the signer is not fully authorized and if the currently evicted seat is not empty, only one eviction is allowed at a time
-                if !is_fully_authorized && !seat_is_empty {
-                    msg!("Successfully evicted 1 seat");
-                    break;
-                }
             }
         }
+
+        // If the signer is not fully authorized and if the currently evicted seat is not empty, only one eviction is allowed at a time
+        if !ctx.is_fully_authorized && outcome == EvictionOutcome::EvictedNonEmptySeat {
+            msg!("Successfully evicted 1 seat");
+            break;
+        }
     }
     Ok(())
 }
 
+/// Fails if the eviction needs the seat waitlist, the DMM registry, the admin history or a multisig or admin group,
+/// which can only be supplied after the evicted traders when the instruction data contains their number
+fn assert_no_accounts_after_traders_required(ctx: &EvictSeatContext) -> ProgramResult {
+    let seat_manager = ctx.seat_manager.load()?;
+    let signer_is_role_holder = *ctx.signer.key == seat_manager.authority
+        || seat_manager.get_role_holder(SeatManagerRole::PrivilegedEvictor)
+            == Some(*ctx.signer.key);
+    assert_with_msg(
+        seat_manager.num_waitlisted_traders == 0
+            && ctx.dmm_registry.is_none()
+            && !(ctx.is_fully_authorized
+                && (seat_manager.is_admin_history_enabled() || !signer_is_role_holder)),
+        ProgramError::InvalidInstructionData,
+        "The seat manager requires accounts after the evicted traders, the instruction data must contain the number of evicted traders",
+    )
+}
+
+/// Evicts the trader if the seat is eligible for eviction under the signer's privileges.
+pub fn evict_trader_if_eligible<'info>(
+    program_id: &Pubkey,
+    ctx: &EvictSeatContext<'_, 'info>,
+    trader_accounts: TraderAccountsContext<'_, 'info>,
+    accounts: &[AccountInfo<'info>],
+) -> Result<EvictionOutcome, ProgramError> {
+    let EvictSeatContext {
+        market_ai,
+        seat_manager,
+        seat_deposit_collector,
+        system_program,
+        signer,
+        base_mint,
+        quote_mint,
+        market_size_params,
+        eviction_privileges,
+        heartbeat_staleness_window_in_slots,
+        requires_stale_heartbeat,
+        ..
+    } = ctx;
+    let TraderAccountsContext {
+        trader: trader_ai,
+        _seat,
+        base_associated_token_account,
+        quote_associated_token_account,
+        backup_base_token_account,
+        backup_quote_token_account,
+        seat_record,
+    } = trader_accounts;
+
+    // Check if trader is a DMM; if so, skip (cannot evict a DMM)
+    if ctx.is_dmm(trader_ai.key)? {
+        return Ok(EvictionOutcome::NotEvicted);
+    }
+
+    // Retrieve trader state if available and ensure no lots are locked before performing eviction-related actions
+    let trader_state = match retrieve_trader_state(market_ai, market_size_params, trader_ai)? {
+        Some(trader_state) => trader_state,
+        None => return Ok(EvictionOutcome::NotEvicted),
+    };
+
+    // If a trader's seat has 0 locked base lots, 0 locked quote lots, and only dust free base and quote lots, then anyone can remove it
+    let seat_is_empty = seat_manager.load()?.is_empty_seat(&trader_state);
+    let has_locked_lots = trader_state.base_lots_locked != 0 || trader_state.quote_lots_locked != 0;
+
+    if !eviction_privileges.covers(has_locked_lots, seat_is_empty) {
+        return Ok(EvictionOutcome::NotEvicted);
+    }
+
+    // A trader that never sent a heartbeat has no seat record, and is treated as inactive
+    if *requires_stale_heartbeat && !seat_is_empty {
+        let is_stale = match seat_record.as_ref() {
+            Some(seat_record) if !seat_record.data_is_empty() => seat_record
+                .load()?
+                .is_stale(Clock::get()?.slot, *heartbeat_staleness_window_in_slots),
+            _ => true,
+        };
+        if !is_stale {
+            msg!("Trader {} has a recent heartbeat", trader_ai.key);
+            return Ok(EvictionOutcome::NotEvicted);
+        }
+    }
+
+    // Change seat status
+    change_seat_status_not_approved_cpi(
+        market_ai,
+        seat_manager,
+        trader_ai,
+        accounts,
+        seat_manager.seeds.clone(),
+    )?;
+
+    // Check ATAs for base and quote; if doesn't exist, create ATAs, using the deposit collected at the time of claim seat.
+    // If ATAs are already created and belong to the traders, then refund the deposit collected at the time of claim seat back to the trader
+    // In the edge case that the ATA is created but does not belong to the trader, refund the deposit to the signer, the likely creator and rent-payer of the backup token accounts.
+    let seat_deposit_collector_seeds =
+        get_seat_deposit_collector_seeds(market_ai.key, seat_deposit_collector.key, program_id)?;
+    let mut total_trader_refund = 0;
+    let mut total_signer_refund = 0;
+
+    for (associated_token_account, token_mint) in [
+        (&base_associated_token_account, base_mint),
+        (&quote_associated_token_account, quote_mint),
+    ] {
+        // Calculate appropriate refund amounts and recipients based on ATA context
+        let (trader_refund, signer_refund) = get_trader_and_signer_refund_amounts(
+            associated_token_account.is_initialized,
+            associated_token_account.has_expected_owner,
+        )?;
+
+        total_trader_refund += trader_refund;
+        total_signer_refund += signer_refund;
+
+        create_ata_if_needed(
+            trader_ai,
+            token_mint,
+            associated_token_account,
+            seat_deposit_collector,
+            seat_deposit_collector_seeds.clone(),
+            accounts,
+        )?;
+    }
+
+    let minimum_rent_for_token_account =
+        Rent::get()?.minimum_balance(spl_token::state::Account::LEN);
+
+    assert_with_msg(
+        total_trader_refund + total_signer_refund <= minimum_rent_for_token_account * 2,
+        ProgramError::InvalidAccountData,
+        "Total refund cannot exceed rent for two token accounts. Check token account inputs.",
+    )?;
+
+    // Handle refunds if any to trader and signer
+    handle_refund(
+        total_trader_refund,
+        trader_ai,
+        seat_deposit_collector,
+        seat_deposit_collector_seeds.clone(),
+        system_program,
+    )?;
+    handle_refund(
+        total_signer_refund,
+        signer,
+        seat_deposit_collector,
+        seat_deposit_collector_seeds,
+        system_program,
+    )?;
+
+    // Evict seat for trader
+    let evict_seat_cpi_context = EvictSeatCpiContext {
+        base_mint: *base_mint,
+        quote_mint: *quote_mint,
+        base_ata_owner_match: base_associated_token_account.has_expected_owner,
+        quote_ata_owner_match: quote_associated_token_account.has_expected_owner,
+        seat_manager_signer_seeds: seat_manager.seeds.clone(),
+    };
+    evict_seat_cpi(
+        seat_manager,
+        market_ai,
+        trader_ai,
+        &backup_base_token_account,
+        &backup_quote_token_account,
+        accounts,
+        &evict_seat_cpi_context,
+    )?;
+
+    // Start the trader's re-claim cooldown. Traders without a seat record have never claimed a seat permissionlessly
+    // or sent a heartbeat, and their seat record is created, at their own expense, the next time they claim a seat
+    if let Some(seat_record) = seat_record {
+        if !seat_record.data_is_empty() {
+            seat_record.load_mut()?.last_evicted_slot = Clock::get()?.slot;
+        }
+    }
+
+    if seat_is_empty {
+        Ok(EvictionOutcome::EvictedEmptySeat)
+    } else {
+        Ok(EvictionOutcome::EvictedNonEmptySeat)
+    }
+}
+
 pub fn retrieve_trader_state(
     market_ai: &AccountInfo,
     market_size_params: &MarketSizeParams,
@@ -281,6 +616,64 @@ pub fn retrieve_trader_state(
     Ok(registered_traders.get(trader_ai.key).copied())
 }
 
+/// Returns the seat that a seat auction displaces on a full market: among the non-DMM seats that the signer can evict,
+/// the one with the fewest free quote lots, then the fewest free base lots. Ties go to the first seat in the market's trader order.
+/// This only reads the market data so that clients can find the seat to pass to `SettleSeatAuction`.
+/// `dmm_registry` is the seat manager's linked DMM registry, if any. It is ignored once `AddDmms` is renounced.
+/// `is_fully_authorized` is whether the signer is the authority or the privileged evictor, and `has_recent_heartbeat`
+/// whether a trader's heartbeat protects their seat, which is only asked for seats that are not empty.
+pub fn get_lowest_priority_evictable_trader(
+    market_data: &[u8],
+    seat_manager: &SeatManager,
+    dmm_registry: Option<&DmmRegistry>,
+    is_fully_authorized: bool,
+    has_recent_heartbeat: impl Fn(&Pubkey) -> bool,
+) -> Result<Option<Pubkey>, ProgramError> {
+    let (header_bytes, market_bytes) = market_data.split_at(size_of::<MarketHeader>());
+    let market_header = bytemuck::try_from_bytes::<MarketHeader>(header_bytes).map_err(|_| {
+        msg!("Invalid market header data");
+        ProgramError::InvalidAccountData
+    })?;
+    let market =
+        dispatch_market::load_with_dispatch(&market_header.market_size_params, market_bytes)?.inner;
+
+    let registered_traders = market.get_registered_traders();
+    let eviction_privileges = seat_manager.get_eviction_privileges(
+        is_fully_authorized,
+        registered_traders.len(),
+        registered_traders.capacity(),
+        seat_manager.get_num_registered_dmms(|dmm| registered_traders.get(dmm).is_some()),
+    );
+
+    let mut lowest_priority: Option<(Pubkey, (u64, u64))> = None;
+    for (trader, trader_state) in registered_traders.iter() {
+        let seat_is_empty = seat_manager.is_empty_seat(trader_state);
+        let has_locked_lots =
+            trader_state.base_lots_locked != 0 || trader_state.quote_lots_locked != 0;
+        if !eviction_privileges.covers(has_locked_lots, seat_is_empty) {
+            continue;
+        }
+        let priority = (
+            trader_state.quote_lots_free.as_u64(),
+            trader_state.base_lots_free.as_u64(),
+        );
+        // The DMM and heartbeat checks are only done for seats that would become the new lowest priority seat
+        let is_lower_priority = match lowest_priority {
+            Some((_, lowest)) => priority < lowest,
+            None => true,
+        };
+        if is_lower_priority
+            && !seat_manager.contains(trader)
+            && !(seat_manager.honours_dmm_registry()
+                && dmm_registry.is_some_and(|dmm_registry| dmm_registry.contains(trader)))
+            && (seat_is_empty || !has_recent_heartbeat(trader))
+        {
+            lowest_priority = Some((*trader, priority));
+        }
+    }
+    Ok(lowest_priority.map(|(trader, _)| trader))
+}
+
 pub fn change_seat_status_not_approved_cpi(
     market: &AccountInfo,
     seat_manager: &AccountInfo,
@@ -449,7 +842,7 @@ fn get_trader_and_signer_refund_amounts(
     Ok((trader_refund, signer_refund))
 }
 
-fn handle_refund<'a>(
+pub fn handle_refund<'a>(
     refund_amount: u64,
     refund_destination: &AccountInfo<'a>,
     seat_deposit_collector: &AccountInfo<'a>,
@@ -0,0 +1,42 @@
+use std::mem::size_of;
+
+use phoenix::program::{
+    checkers::{Program, Signer},
+    system_utils::create_account,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    rent::Rent, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    loaders::{MarketAccount, SeatRecordAccount},
+    seat_record::SeatRecord,
+};
+
+pub fn process_heartbeat(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let trader = Signer::new_payer(&accounts[2])?;
+    let seat_record =
+        SeatRecordAccount::new_with_market_and_trader(&accounts[1], market_ai.key, trader.key)?;
+    let system_program = Program::new(&accounts[3], &system_program::id())?;
+
+    if seat_record.data_is_empty() {
+        msg!("Creating and initializing seat record account");
+        create_account(
+            &trader,
+            &seat_record,
+            &system_program,
+            program_id,
+            &Rent::get()?,
+            size_of::<SeatRecord>() as u64,
+            seat_record.seeds.clone(),
+        )?;
+        let mut seat_record = seat_record.load_mut()?;
+        seat_record.market = *market_ai.key;
+        seat_record.trader = *trader.key;
+    }
+
+    seat_record.load_mut()?.last_active_slot = Clock::get()?.slot;
+    Ok(())
+}
@@ -1,19 +1,63 @@
+pub mod admin_group;
+pub mod admin_history;
 pub mod change_market_fee_recipient;
 pub mod change_market_status;
 pub mod change_seat_manager_authority;
 pub mod claim_market_authority;
 pub mod claim_seat;
+pub mod claim_seat_and_designate;
+pub mod claim_seat_with_eviction;
 pub mod confirm_renounce_seat_manager_authority;
 pub mod designated_market_maker;
+pub mod dmm_application;
+pub mod dmm_registry;
 pub mod evict_seat;
+pub mod heartbeat;
+pub mod multisig;
 pub mod name_market_authority_successor;
+pub mod renounce_capabilities;
+pub mod role;
+pub mod seat_auction;
+pub mod seat_waitlist;
+pub mod set_dust_threshold;
+pub mod set_eviction_policy;
+pub mod set_heartbeat_staleness_window;
+pub mod set_launch_phase_end_slot;
+pub mod set_permissionless_access;
+pub mod set_reclaim_cooldown;
+pub mod set_reserved_seats;
+pub mod sync_dmms;
+pub mod timelock;
+pub mod withdraw_treasury;
 
+pub use admin_group::*;
+pub use admin_history::*;
 pub use change_market_fee_recipient::*;
 pub use change_market_status::*;
 pub use change_seat_manager_authority::*;
 pub use claim_market_authority::*;
 pub use claim_seat::*;
+pub use claim_seat_and_designate::*;
+pub use claim_seat_with_eviction::*;
 pub use confirm_renounce_seat_manager_authority::*;
 pub use designated_market_maker::*;
+pub use dmm_application::*;
+pub use dmm_registry::*;
 pub use evict_seat::*;
+pub use heartbeat::*;
+pub use multisig::*;
 pub use name_market_authority_successor::*;
+pub use renounce_capabilities::*;
+pub use role::*;
+pub use seat_auction::*;
+pub use seat_waitlist::*;
+pub use set_dust_threshold::*;
+pub use set_eviction_policy::*;
+pub use set_heartbeat_staleness_window::*;
+pub use set_launch_phase_end_slot::*;
+pub use set_permissionless_access::*;
+pub use set_reclaim_cooldown::*;
+pub use set_reserved_seats::*;
+pub use sync_dmms::*;
+pub use timelock::*;
+pub use withdraw_treasury::*;
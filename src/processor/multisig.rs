@@ -0,0 +1,162 @@
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Program, Signer},
+    system_utils::create_account,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    admin_group::AdminGroup,
+    admin_history::AdminActionKind,
+    get_admin_group_address, get_multisig_address,
+    loaders::{MarketAccount, MultisigAccount, SeatManagerAccount},
+    multisig::Multisig,
+};
+
+use super::record_admin_action;
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct MultisigParams {
+    pub threshold: u64,
+    pub signers: Vec<Pubkey>,
+}
+
+/// Returns true if `authority` signed, or if `authority` is a multisig and at least `threshold` of its signers signed,
+/// or if `authority` is an admin group and its own authority approved in one of those ways.
+/// The multisig or admin group account and the signers can be anywhere in `accounts`.
+pub fn is_authority_approved(
+    authority: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<bool, ProgramError> {
+    if is_signer_or_multisig_approved(authority, accounts)? {
+        return Ok(true);
+    }
+    let admin_group_ai = match accounts.iter().find(|ai| {
+        ai.key == authority && *ai.owner == crate::id() && ai.data_len() == size_of::<AdminGroup>()
+    }) {
+        Some(admin_group_ai) => admin_group_ai,
+        None => return Ok(false),
+    };
+    let admin_group_data = admin_group_ai.try_borrow_data()?;
+    let admin_group = AdminGroup::load(&admin_group_data)?;
+    if get_admin_group_address(&admin_group.creator).0 != *authority {
+        return Ok(false);
+    }
+    is_signer_or_multisig_approved(&admin_group.authority, accounts)
+}
+
+pub(crate) fn is_signer_or_multisig_approved(
+    authority: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<bool, ProgramError> {
+    if accounts
+        .iter()
+        .any(|ai| ai.key == authority && ai.is_signer)
+    {
+        return Ok(true);
+    }
+    let multisig_ai = match accounts.iter().find(|ai| {
+        ai.key == authority && *ai.owner == crate::id() && ai.data_len() == size_of::<Multisig>()
+    }) {
+        Some(multisig_ai) => multisig_ai,
+        None => return Ok(false),
+    };
+    let multisig_data = multisig_ai.try_borrow_data()?;
+    let multisig = Multisig::load(&multisig_data)?;
+    if get_multisig_address(&multisig.market).0 != *authority {
+        return Ok(false);
+    }
+    Ok(multisig.count_approvals(accounts) >= multisig.threshold)
+}
+
+/// Checks that `authority_ai` is the expected authority, and that it signed or that its multisig signers approved
+pub fn assert_authority_approved(
+    authority_ai: &AccountInfo,
+    authority: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    assert_with_msg(
+        authority_ai.key == authority,
+        ProgramError::InvalidArgument,
+        &format!(
+            "Expected authority {}, found {}",
+            authority, authority_ai.key
+        ),
+    )?;
+    assert_with_msg(
+        is_authority_approved(authority, accounts)?,
+        ProgramError::MissingRequiredSignature,
+        "The authority must sign, or a multisig or admin group authority must be approved by its signers",
+    )
+}
+
+pub fn process_create_multisig(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let multisig = MultisigAccount::new_with_market(&accounts[2], market_ai.key)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[3], &seat_manager.load()?.authority, accounts)?;
+    let payer = Signer::new_payer(&accounts[4])?;
+    let system_program = Program::new(&accounts[5], &system_program::id())?;
+
+    let MultisigParams { threshold, signers } = MultisigParams::try_from_slice(data)?;
+    assert_with_msg(
+        multisig.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "Multisig already exists for this market",
+    )?;
+
+    msg!("Creating and initializing multisig account");
+    create_account(
+        &payer,
+        &multisig,
+        &system_program,
+        program_id,
+        &Rent::get()?,
+        size_of::<Multisig>() as u64,
+        multisig.seeds.clone(),
+    )?;
+    {
+        let mut multisig = multisig.load_mut()?;
+        multisig.market = *market_ai.key;
+        multisig.set_signers(threshold, &signers)?;
+    }
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[3].key,
+        AdminActionKind::CreateMultisig,
+        multisig.key,
+        threshold,
+    )
+}
+
+/// The multisig's signers and threshold can only be changed with the approval of its current signers
+pub fn process_update_multisig(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let multisig = MultisigAccount::new_with_market(&accounts[1], market_ai.key)?;
+    assert_with_msg(
+        !multisig.data_is_empty(),
+        ProgramError::UninitializedAccount,
+        "Multisig does not exist for this market",
+    )?;
+    assert_authority_approved(&multisig, multisig.key, accounts)?;
+
+    let MultisigParams { threshold, signers } = MultisigParams::try_from_slice(data)?;
+    multisig.load_mut()?.set_signers(threshold, &signers)?;
+    Ok(())
+}
@@ -1,14 +1,21 @@
 use borsh::BorshDeserialize;
-use phoenix::program::{checkers::Signer, create_name_successor_instruction};
+use phoenix::program::create_name_successor_instruction;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed, pubkey::Pubkey,
 };
 
 use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
     get_accounts_for_instruction,
     loaders::{MarketAccount, SeatManagerAccount},
 };
 
+use super::{
+    assert_authority_approved, assert_capability_not_renounced, assert_not_timelocked,
+    record_admin_action,
+};
+
 pub fn process_name_market_authority_successor(
     _program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -16,13 +23,35 @@ pub fn process_name_market_authority_successor(
 ) -> ProgramResult {
     let market_ai = MarketAccount::new(&accounts[2])?;
     let seat_manager = SeatManagerAccount::new_with_market(&accounts[3], market_ai.key)?;
-    // Checks that _authority is the valid authority for the seat_manager and a signer
-    let _authority = Signer::new_with_key(&accounts[4], &seat_manager.load()?.authority)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[4], &seat_manager.load()?.authority, accounts)?;
+    assert_not_timelocked(&*seat_manager.load()?)?;
 
     let successor_pubkey = Pubkey::try_from_slice(data)?;
+    name_market_authority_successor(&market_ai, &seat_manager, &successor_pubkey, accounts)?;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[4].key,
+        AdminActionKind::NameMarketAuthoritySuccessor,
+        &successor_pubkey,
+        0,
+    )
+}
 
+/// The accounts follow the `NameMarketAuthoritySuccessor` layout
+pub(crate) fn name_market_authority_successor(
+    market_ai: &MarketAccount,
+    seat_manager: &SeatManagerAccount,
+    successor_pubkey: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    assert_capability_not_renounced(
+        &*seat_manager.load()?,
+        SeatManagerCapability::NameMarketAuthoritySuccessor,
+    )?;
     let name_market_authority_successor_instruction =
-        create_name_successor_instruction(seat_manager.key, market_ai.key, &successor_pubkey);
+        create_name_successor_instruction(seat_manager.key, market_ai.key, successor_pubkey);
 
     invoke_signed(
         &name_market_authority_successor_instruction,
@@ -0,0 +1,92 @@
+use borsh::BorshDeserialize;
+use phoenix::program::assert_with_msg;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
+    loaders::{MarketAccount, SeatManagerAccount},
+    seat_manager::SeatManager,
+};
+
+use super::{assert_authority_approved, record_admin_action};
+
+/// Fails if the capability has been renounced
+pub fn assert_capability_not_renounced(
+    seat_manager: &SeatManager,
+    capability: SeatManagerCapability,
+) -> ProgramResult {
+    assert_with_msg(
+        !seat_manager.is_renounced(capability),
+        ProgramError::InvalidArgument,
+        &format!("The {:?} capability has been renounced", capability),
+    )
+}
+
+/// Stages a bitmask of capabilities to renounce, replacing any staged bitmask. A zero bitmask cancels the renunciation
+pub fn process_initiate_renounce_capabilities(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let capabilities = u64::try_from_slice(data)?;
+    assert_with_msg(
+        SeatManagerCapability::is_valid_mask(capabilities),
+        ProgramError::InvalidInstructionData,
+        "Unknown capabilities in bitmask",
+    )?;
+
+    msg!(
+        "Initiating renunciation of capabilities {:#b}",
+        capabilities
+    );
+    seat_manager.load_mut()?.pending_renounced_capabilities = capabilities;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::InitiateRenounceCapabilities,
+        &Pubkey::default(),
+        capabilities,
+    )
+}
+
+/// Irrevocably renounces the staged capabilities. The bitmask must match the staged one
+pub fn process_confirm_renounce_capabilities(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let capabilities = u64::try_from_slice(data)?;
+    {
+        let mut seat_manager = seat_manager.load_mut()?;
+        assert_with_msg(
+            capabilities != 0 && capabilities == seat_manager.pending_renounced_capabilities,
+            ProgramError::InvalidInstructionData,
+            "The capabilities must match the ones staged by InitiateRenounceCapabilities",
+        )?;
+
+        msg!("Renouncing capabilities {:#b}", capabilities);
+        seat_manager.renounced_capabilities |= capabilities;
+        seat_manager.pending_renounced_capabilities = 0;
+    }
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::ConfirmRenounceCapabilities,
+        &Pubkey::default(),
+        capabilities,
+    )
+}
@@ -0,0 +1,89 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use phoenix::program::assert_with_msg;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    loaders::{MarketAccount, SeatManagerAccount},
+    role::SeatManagerRole,
+    seat_manager::SeatManager,
+};
+
+use super::{assert_authority_approved, is_authority_approved, record_admin_action};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct SetRoleParams {
+    pub role: SeatManagerRole,
+    /// The default pubkey revokes the role
+    pub holder: Pubkey,
+}
+
+/// Returns true if the seat manager authority or the role's holder approved. Roles lapse once the authority is renounced
+pub fn is_role_approved(
+    seat_manager: &SeatManager,
+    role: SeatManagerRole,
+    accounts: &[AccountInfo],
+) -> Result<bool, ProgramError> {
+    if seat_manager.authority == Pubkey::default() {
+        return Ok(false);
+    }
+    if is_authority_approved(&seat_manager.authority, accounts)? {
+        return Ok(true);
+    }
+    match seat_manager.get_role_holder(role) {
+        Some(holder) => is_authority_approved(&holder, accounts),
+        None => Ok(false),
+    }
+}
+
+/// Checks that `role_ai` is the seat manager authority or the role's holder, and that it approved
+pub fn assert_role_approved(
+    role_ai: &AccountInfo,
+    seat_manager: &SeatManager,
+    role: SeatManagerRole,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    assert_with_msg(
+        seat_manager.authority != Pubkey::default(),
+        ProgramError::MissingRequiredSignature,
+        "The seat manager authority has been renounced",
+    )?;
+    match seat_manager.get_role_holder(role) {
+        Some(holder) if *role_ai.key == holder => {
+            assert_authority_approved(role_ai, &holder, accounts)
+        }
+        _ => assert_authority_approved(role_ai, &seat_manager.authority, accounts),
+    }
+}
+
+pub fn process_set_role(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    // Only the seat manager authority can assign and revoke roles
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let SetRoleParams { role, holder } = SetRoleParams::try_from_slice(data)?;
+    assert_with_msg(
+        holder != seat_manager.load()?.authority,
+        ProgramError::InvalidInstructionData,
+        "The seat manager authority already holds every role",
+    )?;
+
+    msg!("Setting {:?} to {}", role, holder);
+    seat_manager.load_mut()?.set_role_holder(role, &holder);
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::SetRole,
+        &holder,
+        role.index() as u64,
+    )
+}
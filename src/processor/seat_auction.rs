@@ -0,0 +1,395 @@
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Program, Signer, PDA},
+    create_change_seat_status_instruction, create_request_seat_authorized_instruction,
+    get_seat_address, load_with_dispatch,
+    status::SeatApprovalStatus,
+    system_utils::create_account,
+    MarketHeader, Seat,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction, system_program,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    get_accounts_for_instruction, get_treasury_address,
+    loaders::{MarketAccount, SeatAuctionAccount, SeatManagerAccount},
+    seat_auction::SeatAuction,
+};
+
+use super::{
+    assert_not_in_launch_phase, check_reclaim_cooldown, evict_trader_if_eligible,
+    get_lowest_priority_evictable_trader, is_authority_approved, record_admin_action,
+    EvictSeatContext,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct ConfigureSeatAuctionParams {
+    pub reserve_price: u64,
+    pub duration_in_slots: u64,
+}
+
+pub fn process_configure_seat_auction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let seat_auction = SeatAuctionAccount::new_with_market(&accounts[2], market_ai.key)?;
+    // The authority pays for the seat auction account. A multisig authority is approved by its signers, one of whom pays
+    let authority = Signer::new_payer(&accounts[3])?;
+    assert_with_msg(
+        is_authority_approved(&seat_manager.load()?.authority, accounts)?,
+        ProgramError::MissingRequiredSignature,
+        "The seat manager authority must approve configuring the seat auction",
+    )?;
+    let treasury = PDA::new(&accounts[4], &get_treasury_address(market_ai.key).0)?;
+    let system_program = Program::new(&accounts[5], &system_program::id())?;
+
+    let ConfigureSeatAuctionParams {
+        reserve_price,
+        duration_in_slots,
+    } = ConfigureSeatAuctionParams::try_from_slice(data)?;
+
+    // The winning bid pays the seat deposit, and the rest must be enough to keep an empty treasury rent exempt
+    let rent = Rent::get()?;
+    let deposit_amount = rent.minimum_balance(spl_token::state::Account::LEN) * 2;
+    assert_with_msg(
+        reserve_price >= deposit_amount + rent.minimum_balance(0),
+        ProgramError::InvalidInstructionData,
+        "Reserve price must cover the seat deposit and the treasury's rent exemption",
+    )?;
+    assert_with_msg(
+        duration_in_slots > 0,
+        ProgramError::InvalidInstructionData,
+        "Auction duration must be at least one slot",
+    )?;
+
+    if seat_auction.data_is_empty() {
+        msg!("Creating and initializing seat auction account");
+        create_account(
+            &authority,
+            &seat_auction,
+            &system_program,
+            program_id,
+            &Rent::get()?,
+            size_of::<SeatAuction>() as u64,
+            seat_auction.seeds.clone(),
+        )?;
+        seat_auction.load_mut()?.market = *market_ai.key;
+    }
+
+    let mut seat_auction = seat_auction.load_mut()?;
+    assert_with_msg(
+        !seat_auction.is_in_progress(),
+        ProgramError::InvalidArgument,
+        "Cannot reconfigure the seat auction while a round is in progress",
+    )?;
+    seat_auction.reserve_price = reserve_price;
+    seat_auction.duration_in_slots = duration_in_slots;
+    seat_manager.load_mut()?.treasury = *treasury.key;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        authority.key,
+        AdminActionKind::ConfigureSeatAuction,
+        &Pubkey::default(),
+        reserve_price,
+    )
+}
+
+pub fn process_bid_for_seat(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[2])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[3], market_ai.key)?;
+    let seat_auction = SeatAuctionAccount::new_with_market(&accounts[4], market_ai.key)?;
+    let bidder = Signer::new_payer(&accounts[5])?;
+    let seat_ai = PDA::new(&accounts[6], &get_seat_address(market_ai.key, bidder.key).0)?;
+    let previous_bidder = &accounts[7];
+    let system_program = Program::new(&accounts[8], &system_program::id())?;
+
+    let amount = u64::try_from_slice(data)?;
+
+    assert_with_msg(
+        !seat_manager.load()?.is_permissionless_claims_disabled(),
+        ProgramError::InvalidArgument,
+        "Permissionless seat claims are disabled",
+    )?;
+    assert_not_in_launch_phase(&seat_manager, &bidder)?;
+
+    // A recently evicted trader cannot bid for a seat until the re-claim cooldown ends
+    if seat_manager.load()?.reclaim_cooldown_in_slots > 0 {
+        let seat_record_ai = accounts.get(9).ok_or_else(|| {
+            msg!("The seat record must be supplied while the seat manager has a re-claim cooldown");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        check_reclaim_cooldown(
+            program_id,
+            &market_ai,
+            &seat_manager,
+            &bidder,
+            seat_record_ai,
+            &bidder,
+            &system_program,
+        )?;
+    }
+
+    assert_with_msg(
+        !seat_auction.data_is_empty(),
+        ProgramError::InvalidAccountData,
+        "Seat auction has not been configured",
+    )?;
+
+    {
+        let market_bytes = market_ai.data.borrow();
+        let (header_bytes, market_bytes) = market_bytes.split_at(size_of::<MarketHeader>());
+        let market_header =
+            bytemuck::try_from_bytes::<MarketHeader>(header_bytes).map_err(|_| {
+                msg!("Invalid market header data");
+                ProgramError::InvalidAccountData
+            })?;
+        let market = load_with_dispatch(&market_header.market_size_params, market_bytes)?.inner;
+        let registered_traders = market.get_registered_traders();
+        assert_with_msg(
+            registered_traders.len() == registered_traders.capacity(),
+            ProgramError::InvalidArgument,
+            "Market has available seats, claim a seat instead",
+        )?;
+    }
+
+    if !seat_ai.data_is_empty() {
+        let seat_data = &seat_ai.try_borrow_data()?;
+        let seat_struct = bytemuck::from_bytes::<Seat>(seat_data);
+        assert_with_msg(
+            SeatApprovalStatus::from(seat_struct.approval_status)
+                == SeatApprovalStatus::NotApproved,
+            ProgramError::InvalidArgument,
+            "Bidder's seat must not be approved or retired",
+        )?;
+    }
+
+    let current_slot = Clock::get()?.slot;
+    let (previous_bidder_key, previous_bid) = {
+        let mut seat_auction = seat_auction.load_mut()?;
+        assert_with_msg(
+            !seat_auction.has_ended(current_slot),
+            ProgramError::InvalidArgument,
+            "Seat auction round has ended and must be settled",
+        )?;
+        assert_with_msg(
+            amount >= seat_auction.reserve_price,
+            ProgramError::InvalidInstructionData,
+            "Bid is below the reserve price",
+        )?;
+        assert_with_msg(
+            amount > seat_auction.highest_bid,
+            ProgramError::InvalidInstructionData,
+            "Bid must exceed the highest bid",
+        )?;
+        // The first bid above the reserve opens the round
+        if !seat_auction.is_in_progress() {
+            seat_auction.end_slot = current_slot + seat_auction.duration_in_slots;
+        }
+        let previous = (seat_auction.highest_bidder, seat_auction.highest_bid);
+        seat_auction.highest_bidder = *bidder.key;
+        seat_auction.highest_bid = amount;
+        previous
+    };
+
+    // The outbid bidder is refunded directly from escrow
+    if previous_bid > 0 {
+        assert_with_msg(
+            *previous_bidder.key == previous_bidder_key,
+            ProgramError::InvalidArgument,
+            "Previous bidder account does not match the highest bidder",
+        )?;
+        msg!(
+            "Refunding {} lamports to outbid bidder {}",
+            previous_bid,
+            previous_bidder.key
+        );
+        **seat_auction.try_borrow_mut_lamports()? -= previous_bid;
+        **previous_bidder.try_borrow_mut_lamports()? += previous_bid;
+    }
+
+    // The seat is requested up front so that it only needs to be approved when the round is settled
+    if seat_ai.data_is_empty() {
+        let request_seat_instruction = create_request_seat_authorized_instruction(
+            seat_manager.key,
+            bidder.key,
+            market_ai.key,
+            bidder.key,
+        );
+        invoke_signed(
+            &request_seat_instruction,
+            get_accounts_for_instruction(&request_seat_instruction, accounts)?.as_slice(),
+            &[seat_manager
+                .seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )?;
+    }
+
+    let bid_ix = system_instruction::transfer(bidder.key, seat_auction.key, amount);
+    invoke(
+        &bid_ix,
+        get_accounts_for_instruction(&bid_ix, accounts)?.as_slice(),
+    )
+}
+
+/// The winner displaces the lowest-priority seat that the signer can evict, if the market is still full.
+/// If no seat can be given to the winner, the round is void and the bid is refunded. The authority can refund it
+/// as soon as no seat is evictable, since no signer has wider eviction privileges. Anyone can once the settle deadline passes.
+pub fn process_settle_seat_auction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    // The displaced seat must be evictable under the signer's privileges, the eviction policy and the heartbeat requirement
+    let ctx = EvictSeatContext::load(accounts)?;
+    let seat_auction = SeatAuctionAccount::new_with_market(&accounts[13], ctx.market_ai.key)?;
+    let treasury = PDA::new(&accounts[14], &get_treasury_address(ctx.market_ai.key).0)?;
+    let winner_ai = &accounts[15];
+    let winner_seat_ai = PDA::new(
+        &accounts[16],
+        &get_seat_address(ctx.market_ai.key, winner_ai.key).0,
+    )?;
+
+    assert_with_msg(
+        !seat_auction.data_is_empty(),
+        ProgramError::InvalidAccountData,
+        "Seat auction has not been configured",
+    )?;
+
+    let current_slot = Clock::get()?.slot;
+    let (winner, winning_bid, settle_deadline_slot) = {
+        let mut seat_auction = seat_auction.load_mut()?;
+        assert_with_msg(
+            seat_auction.has_ended(current_slot),
+            ProgramError::InvalidArgument,
+            "Seat auction round has not ended",
+        )?;
+        let settle_deadline_slot = seat_auction.get_settle_deadline_slot();
+        let (winner, winning_bid) = seat_auction.reset();
+        (winner, winning_bid, settle_deadline_slot)
+    };
+    assert_with_msg(
+        *winner_ai.key == winner,
+        ProgramError::InvalidArgument,
+        "Winner account does not match the highest bidder",
+    )?;
+
+    // If the winner got a seat some other way, the round is void and the bid is returned
+    let winner_seat_is_pending = {
+        let seat_data = &winner_seat_ai.try_borrow_data()?;
+        let seat_struct = bytemuck::from_bytes::<Seat>(seat_data);
+        SeatApprovalStatus::from(seat_struct.approval_status) == SeatApprovalStatus::NotApproved
+    };
+    if !winner_seat_is_pending {
+        msg!("Winner's seat is no longer pending, refunding the winning bid");
+        **seat_auction.try_borrow_mut_lamports()? -= winning_bid;
+        **winner_ai.try_borrow_mut_lamports()? += winning_bid;
+        return Ok(());
+    }
+
+    // Waitlisted traders have priority over the auction for any seat that frees up
+    let has_waitlisted_traders = ctx.seat_manager.load()?.num_waitlisted_traders > 0;
+    let lowest_priority_trader = if ctx.is_market_full && !has_waitlisted_traders {
+        let traders_with_recent_heartbeat = ctx.get_traders_with_recent_heartbeat(accounts)?;
+        let dmm_registry = ctx
+            .dmm_registry
+            .as_ref()
+            .map(|dmm_registry| dmm_registry.load())
+            .transpose()?;
+        get_lowest_priority_evictable_trader(
+            &ctx.market_ai.data.borrow(),
+            &*ctx.seat_manager.load()?,
+            dmm_registry.as_deref(),
+            ctx.is_fully_authorized,
+            |trader| traders_with_recent_heartbeat.contains(trader),
+        )?
+    } else {
+        None
+    };
+    let can_seat_winner =
+        !has_waitlisted_traders && (!ctx.is_market_full || lowest_priority_trader.is_some());
+    if !can_seat_winner {
+        assert_with_msg(
+            current_slot >= settle_deadline_slot
+                || ctx.is_fully_authorized && !has_waitlisted_traders,
+            ProgramError::InvalidArgument,
+            &format!(
+                "No seat can be given to the winner, the winning bid can be refunded from slot {}",
+                settle_deadline_slot
+            ),
+        )?;
+        msg!("No seat can be given to the winner, refunding the winning bid");
+        **seat_auction.try_borrow_mut_lamports()? -= winning_bid;
+        **winner_ai.try_borrow_mut_lamports()? += winning_bid;
+        return Ok(());
+    }
+
+    // The winner displaces the lowest-priority evictable seat if the market is still full
+    if let Some(lowest_priority_trader) = lowest_priority_trader {
+        let evicted_trader_accounts = ctx.load_trader_accounts(accounts[17..].iter())?;
+        assert_with_msg(
+            lowest_priority_trader == *evicted_trader_accounts.trader.key,
+            ProgramError::InvalidArgument,
+            "Evicted trader must hold the lowest-priority evictable seat",
+        )?;
+        let outcome =
+            evict_trader_if_eligible(program_id, &ctx, evicted_trader_accounts, accounts)?;
+        assert_with_msg(
+            outcome.is_evicted(),
+            ProgramError::InvalidArgument,
+            "Seat is not eligible for eviction",
+        )?;
+    }
+    let change_seat_status_instruction = create_change_seat_status_instruction(
+        ctx.seat_manager.key,
+        ctx.market_ai.key,
+        winner_ai.key,
+        SeatApprovalStatus::Approved,
+    );
+    invoke_signed(
+        &change_seat_status_instruction,
+        get_accounts_for_instruction(&change_seat_status_instruction, accounts)?.as_slice(),
+        &[ctx
+            .seat_manager
+            .seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice()],
+    )?;
+
+    // The seat deposit is taken out of the winning bid, and the rest goes to the treasury
+    let deposit_amount = Rent::get()?.minimum_balance(spl_token::state::Account::LEN) * 2;
+    let proceeds = winning_bid.saturating_sub(deposit_amount);
+    msg!(
+        "Seat auction won by {} for {} lamports, {} lamports to the treasury",
+        winner,
+        winning_bid,
+        proceeds
+    );
+    **seat_auction.try_borrow_mut_lamports()? -= winning_bid;
+    **ctx.seat_deposit_collector.try_borrow_mut_lamports()? += winning_bid - proceeds;
+    **treasury.try_borrow_mut_lamports()? += proceeds;
+    Ok(())
+}
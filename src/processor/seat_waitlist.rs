@@ -0,0 +1,259 @@
+use std::mem::size_of;
+
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Program, Signer, PDA},
+    create_change_seat_status_instruction, create_request_seat_authorized_instruction,
+    get_seat_address, load_with_dispatch,
+    status::SeatApprovalStatus,
+    system_utils::create_account,
+    MarketHeader, Seat,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction, system_program,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    get_accounts_for_instruction, get_seat_deposit_collector_address,
+    get_seat_deposit_collector_seeds,
+    loaders::{MarketAccount, SeatManagerAccount, SeatWaitlistAccount},
+    seat_waitlist::SeatWaitlist,
+};
+
+use super::{assert_not_in_launch_phase, check_reclaim_cooldown, handle_refund};
+
+pub fn process_join_seat_waitlist(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[2])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[3], market_ai.key)?;
+    let seat_deposit_collector = PDA::new(
+        &accounts[4],
+        &get_seat_deposit_collector_address(market_ai.key).0,
+    )?;
+    let seat_waitlist = SeatWaitlistAccount::new_with_market(&accounts[5], market_ai.key)?;
+    let trader = Signer::new(&accounts[6])?;
+    let payer = Signer::new_payer(&accounts[7])?;
+    let seat_ai = PDA::new(&accounts[8], &get_seat_address(market_ai.key, trader.key).0)?;
+    let system_program = Program::new(&accounts[9], &system_program::id())?;
+
+    assert_with_msg(
+        !seat_manager.load()?.is_permissionless_claims_disabled(),
+        ProgramError::InvalidArgument,
+        "Permissionless seat claims are disabled",
+    )?;
+    assert_not_in_launch_phase(&seat_manager, &trader)?;
+
+    // A recently evicted trader cannot queue for a seat until the re-claim cooldown ends
+    if seat_manager.load()?.reclaim_cooldown_in_slots > 0 {
+        let seat_record_ai = accounts.get(10).ok_or_else(|| {
+            msg!("The seat record must be supplied while the seat manager has a re-claim cooldown");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        check_reclaim_cooldown(
+            program_id,
+            &market_ai,
+            &seat_manager,
+            &trader,
+            seat_record_ai,
+            &payer,
+            &system_program,
+        )?;
+    }
+
+    {
+        let market_bytes = market_ai.data.borrow();
+        let (header_bytes, market_bytes) = market_bytes.split_at(size_of::<MarketHeader>());
+        let market_header =
+            bytemuck::try_from_bytes::<MarketHeader>(header_bytes).map_err(|_| {
+                msg!("Invalid market header data");
+                ProgramError::InvalidAccountData
+            })?;
+        let market = load_with_dispatch(&market_header.market_size_params, market_bytes)?.inner;
+        let registered_traders = market.get_registered_traders();
+        assert_with_msg(
+            registered_traders.len() == registered_traders.capacity(),
+            ProgramError::InvalidArgument,
+            "Market has available seats, claim a seat instead",
+        )?;
+    }
+
+    if !seat_ai.data_is_empty() {
+        let seat_data = &seat_ai.try_borrow_data()?;
+        let seat_struct = bytemuck::from_bytes::<Seat>(seat_data);
+        assert_with_msg(
+            SeatApprovalStatus::from(seat_struct.approval_status)
+                == SeatApprovalStatus::NotApproved,
+            ProgramError::InvalidArgument,
+            "Trader's seat must not be approved or retired to join the waitlist",
+        )?;
+    }
+
+    if seat_waitlist.data_is_empty() {
+        msg!("Creating and initializing seat waitlist account");
+        create_account(
+            &payer,
+            &seat_waitlist,
+            &system_program,
+            program_id,
+            &Rent::get()?,
+            size_of::<SeatWaitlist>() as u64,
+            seat_waitlist.seeds.clone(),
+        )?;
+        seat_waitlist.load_mut()?.market = *market_ai.key;
+    }
+
+    seat_waitlist
+        .load_mut()?
+        .push_back(trader.key)
+        .ok_or_else(|| {
+            msg!("Failed to add trader to the seat waitlist");
+            ProgramError::InvalidArgument
+        })?;
+    seat_manager.load_mut()?.num_waitlisted_traders += 1;
+
+    // The seat is requested up front so that it only needs to be approved once a seat frees up
+    if seat_ai.data_is_empty() {
+        let request_seat_instruction = create_request_seat_authorized_instruction(
+            seat_manager.key,
+            payer.key,
+            market_ai.key,
+            trader.key,
+        );
+        invoke_signed(
+            &request_seat_instruction,
+            get_accounts_for_instruction(&request_seat_instruction, accounts)?.as_slice(),
+            &[seat_manager
+                .seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )?;
+    }
+
+    // Waitlisted traders pay the same deposit as ClaimSeat, which is returned if they leave the waitlist
+    let deposit_amount = Rent::get()?.minimum_balance(spl_token::state::Account::LEN) * 2;
+    let deposit_ix =
+        system_instruction::transfer(payer.key, seat_deposit_collector.key, deposit_amount);
+    invoke(
+        &deposit_ix,
+        get_accounts_for_instruction(&deposit_ix, accounts)?.as_slice(),
+    )
+}
+
+pub fn process_leave_seat_waitlist(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let seat_deposit_collector = PDA::new(
+        &accounts[2],
+        &get_seat_deposit_collector_address(market_ai.key).0,
+    )?;
+    let seat_waitlist = SeatWaitlistAccount::new_with_market(&accounts[3], market_ai.key)?;
+    let trader = Signer::new(&accounts[4])?;
+    let system_program = Program::new(&accounts[5], &system_program::id())?;
+
+    assert_with_msg(
+        !seat_waitlist.data_is_empty(),
+        ProgramError::InvalidAccountData,
+        "Seat waitlist does not exist",
+    )?;
+    seat_waitlist
+        .load_mut()?
+        .remove(trader.key)
+        .ok_or_else(|| {
+            msg!("Trader is not on the seat waitlist");
+            ProgramError::InvalidArgument
+        })?;
+    seat_manager.load_mut()?.num_waitlisted_traders -= 1;
+
+    let deposit_amount = Rent::get()?.minimum_balance(spl_token::state::Account::LEN) * 2;
+    handle_refund(
+        deposit_amount,
+        &trader,
+        &seat_deposit_collector,
+        get_seat_deposit_collector_seeds(market_ai.key, seat_deposit_collector.key, program_id)?,
+        &system_program,
+    )
+}
+
+/// Approves the seat of the trader at the front of the waitlist, filling a seat that was just freed by an eviction.
+/// Traders whose seats can no longer be approved are dropped from the waitlist and refunded their deposit,
+/// so their trader account must follow their seat account.
+pub fn approve_next_waitlisted_trader<'a>(
+    program_id: &Pubkey,
+    market_ai: &AccountInfo<'a>,
+    seat_manager: &SeatManagerAccount,
+    seat_deposit_collector: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    seat_waitlist: &SeatWaitlistAccount,
+    accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+    loop {
+        let trader = match seat_waitlist.load_mut()?.pop_front() {
+            Some(trader) => trader,
+            None => return Ok(()),
+        };
+        seat_manager.load_mut()?.num_waitlisted_traders -= 1;
+
+        let change_seat_status_instruction = create_change_seat_status_instruction(
+            seat_manager.key,
+            market_ai.key,
+            &trader,
+            SeatApprovalStatus::Approved,
+        );
+        let change_seat_accounts =
+            get_accounts_for_instruction(&change_seat_status_instruction, accounts)?;
+        let is_seat_pending = {
+            // The seat account is the last account of the change seat status instruction
+            let seat_data = change_seat_accounts[4].try_borrow_data()?;
+            let seat_struct = bytemuck::from_bytes::<Seat>(&seat_data);
+            SeatApprovalStatus::from(seat_struct.approval_status) == SeatApprovalStatus::NotApproved
+        };
+        if !is_seat_pending {
+            msg!(
+                "Skipping waitlisted trader {} whose seat is not pending",
+                trader
+            );
+            let trader_ai = accounts
+                .iter()
+                .find(|ai| *ai.key == trader)
+                .ok_or_else(|| {
+                    msg!("The skipped trader's account must be supplied to refund their deposit");
+                    ProgramError::NotEnoughAccountKeys
+                })?;
+            let deposit_amount = Rent::get()?.minimum_balance(spl_token::state::Account::LEN) * 2;
+            handle_refund(
+                deposit_amount,
+                trader_ai,
+                seat_deposit_collector,
+                get_seat_deposit_collector_seeds(
+                    market_ai.key,
+                    seat_deposit_collector.key,
+                    program_id,
+                )?,
+                system_program,
+            )?;
+            continue;
+        }
+
+        msg!("Approving seat for waitlisted trader {}", trader);
+        return invoke_signed(
+            &change_seat_status_instruction,
+            change_seat_accounts.as_slice(),
+            &[seat_manager
+                .seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        );
+    }
+}
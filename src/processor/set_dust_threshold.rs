@@ -0,0 +1,31 @@
+use borsh::BorshDeserialize;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::{
+    admin_history::AdminActionKind,
+    loaders::{MarketAccount, SeatManagerAccount},
+};
+
+use super::{assert_authority_approved, record_admin_action};
+
+pub fn process_set_dust_threshold(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let dust_threshold_in_lots = u64::try_from_slice(data)?;
+    seat_manager.load_mut()?.dust_threshold_in_lots = dust_threshold_in_lots;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::SetDustThreshold,
+        &Pubkey::default(),
+        dust_threshold_in_lots,
+    )
+}
@@ -0,0 +1,50 @@
+use borsh::BorshDeserialize;
+use phoenix::program::assert_with_msg;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    eviction_policy::EvictionPolicy,
+    loaders::{MarketAccount, SeatManagerAccount},
+};
+
+use super::{assert_authority_approved, record_admin_action};
+
+pub fn process_set_eviction_policy(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let eviction_policy = EvictionPolicy::try_from_slice(data)?;
+    if let EvictionPolicy::OccupancyThreshold {
+        occupancy_threshold_bps,
+    } = eviction_policy
+    {
+        assert_with_msg(
+            occupancy_threshold_bps > 0 && occupancy_threshold_bps <= 10_000,
+            ProgramError::InvalidInstructionData,
+            "Occupancy threshold must be between 1 and 10000 basis points",
+        )?;
+    }
+
+    seat_manager
+        .load_mut()?
+        .set_eviction_policy(eviction_policy);
+    let discriminant = seat_manager.load()?.eviction_policy;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::SetEvictionPolicy,
+        &Pubkey::default(),
+        discriminant,
+    )
+}
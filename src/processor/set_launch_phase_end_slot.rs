@@ -0,0 +1,47 @@
+use borsh::BorshDeserialize;
+use phoenix::program::assert_with_msg;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    loaders::{MarketAccount, SeatManagerAccount},
+};
+
+use super::{assert_authority_approved, record_admin_action};
+
+pub fn process_set_launch_phase_end_slot(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let launch_phase_end_slot = u64::try_from_slice(data)?;
+    {
+        let mut seat_manager = seat_manager.load_mut()?;
+        // A launch phase that has ended cannot be reopened to lock out traders who already compete for seats
+        let current_slot = Clock::get()?.slot;
+        let has_launch_phase_ended = seat_manager.launch_phase_end_slot != 0
+            && !seat_manager.is_in_launch_phase(current_slot);
+        assert_with_msg(
+            !has_launch_phase_ended || launch_phase_end_slot <= current_slot,
+            ProgramError::InvalidInstructionData,
+            "The launch phase has ended and cannot be extended",
+        )?;
+        seat_manager.launch_phase_end_slot = launch_phase_end_slot;
+    }
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::SetLaunchPhaseEndSlot,
+        &Pubkey::default(),
+        launch_phase_end_slot,
+    )
+}
@@ -0,0 +1,45 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::{
+    admin_history::AdminActionKind,
+    loaders::{MarketAccount, SeatManagerAccount},
+};
+
+use super::{assert_authority_approved, record_admin_action};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct PermissionlessAccessParams {
+    pub disable_permissionless_eviction: bool,
+    pub disable_permissionless_claims: bool,
+}
+
+pub fn process_set_permissionless_access(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let PermissionlessAccessParams {
+        disable_permissionless_eviction,
+        disable_permissionless_claims,
+    } = PermissionlessAccessParams::try_from_slice(data)?;
+
+    {
+        let mut seat_manager = seat_manager.load_mut()?;
+        seat_manager.permissionless_eviction_disabled = disable_permissionless_eviction as u8;
+        seat_manager.permissionless_claims_disabled = disable_permissionless_claims as u8;
+    }
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::SetPermissionlessAccess,
+        &Pubkey::default(),
+        disable_permissionless_eviction as u64 | (disable_permissionless_claims as u64) << 1,
+    )
+}
@@ -0,0 +1,56 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use phoenix::program::{assert_with_msg, MarketHeader};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    loaders::{MarketAccount, SeatManagerAccount},
+};
+
+use super::{assert_authority_approved, record_admin_action};
+
+pub fn process_set_reserved_seats(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let num_reserved_seats = u64::try_from_slice(data)?;
+    let num_seats = {
+        let market_bytes = market_ai.data.borrow();
+        let market_header =
+            bytemuck::try_from_bytes::<MarketHeader>(&market_bytes[..size_of::<MarketHeader>()])
+                .map_err(|_| {
+                    msg!("Invalid market header data");
+                    ProgramError::InvalidAccountData
+                })?;
+        market_header.market_size_params.num_seats
+    };
+    assert_with_msg(
+        num_reserved_seats <= num_seats,
+        ProgramError::InvalidInstructionData,
+        &format!(
+            "Cannot reserve {} seats on a market with {} seats",
+            num_reserved_seats, num_seats
+        ),
+    )?;
+
+    seat_manager.load_mut()?.num_reserved_seats = num_reserved_seats;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::SetReservedSeats,
+        &Pubkey::default(),
+        num_reserved_seats,
+    )
+}
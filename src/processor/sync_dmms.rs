@@ -0,0 +1,51 @@
+use std::mem::size_of;
+
+use phoenix::program::{dispatch_market, MarketHeader};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::set_return_data, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::loaders::{MarketAccount, SeatManagerAccount};
+
+/// Removes DMMs that no longer have a seat on the market. The number of removed DMMs is set as the return data.
+pub fn process_sync_dmms(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let market_ai = MarketAccount::new_with_checked_discriminant(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+
+    let market_bytes = market_ai.data.borrow();
+    let (header_bytes, market_bytes) = market_bytes.split_at(size_of::<MarketHeader>());
+    let market_header = bytemuck::try_from_bytes::<MarketHeader>(header_bytes).map_err(|_| {
+        msg!("Invalid market header data");
+        ProgramError::InvalidAccountData
+    })?;
+    let market =
+        dispatch_market::load_with_dispatch(&market_header.market_size_params, market_bytes)?.inner;
+    let registered_traders = market.get_registered_traders();
+
+    let mut seat_manager = seat_manager.load_mut()?;
+    // DMMs pre-registered during the launch phase have not claimed their seats yet
+    let num_pruned = if seat_manager.is_in_launch_phase(Clock::get()?.slot) {
+        msg!("DMMs are not synced during the launch phase");
+        0
+    } else {
+        let mut num_pruned = 0_u64;
+        let mut index = 0;
+        while index < seat_manager.len() {
+            let dmm = seat_manager.designated_market_makers[index];
+            if registered_traders.contains(&dmm) {
+                index += 1;
+            } else {
+                // The swap-remove moves the last DMM into this index, so the index is checked again
+                msg!("Removing DMM {} without a seat", dmm);
+                seat_manager.remove(&dmm);
+                num_pruned += 1;
+            }
+        }
+        num_pruned
+    };
+
+    msg!("Removed {} DMMs", num_pruned);
+    set_return_data(&num_pruned.to_le_bytes());
+    Ok(())
+}
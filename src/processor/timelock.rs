@@ -0,0 +1,325 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Program, Signer},
+    system_utils::create_account,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
+    close_account,
+    loaders::{MarketAccount, PendingActionAccount, SeatManagerAccount},
+    pending_action::{PendingAction, TimelockedAction},
+    role::SeatManagerRole,
+    seat_manager::{SeatManager, DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS},
+};
+
+use super::{
+    assert_authority_approved, assert_capability_not_renounced, assert_role_approved,
+    change_market_fee_recipient, name_market_authority_successor, record_admin_action,
+};
+
+/// Fails if the seat manager has a timelock delay, in which case the action must be queued
+pub fn assert_not_timelocked(seat_manager: &SeatManager) -> ProgramResult {
+    assert_with_msg(
+        !seat_manager.is_timelocked(),
+        ProgramError::InvalidArgument,
+        "The seat manager has a timelock delay, this action must be queued",
+    )
+}
+
+/// Fee recipient changes can be queued and cancelled by the fee admin, every other action only by the authority
+fn assert_action_approved(
+    authority_ai: &AccountInfo,
+    seat_manager: &SeatManager,
+    action: &TimelockedAction,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    match action {
+        TimelockedAction::ChangeMarketFeeRecipient { .. } => assert_role_approved(
+            authority_ai,
+            seat_manager,
+            SeatManagerRole::FeeAdmin,
+            accounts,
+        ),
+        _ => assert_authority_approved(authority_ai, &seat_manager.authority, accounts),
+    }
+}
+
+/// Fails if the seat manager authority was renounced, or if the key that queued the action
+/// no longer holds the authority, or the fee admin role for fee recipient changes
+fn assert_queued_by_current_authority(
+    seat_manager: &SeatManager,
+    action: &TimelockedAction,
+    queued_by: &Pubkey,
+) -> ProgramResult {
+    assert_with_msg(
+        seat_manager.authority != Pubkey::default(),
+        ProgramError::InvalidArgument,
+        "The seat manager authority has been renounced",
+    )?;
+    let is_current = *queued_by == seat_manager.authority
+        || matches!(action, TimelockedAction::ChangeMarketFeeRecipient { .. })
+            && seat_manager.get_role_holder(SeatManagerRole::FeeAdmin) == Some(*queued_by);
+    assert_with_msg(
+        is_current,
+        ProgramError::InvalidArgument,
+        "The action was queued by a key that no longer holds the authority, cancel it instead",
+    )
+}
+
+/// Increases take effect immediately. While the seat manager is timelocked, decreases must be queued
+pub fn process_set_timelock_delay(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[2], &seat_manager.load()?.authority, accounts)?;
+
+    let timelock_delay_in_slots = u64::try_from_slice(data)?;
+    assert_with_msg(
+        timelock_delay_in_slots >= seat_manager.load()?.timelock_delay_in_slots,
+        ProgramError::InvalidArgument,
+        "Decreasing the timelock delay must be queued",
+    )?;
+    seat_manager.load_mut()?.timelock_delay_in_slots = timelock_delay_in_slots;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[2].key,
+        AdminActionKind::SetTimelockDelay,
+        &Pubkey::default(),
+        timelock_delay_in_slots,
+    )
+}
+
+pub fn process_queue_timelocked_action(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let action = TimelockedAction::try_from_slice(data)?;
+    let pending_action =
+        PendingActionAccount::new_with_market_and_action(&accounts[2], market_ai.key, &action)?;
+    assert_action_approved(&accounts[3], &*seat_manager.load()?, &action, accounts)?;
+    let payer = Signer::new_payer(&accounts[4])?;
+    let system_program = Program::new(&accounts[5], &system_program::id())?;
+
+    let timelock_delay_in_slots = seat_manager.load()?.timelock_delay_in_slots;
+    assert_with_msg(
+        timelock_delay_in_slots > 0,
+        ProgramError::InvalidArgument,
+        "The seat manager has no timelock delay, execute the action directly",
+    )?;
+    assert_with_msg(
+        pending_action.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "An action of this kind is already queued, cancel it first",
+    )?;
+
+    let execute_after_slot = Clock::get()?.slot + timelock_delay_in_slots;
+    msg!(
+        "Queueing {:?}, executable from slot {}",
+        action,
+        execute_after_slot
+    );
+    create_account(
+        &payer,
+        &pending_action,
+        &system_program,
+        program_id,
+        &Rent::get()?,
+        size_of::<PendingAction>() as u64,
+        pending_action.seeds.clone(),
+    )?;
+    {
+        let mut pending_action = pending_action.load_mut()?;
+        pending_action.market = *market_ai.key;
+        pending_action.set_action(action);
+        pending_action.execute_after_slot = execute_after_slot;
+        pending_action.payer = *payer.key;
+        pending_action.queued_by = *accounts[3].key;
+    }
+    seat_manager.load_mut()?.num_pending_actions += 1;
+    let target = pending_action.load()?.target;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[3].key,
+        AdminActionKind::QueueTimelockedAction,
+        &target,
+        action.discriminant() as u64,
+    )
+}
+
+pub fn process_cancel_timelocked_action(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let action = TimelockedAction::try_from_slice(data)?;
+    let pending_action =
+        PendingActionAccount::new_with_market_and_action(&accounts[2], market_ai.key, &action)?;
+    assert_action_approved(&accounts[3], &*seat_manager.load()?, &action, accounts)?;
+    let rent_receiver = &accounts[4];
+
+    assert_with_msg(
+        !pending_action.data_is_empty(),
+        ProgramError::UninitializedAccount,
+        "No action of this kind is queued",
+    )?;
+    assert_with_msg(
+        pending_action.load()?.payer == *rent_receiver.key,
+        ProgramError::InvalidArgument,
+        "The rent receiver must be the payer of the pending action",
+    )?;
+
+    msg!("Cancelling {:?}", pending_action.load()?.get_action()?);
+    let target = pending_action.load()?.target;
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[3].key,
+        AdminActionKind::CancelTimelockedAction,
+        &target,
+        action.discriminant() as u64,
+    )?;
+    {
+        let mut seat_manager = seat_manager.load_mut()?;
+        seat_manager.num_pending_actions = seat_manager.num_pending_actions.saturating_sub(1);
+    }
+    close_account(&pending_action, rent_receiver)
+}
+
+/// Anyone can execute a pending action once its delay has passed.
+/// Accounts 0 and 1 are the pending action and its rent receiver, followed by the accounts of the instruction
+/// that the action replaces. The authority account of that instruction does not need to sign.
+/// Actions queued by a previous authority or fee admin cannot be executed, the current one must cancel them.
+pub fn process_execute_timelocked_action(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let pending_action_ai = &accounts[0];
+    let rent_receiver = &accounts[1];
+    let action_accounts = &accounts[2..];
+
+    assert_with_msg(
+        *pending_action_ai.owner == crate::id() && !pending_action_ai.data_is_empty(),
+        ProgramError::UninitializedAccount,
+        "No pending action to execute",
+    )?;
+    let (market, action, execute_after_slot, payer, target, queued_by) = {
+        let pending_action_data = pending_action_ai.try_borrow_data()?;
+        let pending_action = PendingAction::load(&pending_action_data)?;
+        (
+            pending_action.market,
+            pending_action.get_action()?,
+            pending_action.execute_after_slot,
+            pending_action.payer,
+            pending_action.target,
+            pending_action.queued_by,
+        )
+    };
+    // Checks the pending action address
+    let pending_action =
+        PendingActionAccount::new_with_market_and_action(pending_action_ai, &market, &action)?;
+    assert_with_msg(
+        Clock::get()?.slot >= execute_after_slot,
+        ProgramError::InvalidArgument,
+        &format!(
+            "The pending action cannot be executed before slot {}",
+            execute_after_slot
+        ),
+    )?;
+    assert_with_msg(
+        payer == *rent_receiver.key,
+        ProgramError::InvalidArgument,
+        "The rent receiver must be the payer of the pending action",
+    )?;
+
+    let seat_manager = match action {
+        TimelockedAction::ChangeMarketFeeRecipient { .. }
+        | TimelockedAction::NameMarketAuthoritySuccessor { .. } => {
+            SeatManagerAccount::new_with_market(&action_accounts[3], &market)?
+        }
+        TimelockedAction::NameSuccessor { .. } => {
+            let seat_manager = SeatManagerAccount::new(&action_accounts[0])?;
+            assert_with_msg(
+                seat_manager.load()?.market == market,
+                ProgramError::InvalidArgument,
+                "The seat manager does not match the pending action",
+            )?;
+            seat_manager
+        }
+        TimelockedAction::SetTimelockDelay { .. } => {
+            SeatManagerAccount::new_with_market(&action_accounts[1], &market)?
+        }
+    };
+    assert_queued_by_current_authority(&*seat_manager.load()?, &action, &queued_by)?;
+
+    msg!("Executing {:?}", action);
+    match action {
+        TimelockedAction::ChangeMarketFeeRecipient { new_fee_recipient } => {
+            let market_ai = MarketAccount::new(&action_accounts[2])?;
+            assert_with_msg(
+                *action_accounts[7].key == new_fee_recipient,
+                ProgramError::InvalidArgument,
+                "The new fee recipient does not match the pending action",
+            )?;
+            change_market_fee_recipient(&market_ai, &seat_manager, action_accounts)?;
+        }
+        TimelockedAction::NameMarketAuthoritySuccessor { successor } => {
+            let market_ai = MarketAccount::new(&action_accounts[2])?;
+            name_market_authority_successor(
+                &market_ai,
+                &seat_manager,
+                &successor,
+                action_accounts,
+            )?;
+        }
+        TimelockedAction::NameSuccessor { successor } => {
+            assert_capability_not_renounced(
+                &*seat_manager.load()?,
+                SeatManagerCapability::NameSuccessor,
+            )?;
+            // The nomination window starts when the action is executed
+            seat_manager.load_mut()?.set_successor(
+                &successor,
+                Clock::get()?.slot + DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS,
+            );
+        }
+        TimelockedAction::SetTimelockDelay {
+            timelock_delay_in_slots,
+        } => {
+            seat_manager.load_mut()?.timelock_delay_in_slots = timelock_delay_in_slots;
+        }
+    }
+    {
+        let mut seat_manager = seat_manager.load_mut()?;
+        seat_manager.num_pending_actions = seat_manager.num_pending_actions.saturating_sub(1);
+    }
+
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        &payer,
+        AdminActionKind::ExecuteTimelockedAction,
+        &target,
+        action.discriminant() as u64,
+    )?;
+    close_account(&pending_action, rent_receiver)
+}
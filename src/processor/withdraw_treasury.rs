@@ -0,0 +1,72 @@
+use borsh::BorshDeserialize;
+use phoenix::program::{
+    assert_with_msg,
+    checkers::{Program, PDA},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
+    program_error::ProgramError, pubkey::Pubkey, system_instruction, system_program,
+};
+
+use crate::{
+    admin_history::AdminActionKind,
+    capability::SeatManagerCapability,
+    get_treasury_address, get_treasury_seeds,
+    loaders::{MarketAccount, SeatManagerAccount},
+};
+
+use super::{assert_authority_approved, assert_capability_not_renounced, record_admin_action};
+
+pub fn process_withdraw_treasury(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let market_ai = MarketAccount::new(&accounts[0])?;
+    let seat_manager = SeatManagerAccount::new_with_market(&accounts[1], market_ai.key)?;
+    let treasury = PDA::new(&accounts[2], &get_treasury_address(market_ai.key).0)?;
+    // Checks that the authority is the seat manager's authority and that it approved
+    assert_authority_approved(&accounts[3], &seat_manager.load()?.authority, accounts)?;
+    assert_capability_not_renounced(
+        &*seat_manager.load()?,
+        SeatManagerCapability::WithdrawTreasury,
+    )?;
+    let destination = &accounts[4];
+    let system_program = Program::new(&accounts[5], &system_program::id())?;
+
+    let amount = u64::try_from_slice(data)?;
+    assert_with_msg(
+        amount <= treasury.lamports(),
+        ProgramError::InsufficientFunds,
+        "Withdrawal exceeds the treasury balance",
+    )?;
+
+    msg!(
+        "Withdrawing {} lamports from the treasury to {}",
+        amount,
+        destination.key
+    );
+    let treasury_seeds = get_treasury_seeds(market_ai.key, treasury.key, program_id)?;
+    let transfer_ix = system_instruction::transfer(treasury.key, destination.key, amount);
+    record_admin_action(
+        &seat_manager,
+        accounts,
+        accounts[3].key,
+        AdminActionKind::WithdrawTreasury,
+        destination.key,
+        amount,
+    )?;
+    invoke_signed(
+        &transfer_ix,
+        &[
+            (*treasury).clone(),
+            destination.clone(),
+            (*system_program).clone(),
+        ],
+        &[treasury_seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice()],
+    )
+}
@@ -0,0 +1,25 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+pub const NUM_ROLES: usize = 5;
+
+/// Powers the seat manager authority can delegate to other keys. The authority keeps the powers of every role,
+/// and a role without a holder is only exercised by the authority.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum SeatManagerRole {
+    /// Changes the market status
+    StatusOperator,
+    /// Adds and removes DMMs and reviews DMM applications
+    DmmManager,
+    /// Changes the market fee recipient
+    FeeAdmin,
+    /// Evicts seats with the authority's eviction privileges
+    PrivilegedEvictor,
+    /// Claims seats through the authorized claim instructions
+    AuthorizedClaimer,
+}
+
+impl SeatManagerRole {
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+}
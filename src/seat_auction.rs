@@ -0,0 +1,65 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Slots after a round ends during which the winning bid stays escrowed while no seat can be given to the winner.
+/// After that, anyone can settle the round by refunding the bid
+pub const SEAT_AUCTION_SETTLE_WINDOW_IN_SLOTS: u64 = 1_512_000;
+
+/// An ascending auction for a seat on a full market.
+/// Bids are escrowed in this account. When the round ends, the highest bidder's seat replaces the
+/// lowest-priority evictable seat and the bid is paid out to the seat deposit collector and the treasury.
+/// If no seat can be given to the highest bidder, the bid is refunded instead.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct SeatAuction {
+    pub market: Pubkey,
+    pub highest_bidder: Pubkey,
+    /// Minimum bid in lamports, set by the seat manager authority
+    pub reserve_price: u64,
+    /// Number of slots a round stays open after its first bid
+    pub duration_in_slots: u64,
+    /// Slot at which the current round closes, or 0 if no round is in progress
+    pub end_slot: u64,
+    /// Lamports escrowed for the highest bid, or 0 if no round is in progress
+    pub highest_bid: u64,
+    pub _padding: [u64; 12],
+}
+
+impl SeatAuction {
+    pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
+        bytemuck::try_from_bytes::<SeatAuction>(bytes).map_err(|_| {
+            msg!("Failed to load seat auction from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn load_mut(bytes: &'_ mut [u8]) -> Result<&'_ mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut::<SeatAuction>(bytes).map_err(|_| {
+            msg!("Failed to load seat auction from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn is_in_progress(&self) -> bool {
+        self.highest_bid > 0
+    }
+
+    pub fn has_ended(&self, slot: u64) -> bool {
+        self.is_in_progress() && slot >= self.end_slot
+    }
+
+    /// Slot from which a round that cannot give the winner a seat can be settled by refunding the winning bid
+    pub fn get_settle_deadline_slot(&self) -> u64 {
+        self.end_slot
+            .saturating_add(SEAT_AUCTION_SETTLE_WINDOW_IN_SLOTS)
+    }
+
+    /// Clears the current round, returning the winning bidder and bid.
+    pub fn reset(&mut self) -> (Pubkey, u64) {
+        let result = (self.highest_bidder, self.highest_bid);
+        self.highest_bidder = Pubkey::default();
+        self.highest_bid = 0;
+        self.end_slot = 0;
+        result
+    }
+}
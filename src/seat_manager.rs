@@ -1,7 +1,14 @@
 use bytemuck::{Pod, Zeroable};
+use phoenix::{program::MarketSizeParams, quantities::WrapperU64, state::TraderState};
 use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+use static_assertions::const_assert_eq;
 
-use crate::MAX_DMMS;
+use crate::{
+    capability::SeatManagerCapability,
+    eviction_policy::{EvictionPolicy, EvictionPrivileges},
+    role::{SeatManagerRole, NUM_ROLES},
+    MAX_DMMS,
+};
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Zeroable, Pod)]
@@ -10,11 +17,63 @@ pub struct SeatManager {
     pub authority: Pubkey,
     pub successor: Pubkey,
     pub num_makers: u64,
-    pub _header_padding: [u64; 11],
+    /// Number of traders in the market's seat waitlist, mirrored here so that evictions can require the waitlist account
+    pub num_waitlisted_traders: u64,
+    /// The market's treasury PDA, seeds are [market_address, b'treasury']. Recorded when the seat auction is configured
+    pub treasury: Pubkey,
+    /// Seats with no locked lots and fewer free base and quote lots than this count as empty for permissionless eviction
+    pub dust_threshold_in_lots: u64,
+    /// Discriminant of the `EvictionPolicy`, 0 is `EvictionPolicy::Default`
+    pub eviction_policy: u64,
+    /// Only used by `EvictionPolicy::OccupancyThreshold`
+    pub eviction_occupancy_threshold_bps: u64,
+    /// When non-zero, only the authority can evict seats, e.g. during an incident
+    pub permissionless_eviction_disabled: u8,
+    /// When non-zero, seats can only be claimed through `ClaimSeatAuthorized`
+    pub permissionless_claims_disabled: u8,
+    /// When non-zero, authority-gated instructions must supply the admin history account and are recorded in it
+    pub admin_history_enabled: u8,
+    pub _flags_padding: [u8; 5],
+    /// When non-zero, privileged eviction of a non-empty seat requires the trader's last heartbeat to be older than this many slots
+    pub heartbeat_staleness_window_in_slots: u64,
+    /// Slots after an eviction during which the evicted trader cannot claim a seat without the authority
+    pub reclaim_cooldown_in_slots: u64,
     pub designated_market_makers: [Pubkey; MAX_DMMS as usize],
-    pub _dmm_padding: [u128; MAX_DMMS as usize],
+    /// Seats kept free for DMMs and authority claims. Permissionless claims are refused once
+    /// the number of non-DMM registered traders reaches the market's seat capacity minus this count
+    pub num_reserved_seats: u64,
+    /// The market's number of seats, recorded when the market authority is claimed. 0 for seat managers created before it was recorded
+    pub seat_capacity: u64,
+    /// The number of DMMs the market has room for, only used if `seat_capacity` is recorded
+    pub dmm_capacity: u64,
+    /// Until this slot, only the authority and pre-registered DMMs can claim seats
+    pub launch_phase_end_slot: u64,
+    /// Holders of the roles delegated by the authority, indexed by `SeatManagerRole`. The default pubkey if unassigned
+    pub roles: [Pubkey; NUM_ROLES],
+    /// When non-zero, fee recipient and successor changes must be queued and wait this many slots before they can be executed
+    pub timelock_delay_in_slots: u64,
+    /// Slot after which the successor can no longer claim the authority. 0 if the nomination does not expire
+    pub successor_expiry_slot: u64,
+    /// Bitmask of the `SeatManagerCapability`s that have been irrevocably renounced
+    pub renounced_capabilities: u64,
+    /// Bitmask of the capabilities awaiting confirmation to be renounced
+    pub pending_renounced_capabilities: u64,
+    /// Can only move the market to `PostOnly` or `Paused`. The default pubkey if unassigned
+    pub guardian: Pubkey,
+    /// A `DmmRegistry` whose DMMs are protected from eviction along with `designated_market_makers`. The default pubkey if unlinked
+    pub dmm_registry: Pubkey,
+    /// Number of queued timelocked actions. The authority cannot be renounced while any are pending
+    pub num_pending_actions: u64,
+    pub _pending_actions_padding: u64,
+    pub _dmm_padding: [u128; MAX_DMMS as usize - 19],
 }
 
+/// Nominations made without an explicit expiry slot lapse after roughly two weeks
+pub const DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS: u64 = 3_024_000;
+
+// New fields must be carved out of the padding so that existing seat manager accounts remain valid
+const_assert_eq!(std::mem::size_of::<SeatManager>(), 6336);
+
 impl SeatManager {
     pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
         bytemuck::try_from_bytes::<SeatManager>(bytes).map_err(|_| {
@@ -30,8 +89,161 @@ impl SeatManager {
         })
     }
 
+    pub fn get_eviction_policy(&self) -> EvictionPolicy {
+        match self.eviction_policy {
+            1 => EvictionPolicy::OccupancyThreshold {
+                occupancy_threshold_bps: self.eviction_occupancy_threshold_bps,
+            },
+            2 => EvictionPolicy::AuthorityOnly,
+            _ => EvictionPolicy::Default,
+        }
+    }
+
+    pub fn set_eviction_policy(&mut self, eviction_policy: EvictionPolicy) {
+        let (discriminant, occupancy_threshold_bps) = match eviction_policy {
+            EvictionPolicy::Default => (0, 0),
+            EvictionPolicy::OccupancyThreshold {
+                occupancy_threshold_bps,
+            } => (1, occupancy_threshold_bps),
+            EvictionPolicy::AuthorityOnly => (2, 0),
+        };
+        self.eviction_policy = discriminant;
+        self.eviction_occupancy_threshold_bps = occupancy_threshold_bps;
+    }
+
+    pub fn get_role_holder(&self, role: SeatManagerRole) -> Option<Pubkey> {
+        let holder = self.roles[role.index()];
+        (holder != Pubkey::default()).then_some(holder)
+    }
+
+    pub fn get_guardian(&self) -> Option<Pubkey> {
+        (self.guardian != Pubkey::default()).then_some(self.guardian)
+    }
+
+    pub fn get_dmm_registry(&self) -> Option<Pubkey> {
+        (self.dmm_registry != Pubkey::default()).then_some(self.dmm_registry)
+    }
+
+    /// Registry authorities can add DMMs at any time, so the registry is ignored once the seat manager renounces `AddDmms`
+    pub fn honours_dmm_registry(&self) -> bool {
+        !self.is_renounced(SeatManagerCapability::AddDmms)
+    }
+
+    /// Assigns the role to `holder`, or revokes it if `holder` is the default pubkey
+    pub fn set_role_holder(&mut self, role: SeatManagerRole, holder: &Pubkey) {
+        self.roles[role.index()] = *holder;
+    }
+
+    /// Evicted traders' seat records are needed to check heartbeats and to record evictions for the re-claim cooldown
+    pub fn requires_seat_records(&self) -> bool {
+        self.heartbeat_staleness_window_in_slots > 0 || self.reclaim_cooldown_in_slots > 0
+    }
+
+    pub fn set_successor(&mut self, successor: &Pubkey, expiry_slot: u64) {
+        self.successor = *successor;
+        self.successor_expiry_slot = expiry_slot;
+    }
+
+    /// Nominations made before expiry slots were recorded never expire
+    pub fn is_successor_expired(&self, current_slot: u64) -> bool {
+        self.successor_expiry_slot != 0 && current_slot > self.successor_expiry_slot
+    }
+
+    pub fn is_renounced(&self, capability: SeatManagerCapability) -> bool {
+        self.renounced_capabilities & capability.mask() != 0
+    }
+
+    pub fn is_timelocked(&self) -> bool {
+        self.timelock_delay_in_slots > 0
+    }
+
+    pub fn is_in_launch_phase(&self, current_slot: u64) -> bool {
+        current_slot < self.launch_phase_end_slot
+    }
+
+    pub fn is_permissionless_eviction_disabled(&self) -> bool {
+        self.permissionless_eviction_disabled != 0
+    }
+
+    pub fn is_admin_history_enabled(&self) -> bool {
+        self.admin_history_enabled != 0
+    }
+
+    pub fn is_permissionless_claims_disabled(&self) -> bool {
+        self.permissionless_claims_disabled != 0
+    }
+
+    /// Returns true if the free lots are small enough to be evicted by anyone.
+    /// Free lots are still returned to the trader on eviction.
+    pub fn is_dust(&self, free_lots: u64) -> bool {
+        free_lots == 0 || free_lots < self.dust_threshold_in_lots
+    }
+
+    /// Seats with no locked lots and only dust free lots can be evicted by anyone
+    pub fn is_empty_seat(&self, trader_state: &TraderState) -> bool {
+        trader_state.base_lots_locked == 0
+            && trader_state.quote_lots_locked == 0
+            && self.is_dust(trader_state.base_lots_free.as_u64())
+            && self.is_dust(trader_state.quote_lots_free.as_u64())
+    }
+
+    /// The seats that a signer can evict under the eviction policy, on a market with `num_registered_traders` of `num_seats` seats taken
+    pub fn get_eviction_privileges(
+        &self,
+        is_fully_authorized: bool,
+        num_registered_traders: usize,
+        num_seats: usize,
+        num_registered_dmms: usize,
+    ) -> EvictionPrivileges {
+        self.get_eviction_policy().evaluate(
+            is_fully_authorized,
+            num_registered_traders,
+            self.get_effective_num_seats(num_seats, num_registered_dmms),
+        )
+    }
+
+    /// Records the market's seat capacity and gives DMMs the seats left over once both sides of the book are full.
+    /// One seat beyond the book sizes is kept so that a full market always has a seat without open orders.
+    /// Reserved seats are clamped to the market's seats.
+    pub fn set_capacity(&mut self, market_size_params: &MarketSizeParams) {
+        let MarketSizeParams {
+            bids_size,
+            asks_size,
+            num_seats,
+        } = *market_size_params;
+        self.seat_capacity = num_seats;
+        self.dmm_capacity = num_seats
+            .saturating_sub(bids_size + asks_size + 1)
+            .min(self.designated_market_makers.len() as u64);
+        self.num_reserved_seats = self.num_reserved_seats.min(num_seats);
+    }
+
+    /// The number of non-DMM traders that can hold seats before permissionless claims are refused
+    pub fn get_non_dmm_seat_cap(&self, num_seats: usize) -> usize {
+        num_seats.saturating_sub(self.num_reserved_seats as usize)
+    }
+
+    /// The number of registered traders at which the market counts as full for eviction.
+    /// Reserved seats that are not taken by DMMs cannot be claimed permissionlessly, so they are not counted.
+    pub fn get_effective_num_seats(&self, num_seats: usize, num_registered_dmms: usize) -> usize {
+        (self.get_non_dmm_seat_cap(num_seats) + num_registered_dmms).min(num_seats)
+    }
+
+    pub fn get_num_registered_dmms(&self, is_registered: impl Fn(&Pubkey) -> bool) -> usize {
+        self.designated_market_makers
+            .iter()
+            .take(self.num_makers as usize)
+            .filter(|dmm| is_registered(dmm))
+            .count()
+    }
+
+    /// The maximum number of DMMs
     pub fn capacity(&self) -> usize {
-        self.designated_market_makers.len()
+        if self.seat_capacity == 0 {
+            self.designated_market_makers.len()
+        } else {
+            self.dmm_capacity as usize
+        }
     }
 
     pub fn contains(&self, trader: &Pubkey) -> bool {
@@ -42,7 +254,7 @@ impl SeatManager {
     }
 
     pub fn is_full(&self) -> bool {
-        self.num_makers == self.designated_market_makers.len() as u64
+        self.num_makers as usize >= self.capacity()
     }
 
     pub fn is_empty(&self) -> bool {
@@ -90,3 +302,30 @@ impl SeatManager {
         self.num_makers = 0;
     }
 }
+
+#[test]
+fn test_set_capacity() {
+    use bytemuck::Zeroable;
+
+    let mut seat_manager = SeatManager::zeroed();
+    // Seat managers created before the capacity was recorded keep room for MAX_DMMS DMMs
+    assert_eq!(seat_manager.capacity(), MAX_DMMS as usize);
+
+    seat_manager.num_reserved_seats = 1500;
+    for (num_seats, expected_dmm_capacity) in
+        [(2000, 128), (1153, 128), (1030, 5), (100, 0), (1025, 0)]
+    {
+        seat_manager.set_capacity(&MarketSizeParams {
+            bids_size: 512,
+            asks_size: 512,
+            num_seats,
+        });
+        assert_eq!(seat_manager.seat_capacity, num_seats);
+        assert_eq!(seat_manager.capacity(), expected_dmm_capacity);
+        assert!(seat_manager.num_reserved_seats <= num_seats);
+    }
+    assert_eq!(seat_manager.num_reserved_seats, 100);
+    // A market without seats beyond the book sizes has no room for DMMs
+    assert!(seat_manager.is_full());
+    assert!(seat_manager.insert(&Pubkey::new_unique()).is_none());
+}
@@ -0,0 +1,49 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Per-trader state kept by the seat manager, seeds are [market_address, trader_address, b"record"].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct SeatRecord {
+    pub market: Pubkey,
+    pub trader: Pubkey,
+    /// Slot of the trader's last `Heartbeat`
+    pub last_active_slot: u64,
+    /// Slot at which the trader's seat was last evicted, 0 if never evicted
+    pub last_evicted_slot: u64,
+    pub _padding: [u64; 6],
+}
+
+impl SeatRecord {
+    pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
+        bytemuck::try_from_bytes::<SeatRecord>(bytes).map_err(|_| {
+            msg!("Failed to load seat record from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn load_mut(bytes: &'_ mut [u8]) -> Result<&'_ mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut::<SeatRecord>(bytes).map_err(|_| {
+            msg!("Failed to load seat record from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    /// Returns true if the trader has not sent a heartbeat within the staleness window.
+    pub fn is_stale(&self, current_slot: u64, staleness_window_in_slots: u64) -> bool {
+        current_slot.saturating_sub(self.last_active_slot) > staleness_window_in_slots
+    }
+
+    /// Returns true if the trader was evicted less than `reclaim_cooldown_in_slots` slots ago.
+    pub fn is_in_reclaim_cooldown(
+        &self,
+        current_slot: u64,
+        reclaim_cooldown_in_slots: u64,
+    ) -> bool {
+        self.last_evicted_slot != 0
+            && current_slot
+                < self
+                    .last_evicted_slot
+                    .saturating_add(reclaim_cooldown_in_slots)
+    }
+}
@@ -0,0 +1,136 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::MAX_WAITLISTED_TRADERS;
+
+/// A FIFO queue of traders waiting for a seat on a full market.
+/// Waitlisted traders have already paid the seat deposit and requested their seat.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct SeatWaitlist {
+    pub market: Pubkey,
+    /// Index of the trader at the front of the queue
+    pub head: u64,
+    pub num_traders: u64,
+    pub _header_padding: [u64; 14],
+    pub traders: [Pubkey; MAX_WAITLISTED_TRADERS as usize],
+}
+
+impl SeatWaitlist {
+    pub fn load(bytes: &'_ [u8]) -> Result<&'_ Self, ProgramError> {
+        bytemuck::try_from_bytes::<SeatWaitlist>(bytes).map_err(|_| {
+            msg!("Failed to load seat waitlist from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn load_mut(bytes: &'_ mut [u8]) -> Result<&'_ mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut::<SeatWaitlist>(bytes).map_err(|_| {
+            msg!("Failed to load seat waitlist from data");
+            ProgramError::InvalidAccountData
+        })
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.traders.len()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_traders == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.num_traders as usize
+    }
+
+    /// Iterates over the waitlisted traders from the front of the queue to the back.
+    pub fn iter(&self) -> impl Iterator<Item = &Pubkey> {
+        (0..self.len()).map(move |i| &self.traders[self.slot(i)])
+    }
+
+    pub fn contains(&self, trader: &Pubkey) -> bool {
+        self.iter().any(|t| t == trader)
+    }
+
+    pub fn front(&self) -> Option<&Pubkey> {
+        self.iter().next()
+    }
+
+    pub fn push_back(&mut self, trader: &Pubkey) -> Option<usize> {
+        if self.contains(trader) {
+            msg!("Trader is already on the waitlist");
+            return None;
+        }
+        if self.is_full() {
+            msg!("Seat waitlist is full");
+            return None;
+        }
+        let position = self.len();
+        let slot = self.slot(position);
+        self.traders[slot] = *trader;
+        self.num_traders += 1;
+        Some(position)
+    }
+
+    pub fn pop_front(&mut self) -> Option<Pubkey> {
+        if self.is_empty() {
+            return None;
+        }
+        let slot = self.slot(0);
+        let trader = self.traders[slot];
+        self.traders[slot] = Pubkey::default();
+        self.head = ((slot + 1) % self.capacity()) as u64;
+        self.num_traders -= 1;
+        Some(trader)
+    }
+
+    /// Removes the trader from the queue, preserving the order of the remaining traders.
+    pub fn remove(&mut self, trader: &Pubkey) -> Option<usize> {
+        let position = self.iter().position(|t| t == trader)?;
+        for i in position..self.len() - 1 {
+            let (current, next) = (self.slot(i), self.slot(i + 1));
+            self.traders[current] = self.traders[next];
+        }
+        let last = self.slot(self.len() - 1);
+        self.traders[last] = Pubkey::default();
+        self.num_traders -= 1;
+        Some(position)
+    }
+
+    fn slot(&self, position: usize) -> usize {
+        (self.head as usize + position) % self.capacity()
+    }
+}
+
+#[test]
+fn test_seat_waitlist_preserves_fifo_order() {
+    let mut waitlist = SeatWaitlist::zeroed();
+    let traders = (0..waitlist.capacity())
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+
+    // Wrap the queue around the end of the array
+    for trader in traders.iter() {
+        assert!(waitlist.push_back(trader).is_some());
+    }
+    assert!(waitlist.is_full());
+    assert!(waitlist.push_back(&Pubkey::new_unique()).is_none());
+    assert_eq!(waitlist.pop_front(), Some(traders[0]));
+    assert_eq!(waitlist.pop_front(), Some(traders[1]));
+    assert!(waitlist.push_back(&traders[0]).is_some());
+    assert!(waitlist.push_back(&traders[0]).is_none());
+
+    // Removing from the middle keeps the remaining traders in order
+    assert_eq!(waitlist.remove(&traders[3]), Some(1));
+    let remaining = waitlist.iter().copied().collect::<Vec<_>>();
+    let expected = [&traders[2..3], &traders[4..], &traders[0..1]].concat();
+    assert_eq!(remaining, expected);
+
+    while waitlist.pop_front().is_some() {}
+    assert!(waitlist.is_empty());
+    assert!(waitlist.remove(&traders[0]).is_none());
+}
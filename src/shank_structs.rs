@@ -1,4 +1,4 @@
-///! These types are unused in the program, but are used to generate the IDL using shank.
+//! These types are unused in the program, but are used to generate the IDL using shank.
 use borsh::{BorshDeserialize, BorshSerialize};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
@@ -125,13 +125,12 @@ pub async fn get_and_bootstrap_maker(
     sdk: &mut SDKClient,
     mint_authority: &Keypair,
 ) -> PhoenixTestAccount {
-    let maker = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint).await;
+    let maker = setup_account(&sdk.client, mint_authority, sdk.base_mint, sdk.quote_mint).await;
     sdk.client.add_keypair(&maker.user);
-    let mut init_instructions = vec![];
-    init_instructions.push(create_claim_seat_instruction(
+    let init_instructions = vec![create_claim_seat_instruction(
         &maker.user.pubkey(),
         &sdk.active_market_key,
-    ));
+    )];
     println!("maker: {:?}", maker.user.pubkey());
     sdk.client
         .sign_send_instructions_with_payer(init_instructions, vec![&sdk.client.payer, &maker.user])
@@ -145,7 +144,7 @@ pub async fn get_and_bootstrap_taker(
     sdk: &mut SDKClient,
     mint_authority: &Keypair,
 ) -> PhoenixTestAccount {
-    let taker = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint).await;
+    let taker = setup_account(&sdk.client, mint_authority, sdk.base_mint, sdk.quote_mint).await;
     sdk.client.add_keypair(&taker.user);
     taker
 }
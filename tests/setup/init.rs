@@ -24,12 +24,14 @@ pub const NUM_SEATS: usize = 1153;
 
 const _INVALID_NUM_SEATS: usize = 1025;
 
+#[allow(dead_code)]
 pub struct PhoenixTestAccount {
     pub user: Keypair,
     pub base_ata: Pubkey,
     pub quote_ata: Pubkey,
 }
 
+#[allow(dead_code)]
 pub struct PhoenixTestClient {
     pub ctx: ProgramTestContext,
     pub sdk: SDKClient,
@@ -92,11 +94,64 @@ pub async fn setup_account(
     }
 }
 
+#[allow(dead_code)]
 pub async fn bootstrap_default(fees_bps: u16) -> PhoenixTestClient {
-    bootstrap_with_parameters(100_000, 1_000, 1_000, 9, 6, fees_bps, None, true).await
+    bootstrap_with_parameters(
+        phoenix_test(),
+        100_000,
+        1_000,
+        1_000,
+        9,
+        6,
+        fees_bps,
+        None,
+        true,
+        NUM_SEATS,
+    )
+    .await
 }
 
+/// Bootstraps the default market on a `ProgramTest` with additional programs, e.g. CPI callers
+#[allow(dead_code)]
+pub async fn bootstrap_with_program_test(
+    program_test: ProgramTest,
+    fees_bps: u16,
+) -> PhoenixTestClient {
+    bootstrap_with_parameters(
+        program_test,
+        100_000,
+        1_000,
+        1_000,
+        9,
+        6,
+        fees_bps,
+        None,
+        true,
+        NUM_SEATS,
+    )
+    .await
+}
+
+#[allow(dead_code)]
+pub async fn bootstrap_with_num_seats(fees_bps: u16, num_seats: usize) -> PhoenixTestClient {
+    bootstrap_with_parameters(
+        phoenix_test(),
+        100_000,
+        1_000,
+        1_000,
+        9,
+        6,
+        fees_bps,
+        None,
+        true,
+        num_seats,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn bootstrap_with_parameters(
+    program_test: ProgramTest,
     num_quote_lots_per_quote_unit: u64,
     num_base_lots_per_base_unit: u64,
     tick_size_in_quote_lots_per_base_unit: u64,
@@ -105,8 +160,9 @@ async fn bootstrap_with_parameters(
     fee_bps: u16,
     raw_base_units_per_base_unit: Option<u32>,
     claim_authority_as_seat_manager: bool,
+    num_seats: usize,
 ) -> PhoenixTestClient {
-    let context = phoenix_test().start_with_context().await;
+    let context = program_test.start_with_context().await;
     let mut ellipsis_client = EllipsisClient::from_banks(&context.banks_client, &context.payer)
         .await
         .unwrap();
@@ -119,7 +175,7 @@ async fn bootstrap_with_parameters(
     let params = MarketSizeParams {
         bids_size: BOOK_SIZE as u64,
         asks_size: BOOK_SIZE as u64,
-        num_seats: NUM_SEATS as u64,
+        num_seats: num_seats as u64,
     };
 
     // create base and quote token mints
@@ -230,7 +286,39 @@ async fn bootstrap_with_parameters(
     }
 }
 
+#[allow(dead_code)]
+pub async fn bootstrap_with_num_seats_without_sm_claiming_authority(
+    fees_bps: u16,
+    num_seats: usize,
+) -> PhoenixTestClient {
+    bootstrap_with_parameters(
+        phoenix_test(),
+        100_000,
+        1_000,
+        1_000,
+        9,
+        6,
+        fees_bps,
+        None,
+        false,
+        num_seats,
+    )
+    .await
+}
+
 #[allow(dead_code)]
 pub async fn bootstrap_default_without_sm_claiming_authority(fees_bps: u16) -> PhoenixTestClient {
-    bootstrap_with_parameters(100_000, 1_000, 1_000, 9, 6, fees_bps, None, false).await
+    bootstrap_with_parameters(
+        phoenix_test(),
+        100_000,
+        1_000,
+        1_000,
+        9,
+        6,
+        fees_bps,
+        None,
+        false,
+        NUM_SEATS,
+    )
+    .await
 }
@@ -5,8 +5,11 @@ use crate::setup::init::bootstrap_default;
 use crate::setup::init::PhoenixTestClient;
 use phoenix_seat_manager::get_seat_manager_address;
 use phoenix_seat_manager::instruction_builders::create_add_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_and_designate_instruction;
 use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
 use phoenix_seat_manager::instruction_builders::create_remove_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_launch_phase_end_slot_instruction;
+use phoenix_seat_manager::instruction_builders::create_sync_dmms_instruction;
 use phoenix_seat_manager::seat_manager::SeatManager;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
@@ -150,3 +153,127 @@ async fn test_add_remove_fails_if_authority_not_signer_or_authority_mismatch() {
         .await
         .is_err());
 }
+
+#[tokio::test]
+async fn test_claim_seat_and_designate() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let trader = Pubkey::new_unique();
+
+    // Only the seat manager authority can claim and designate
+    let unauthorized = Keypair::new();
+    airdrop(&sdk.client, &unauthorized.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_claim_seat_and_designate_instruction(
+                &trader,
+                &sdk.active_market_key,
+                &unauthorized.pubkey(),
+            )],
+            vec![&unauthorized],
+        )
+        .await
+        .is_err());
+
+    let claim_seat_and_designate = create_claim_seat_and_designate_instruction(
+        &trader,
+        &sdk.active_market_key,
+        &sdk.client.payer.pubkey(),
+    );
+    sdk.client
+        .sign_send_instructions(vec![claim_seat_and_designate.clone()], vec![])
+        .await
+        .unwrap();
+
+    assert!(sdk.get_traders().await.contains_key(&trader));
+    let (seat_manager_address, _) = get_seat_manager_address(&sdk.active_market_key);
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&seat_manager_address)
+        .await
+        .unwrap();
+    let seat_manager = bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap();
+    assert_eq!(seat_manager.num_makers, 1);
+    assert_eq!(seat_manager.designated_market_makers[0], trader);
+
+    // The trader is already a DMM
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![claim_seat_and_designate], vec![])
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_sync_dmms_removes_dmms_without_seats() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    // DMMs pre-registered during the launch phase do not have seats
+    let launch_phase_end_slot = 1_000;
+    let seated_dmm = Pubkey::new_unique();
+    let unseated_dmm = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_set_launch_phase_end_slot_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    launch_phase_end_slot,
+                ),
+                create_add_dmm_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    &unseated_dmm,
+                ),
+                create_claim_seat_and_designate_instruction(
+                    &seated_dmm,
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let get_dmms = || async {
+        let seat_manager_data = sdk
+            .client
+            .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+            .await
+            .unwrap();
+        let seat_manager = bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap();
+        seat_manager.designated_market_makers[..seat_manager.num_makers as usize].to_vec()
+    };
+
+    // The crank needs no signer, but pre-registered DMMs are kept during the launch phase
+    sdk.client
+        .sign_send_instructions(
+            vec![create_sync_dmms_instruction(&sdk.active_market_key)],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_dmms().await, vec![unseated_dmm, seated_dmm]);
+
+    ctx.warp_to_slot(launch_phase_end_slot).unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_sync_dmms_instruction(&sdk.active_market_key)],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_dmms().await, vec![seated_dmm]);
+}
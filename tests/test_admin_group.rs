@@ -0,0 +1,282 @@
+mod setup;
+
+use crate::setup::helpers::airdrop;
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::PhoenixTestClient;
+use phoenix::program::status::MarketStatus;
+use phoenix_seat_manager::admin_group::AdminGroup;
+use phoenix_seat_manager::get_admin_group_address;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_change_market_status_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_admin_group_authority_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_manager_authority_instruction;
+use phoenix_seat_manager::instruction_builders::create_create_admin_group_instruction;
+use phoenix_seat_manager::instruction_builders::create_name_admin_group_successor_instruction;
+use phoenix_seat_manager::instruction_builders::create_name_admin_group_successor_with_expiry_instruction;
+use phoenix_seat_manager::instruction_builders::create_name_seat_manager_successor_instruction;
+use phoenix_seat_manager::instruction_builders::with_admin_group_approval;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use phoenix_seat_manager::seat_manager::DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn test_admin_group_governs_market() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let creator = Keypair::new();
+    let new_admin = Keypair::new();
+    for keypair in [&creator, &new_admin] {
+        airdrop(&sdk.client, &keypair.pubkey(), 1_000_000_000)
+            .await
+            .unwrap();
+    }
+    let (admin_group, _) = get_admin_group_address(&creator.pubkey());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_create_admin_group_instruction(
+                &creator.pubkey(),
+                &authority,
+            )],
+            vec![&creator],
+        )
+        .await
+        .unwrap();
+
+    // The seat manager joins the admin group by naming it as the successor, and the group's authority claims
+    sdk.client
+        .sign_send_instructions(
+            vec![create_name_seat_manager_successor_instruction(
+                &authority,
+                &market,
+                &admin_group,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![with_admin_group_approval(
+                create_claim_seat_manager_authority_instruction(&market, &admin_group),
+                &admin_group,
+                &creator.pubkey(),
+            )],
+            vec![&creator],
+        )
+        .await
+        .unwrap();
+    let (seat_manager, _) = get_seat_manager_address(&market);
+    let seat_manager_data = sdk.client.get_account_data(&seat_manager).await.unwrap();
+    assert_eq!(
+        bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data)
+            .unwrap()
+            .authority,
+        admin_group
+    );
+
+    // The previous authority can no longer act
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_change_market_status_instruction(
+                &market,
+                &authority,
+                MarketStatus::PostOnly,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![with_admin_group_approval(
+                create_change_market_status_instruction(
+                    &market,
+                    &admin_group,
+                    MarketStatus::PostOnly,
+                ),
+                &admin_group,
+                &creator.pubkey(),
+            )],
+            vec![&creator],
+        )
+        .await
+        .unwrap();
+
+    // Rotating the group's authority rotates the market's authority
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_name_admin_group_successor_instruction(
+                &admin_group,
+                &new_admin.pubkey(),
+                &new_admin.pubkey(),
+            )],
+            vec![&new_admin],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_name_admin_group_successor_instruction(
+                &admin_group,
+                &creator.pubkey(),
+                &new_admin.pubkey(),
+            )],
+            vec![&creator],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_admin_group_authority_instruction(
+                &admin_group,
+                &new_admin.pubkey(),
+            )],
+            vec![&new_admin],
+        )
+        .await
+        .unwrap();
+    let admin_group_data = sdk.client.get_account_data(&admin_group).await.unwrap();
+    let group = AdminGroup::load(&admin_group_data).unwrap();
+    assert_eq!(group.creator, creator.pubkey());
+    assert_eq!(group.authority, new_admin.pubkey());
+
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![with_admin_group_approval(
+                create_change_market_status_instruction(
+                    &market,
+                    &admin_group,
+                    MarketStatus::Paused
+                ),
+                &admin_group,
+                &creator.pubkey(),
+            )],
+            vec![&creator],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![with_admin_group_approval(
+                create_change_market_status_instruction(
+                    &market,
+                    &admin_group,
+                    MarketStatus::Paused,
+                ),
+                &admin_group,
+                &new_admin.pubkey(),
+            )],
+            vec![&new_admin],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_admin_group_successor_nomination_expires() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let creator = Keypair::new();
+    let new_admin = Keypair::new();
+    for keypair in [&creator, &new_admin] {
+        airdrop(&sdk.client, &keypair.pubkey(), 1_000_000_000)
+            .await
+            .unwrap();
+    }
+    let (admin_group, _) = get_admin_group_address(&creator.pubkey());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_create_admin_group_instruction(
+                &creator.pubkey(),
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![&creator],
+        )
+        .await
+        .unwrap();
+
+    ctx.warp_to_slot(100).unwrap();
+    let expiry_slot = 200;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_name_admin_group_successor_with_expiry_instruction(
+                &admin_group,
+                &creator.pubkey(),
+                &new_admin.pubkey(),
+                expiry_slot,
+            )],
+            vec![&creator],
+        )
+        .await
+        .unwrap();
+    let admin_group_data = sdk.client.get_account_data(&admin_group).await.unwrap();
+    assert_eq!(
+        AdminGroup::load(&admin_group_data)
+            .unwrap()
+            .successor_expiry_slot,
+        expiry_slot
+    );
+
+    // The nomination can't be claimed once it has expired
+    ctx.warp_to_slot(expiry_slot + 1).unwrap();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_claim_admin_group_authority_instruction(
+                &admin_group,
+                &new_admin.pubkey(),
+            )],
+            vec![&new_admin],
+        )
+        .await
+        .is_err());
+
+    // Without an expiry slot, the nomination lasts for the default window
+    sdk.client
+        .sign_send_instructions(
+            vec![create_name_admin_group_successor_instruction(
+                &admin_group,
+                &creator.pubkey(),
+                &new_admin.pubkey(),
+            )],
+            vec![&creator],
+        )
+        .await
+        .unwrap();
+    let admin_group_data = sdk.client.get_account_data(&admin_group).await.unwrap();
+    assert!(
+        AdminGroup::load(&admin_group_data)
+            .unwrap()
+            .successor_expiry_slot
+            >= expiry_slot + 1 + DEFAULT_SUCCESSOR_NOMINATION_WINDOW_IN_SLOTS
+    );
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_admin_group_authority_instruction(
+                &admin_group,
+                &new_admin.pubkey(),
+            )],
+            vec![&new_admin],
+        )
+        .await
+        .unwrap();
+    let admin_group_data = sdk.client.get_account_data(&admin_group).await.unwrap();
+    let group = AdminGroup::load(&admin_group_data).unwrap();
+    assert_eq!(group.authority, new_admin.pubkey());
+    assert_eq!(group.successor_expiry_slot, 0);
+}
@@ -0,0 +1,159 @@
+mod setup;
+
+use crate::setup::helpers::airdrop;
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::PhoenixTestClient;
+use phoenix::program::status::MarketStatus;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::admin_history::{AdminActionKind, AdminHistory};
+use phoenix_seat_manager::get_admin_history_address;
+use phoenix_seat_manager::instruction_builders::create_change_market_status_instruction;
+use phoenix_seat_manager::instruction_builders::create_create_admin_history_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_guardian_instruction;
+use phoenix_seat_manager::instruction_builders::with_admin_history;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn get_admin_history_records(sdk: &SDKClient) -> Vec<(AdminActionKind, Pubkey, Pubkey, u64)> {
+    let (admin_history, _) = get_admin_history_address(&sdk.active_market_key);
+    let data = sdk.client.get_account_data(&admin_history).await.unwrap();
+    AdminHistory::load(&data)
+        .unwrap()
+        .iter()
+        .map(|record| {
+            (
+                record.get_kind().unwrap(),
+                record.signer,
+                record.key,
+                record.value,
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_admin_history() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let guardian = Keypair::new();
+    let payer = Keypair::new();
+    airdrop(&sdk.client, &payer.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+
+    // Admin actions don't need the admin history before it exists
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_guardian_instruction(
+                &market,
+                &authority,
+                &guardian.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Only the authority can create the admin history
+    assert!(sdk
+        .client
+        .sign_send_instructions_with_payer(
+            vec![create_create_admin_history_instruction(
+                &market,
+                &payer.pubkey(),
+                &payer.pubkey(),
+            )],
+            vec![&payer],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_create_admin_history_instruction(
+                &market, &authority, &authority,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Once it exists, admin actions fail without it
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_change_market_status_instruction(
+                &market,
+                &authority,
+                MarketStatus::PostOnly,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![with_admin_history(
+                create_change_market_status_instruction(&market, &authority, MarketStatus::Paused),
+                &market,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![with_admin_history(
+                create_set_guardian_instruction(&market, &authority, &Pubkey::default()),
+                &market,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        get_admin_history_records(&sdk).await,
+        vec![
+            (
+                AdminActionKind::CreateAdminHistory,
+                authority,
+                Pubkey::default(),
+                0
+            ),
+            (
+                AdminActionKind::ChangeMarketStatus,
+                authority,
+                Pubkey::default(),
+                MarketStatus::Paused as u64
+            ),
+            (
+                AdminActionKind::SetGuardian,
+                authority,
+                Pubkey::default(),
+                0
+            ),
+        ]
+    );
+
+    // The admin history can only be created once
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_create_admin_history_instruction(
+                &market,
+                &authority,
+                &payer.pubkey(),
+            )],
+            vec![&payer],
+        )
+        .await
+        .is_err());
+}
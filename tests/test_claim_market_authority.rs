@@ -21,7 +21,10 @@ use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
 use crate::setup::init::PhoenixTestClient;
-use crate::setup::init::{bootstrap_default, bootstrap_default_without_sm_claiming_authority};
+use crate::setup::init::{
+    bootstrap_default, bootstrap_default_without_sm_claiming_authority, bootstrap_with_num_seats,
+    bootstrap_with_num_seats_without_sm_claiming_authority,
+};
 mod setup;
 
 #[tokio::test]
@@ -334,3 +337,87 @@ async fn test_claim_market_authority_fails_if_deposit_collector_has_insufficient
         .await
         .is_err());
 }
+
+#[tokio::test]
+async fn test_claim_market_authority_records_capacity_for_any_market_size() {
+    // The book sizes are 512, so a market with 1026 seats only has room for one DMM
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_with_num_seats(5, 1026).await;
+
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    let seat_manager = bytemuck::try_from_bytes::<phoenix_seat_manager::seat_manager::SeatManager>(
+        &seat_manager_data,
+    )
+    .unwrap();
+    assert_eq!(seat_manager.seat_capacity, 1026);
+    assert_eq!(seat_manager.dmm_capacity, 1);
+
+    let dmm_one = Pubkey::new_unique();
+    let dmm_two = Pubkey::new_unique();
+    for dmm in [&dmm_one, &dmm_two] {
+        sdk.client
+            .sign_send_instructions(
+                vec![create_claim_seat_authorized_instruction(
+                    dmm,
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                )],
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_add_dmm_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                &dmm_one,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_add_dmm_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                &dmm_two,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_claim_market_authority_fails_if_market_has_fewer_seats_than_orders() {
+    // The book sizes are 512, so a market needs at least 1025 seats
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_with_num_seats_without_sm_claiming_authority(5, 128).await;
+
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_claim_market_authority_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+}
@@ -1,8 +1,10 @@
 mod setup;
 
 use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_cancel_seat_manager_successor_instruction;
 use phoenix_seat_manager::instruction_builders::create_claim_seat_manager_authority_instruction;
 use phoenix_seat_manager::instruction_builders::create_name_seat_manager_successor_instruction;
+use phoenix_seat_manager::instruction_builders::create_name_seat_manager_successor_with_expiry_instruction;
 use phoenix_seat_manager::seat_manager::SeatManager;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
@@ -99,3 +101,120 @@ async fn test_claim_seat_manager_authority_fails_if_sucessor_not_signer_or_wrong
         .await
         .is_err());
 }
+
+#[tokio::test]
+async fn test_claim_seat_manager_authority_fails_if_nomination_expired() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let successor = Keypair::new();
+    let expiry_slot = 100;
+
+    // The expiry slot must be in the future
+    ctx.warp_to_slot(10).unwrap();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_name_seat_manager_successor_with_expiry_instruction(
+                &sdk.client.payer.pubkey(),
+                &sdk.active_market_key,
+                &successor.pubkey(),
+                5,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_name_seat_manager_successor_with_expiry_instruction(
+                &sdk.client.payer.pubkey(),
+                &sdk.active_market_key,
+                &successor.pubkey(),
+                expiry_slot,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    ctx.warp_to_slot(expiry_slot + 1).unwrap();
+    let claim_authority_ix = create_claim_seat_manager_authority_instruction(
+        &sdk.active_market_key,
+        &successor.pubkey(),
+    );
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![claim_authority_ix], vec![&successor])
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_claim_seat_manager_authority_fails_if_nomination_cancelled() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let authority = sdk.client.payer.pubkey();
+    let successor = Keypair::new();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_name_seat_manager_successor_instruction(
+                &authority,
+                &sdk.active_market_key,
+                &successor.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Only the authority can cancel the nomination
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_cancel_seat_manager_successor_instruction(
+                &successor.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&successor],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_cancel_seat_manager_successor_instruction(
+                &authority,
+                &sdk.active_market_key,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let (seat_manager_address, _) = get_seat_manager_address(&sdk.active_market_key);
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&seat_manager_address)
+        .await
+        .unwrap();
+    let seat_manager = bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap();
+    assert_eq!(seat_manager.successor, authority);
+
+    let claim_authority_ix = create_claim_seat_manager_authority_instruction(
+        &sdk.active_market_key,
+        &successor.pubkey(),
+    );
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![claim_authority_ix], vec![&successor])
+        .await
+        .is_err());
+}
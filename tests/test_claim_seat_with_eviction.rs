@@ -0,0 +1,240 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::setup_account;
+use crate::setup::init::PhoenixTestClient;
+use phoenix::program::create_deposit_funds_instruction;
+use phoenix::program::deposit::DepositParams;
+use phoenix_seat_manager::instruction_builders::create_add_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_instruction;
+use phoenix_seat_manager::instruction_builders::{
+    create_claim_seat_with_eviction_authorized_instruction,
+    create_claim_seat_with_eviction_instruction, EvictTraderAccountBackup,
+};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn test_claim_seat_with_eviction_authorized_happy_path() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let evicted_trader =
+        setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint).await;
+    let deposit = create_deposit_funds_instruction(
+        &sdk.active_market_key,
+        &evicted_trader.user.pubkey(),
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        &DepositParams {
+            quote_lots_to_deposit: 1,
+            base_lots_to_deposit: 1,
+        },
+    );
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_claim_seat_instruction(
+                    &evicted_trader.user.pubkey(),
+                    &sdk.active_market_key,
+                ),
+                deposit,
+            ],
+            vec![&evicted_trader.user],
+        )
+        .await
+        .unwrap();
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+
+    let claim_seat_with_eviction = create_claim_seat_with_eviction_authorized_instruction(
+        &trader.pubkey(),
+        &sdk.active_market_key,
+        &sdk.client.payer.pubkey(),
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        EvictTraderAccountBackup {
+            trader_pubkey: evicted_trader.user.pubkey(),
+            base_token_account_backup: None,
+            quote_token_account_backup: None,
+        },
+    );
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                claim_seat_with_eviction,
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let traders = sdk.get_traders().await;
+    assert!(!traders.contains_key(&evicted_trader.user.pubkey()));
+    assert!(traders.contains_key(&trader.pubkey()));
+}
+
+#[tokio::test]
+async fn test_claim_seat_with_eviction_permissionless_only_evicts_empty_seats_when_not_full() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let empty_trader =
+        setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint).await;
+    let funded_trader =
+        setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint).await;
+    let deposit = create_deposit_funds_instruction(
+        &sdk.active_market_key,
+        &funded_trader.user.pubkey(),
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        &DepositParams {
+            quote_lots_to_deposit: 1,
+            base_lots_to_deposit: 1,
+        },
+    );
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_claim_seat_instruction(&empty_trader.user.pubkey(), &sdk.active_market_key),
+                create_claim_seat_instruction(&funded_trader.user.pubkey(), &sdk.active_market_key),
+                deposit,
+            ],
+            vec![&empty_trader.user, &funded_trader.user],
+        )
+        .await
+        .unwrap();
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+
+    // The funded seat cannot be evicted by a third party while the market has free seats
+    let claim_seat_with_eviction = create_claim_seat_with_eviction_instruction(
+        &trader.pubkey(),
+        &sdk.active_market_key,
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        EvictTraderAccountBackup {
+            trader_pubkey: funded_trader.user.pubkey(),
+            base_token_account_backup: None,
+            quote_token_account_backup: None,
+        },
+    );
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                claim_seat_with_eviction,
+            ],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+
+    let traders = sdk.get_traders().await;
+    assert!(traders.contains_key(&funded_trader.user.pubkey()));
+    assert!(!traders.contains_key(&trader.pubkey()));
+
+    // The empty seat can be evicted by anyone
+    let claim_seat_with_eviction = create_claim_seat_with_eviction_instruction(
+        &trader.pubkey(),
+        &sdk.active_market_key,
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        EvictTraderAccountBackup {
+            trader_pubkey: empty_trader.user.pubkey(),
+            base_token_account_backup: None,
+            quote_token_account_backup: None,
+        },
+    );
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                claim_seat_with_eviction,
+            ],
+            vec![&trader],
+        )
+        .await
+        .unwrap();
+
+    let traders = sdk.get_traders().await;
+    assert!(!traders.contains_key(&empty_trader.user.pubkey()));
+    assert!(traders.contains_key(&trader.pubkey()));
+}
+
+#[tokio::test]
+async fn test_claim_seat_with_eviction_fails_on_designated_market_maker() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let dmm = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint).await;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &dmm.user.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&dmm.user],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_add_dmm_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                &dmm.user.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+
+    let claim_seat_with_eviction = create_claim_seat_with_eviction_authorized_instruction(
+        &trader.pubkey(),
+        &sdk.active_market_key,
+        &sdk.client.payer.pubkey(),
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        EvictTraderAccountBackup {
+            trader_pubkey: dmm.user.pubkey(),
+            base_token_account_backup: None,
+            quote_token_account_backup: None,
+        },
+    );
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                claim_seat_with_eviction,
+            ],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    let traders = sdk.get_traders().await;
+    assert!(traders.contains_key(&dmm.user.pubkey()));
+    assert!(!traders.contains_key(&trader.pubkey()));
+}
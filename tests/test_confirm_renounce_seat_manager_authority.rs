@@ -4,6 +4,8 @@ use phoenix_seat_manager::get_seat_manager_address;
 use phoenix_seat_manager::instruction_builders::create_change_market_status_instruction;
 use phoenix_seat_manager::instruction_builders::create_confirm_renounce_seat_manager_authority_instruction;
 use phoenix_seat_manager::instruction_builders::create_initiate_renounce_seat_manager_authority_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_role_instruction;
+use phoenix_seat_manager::role::SeatManagerRole;
 use phoenix_seat_manager::seat_manager::SeatManager;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
@@ -68,6 +70,87 @@ async fn test_confirm_renounce_seat_manager_authority_success() {
         .is_err());
 }
 
+#[tokio::test]
+async fn test_confirm_renounce_seat_manager_authority_revokes_roles() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let operator = Keypair::new();
+    airdrop(&sdk.client, &operator.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_set_role_instruction(
+                    &market,
+                    &authority,
+                    SeatManagerRole::StatusOperator,
+                    &operator.pubkey(),
+                ),
+                create_initiate_renounce_seat_manager_authority_instruction(&authority, &market),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The role holder can act before the renounce
+    sdk.client
+        .sign_send_instructions_with_payer(
+            vec![create_change_market_status_instruction(
+                &market,
+                &operator.pubkey(),
+                MarketStatus::PostOnly,
+            )],
+            vec![&operator],
+        )
+        .await
+        .unwrap();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_confirm_renounce_seat_manager_authority_instruction(
+                &authority, &market,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let (seat_manager_address, _) = get_seat_manager_address(&market);
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&seat_manager_address)
+        .await
+        .unwrap();
+    let seat_manager = bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap();
+    assert!(seat_manager
+        .roles
+        .iter()
+        .all(|holder| *holder == Pubkey::default()));
+
+    // The market stays frozen for the former role holder
+    assert!(sdk
+        .client
+        .sign_send_instructions_with_payer(
+            vec![create_change_market_status_instruction(
+                &market,
+                &operator.pubkey(),
+                MarketStatus::Paused,
+            )],
+            vec![&operator],
+        )
+        .await
+        .is_err());
+}
+
 #[tokio::test]
 async fn test_confirm_renounce_seat_manager_authority_fails_unauthorized_signer() {
     let PhoenixTestClient {
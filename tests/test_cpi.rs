@@ -0,0 +1,318 @@
+mod setup;
+use std::mem::size_of;
+
+use crate::setup::init::bootstrap_with_program_test;
+use crate::setup::init::phoenix_test;
+use crate::setup::init::PhoenixTestClient;
+use ellipsis_client::program_test::builtin_process_instruction;
+use ellipsis_client::program_test::InvokeContext;
+use phoenix::program::status::MarketStatus;
+use phoenix::program::MarketHeader;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::admin_history::AdminActionKind;
+use phoenix_seat_manager::admin_history::AdminHistory;
+use phoenix_seat_manager::get_admin_history_address;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_add_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_change_market_status_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_manager_authority_instruction;
+use phoenix_seat_manager::instruction_builders::create_create_admin_history_instruction;
+use phoenix_seat_manager::instruction_builders::create_name_seat_manager_successor_instruction;
+use phoenix_seat_manager::instruction_builders::with_admin_history;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_program::instruction::InstructionError;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+/// A minimal governance program whose PDA holds the seat manager authority
+mod governance {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use phoenix::program::status::MarketStatus;
+    use phoenix_seat_manager::cpi;
+    use solana_program::{
+        account_info::AccountInfo,
+        entrypoint::ProgramResult,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    pub const ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    pub enum GovernanceInstruction {
+        ClaimSeatManagerAuthority {
+            market: Pubkey,
+        },
+        AddDesignatedMarketMaker {
+            market: Pubkey,
+            trader: Pubkey,
+        },
+        ChangeMarketStatus {
+            market: Pubkey,
+            status: MarketStatus,
+        },
+    }
+
+    pub fn get_authority_address() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"authority"], &ID)
+    }
+
+    pub fn process_instruction(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        data: &[u8],
+    ) -> ProgramResult {
+        let (authority, bump) = get_authority_address();
+        let authority_seeds: &[&[u8]] = &[b"authority", &[bump]];
+        match GovernanceInstruction::try_from_slice(data)? {
+            GovernanceInstruction::ClaimSeatManagerAuthority { market } => {
+                cpi::claim_seat_manager_authority(&market, &authority, accounts, authority_seeds)
+            }
+            GovernanceInstruction::AddDesignatedMarketMaker { market, trader } => {
+                cpi::add_designated_market_maker(
+                    &market,
+                    &authority,
+                    &trader,
+                    accounts,
+                    authority_seeds,
+                )
+            }
+            GovernanceInstruction::ChangeMarketStatus { market, status } => {
+                cpi::change_market_status(&market, &authority, status, accounts, authority_seeds)
+            }
+        }
+    }
+
+    /// Passes the accounts of the seat manager instruction through, without the authority PDA signing
+    pub fn create_governance_instruction(
+        seat_manager_instruction: Instruction,
+        governance_instruction: GovernanceInstruction,
+    ) -> Instruction {
+        let mut accounts = seat_manager_instruction.accounts;
+        for account in accounts.iter_mut() {
+            account.is_signer = false;
+        }
+        accounts.push(AccountMeta::new_readonly(phoenix_seat_manager::id(), false));
+        Instruction {
+            program_id: ID,
+            accounts,
+            data: governance_instruction.try_to_vec().unwrap(),
+        }
+    }
+}
+
+/// Runs the governance program natively in the test validator
+fn process_governance_instruction(
+    first_instruction_account: usize,
+    invoke_context: &mut InvokeContext,
+) -> Result<(), InstructionError> {
+    builtin_process_instruction(
+        governance::process_instruction,
+        first_instruction_account,
+        invoke_context,
+    )
+}
+
+async fn get_seat_manager(sdk: &SDKClient) -> SeatManager {
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    *bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap()
+}
+
+#[tokio::test]
+async fn test_program_controlled_authority() {
+    let mut program_test = phoenix_test();
+    program_test.add_program(
+        "governance",
+        governance::ID,
+        Some(process_governance_instruction),
+    );
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_with_program_test(program_test, 5).await;
+
+    let market = sdk.active_market_key;
+    let payer = sdk.client.payer.pubkey();
+    let (governance_authority, _) = governance::get_authority_address();
+
+    // Give the trader a seat while the payer is still the authority
+    let trader = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_claim_seat_authorized_instruction(&trader, &market, &payer),
+                create_name_seat_manager_successor_instruction(
+                    &payer,
+                    &market,
+                    &governance_authority,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![governance::create_governance_instruction(
+                create_claim_seat_manager_authority_instruction(&market, &governance_authority),
+                governance::GovernanceInstruction::ClaimSeatManagerAuthority { market },
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_seat_manager(&sdk).await.authority, governance_authority);
+
+    // The previous authority can no longer act
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_add_dmm_instruction(&market, &payer, &trader)],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![governance::create_governance_instruction(
+                create_add_dmm_instruction(&market, &governance_authority, &trader),
+                governance::GovernanceInstruction::AddDesignatedMarketMaker { market, trader },
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    let seat_manager = get_seat_manager(&sdk).await;
+    assert_eq!(seat_manager.num_makers, 1);
+    assert_eq!(seat_manager.designated_market_makers[0], trader);
+
+    sdk.client
+        .sign_send_instructions(
+            vec![governance::create_governance_instruction(
+                create_change_market_status_instruction(
+                    &market,
+                    &governance_authority,
+                    MarketStatus::Paused,
+                ),
+                governance::GovernanceInstruction::ChangeMarketStatus {
+                    market,
+                    status: MarketStatus::Paused,
+                },
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let market_data = sdk.client.get_account_data(&market).await.unwrap();
+    let (header_bytes, _) = market_data.split_at(size_of::<MarketHeader>());
+    let header = bytemuck::try_from_bytes::<MarketHeader>(header_bytes).unwrap();
+    assert_eq!(header.status, MarketStatus::Paused as u64);
+}
+
+#[tokio::test]
+async fn test_program_controlled_authority_with_admin_history() {
+    let mut program_test = phoenix_test();
+    program_test.add_program(
+        "governance",
+        governance::ID,
+        Some(process_governance_instruction),
+    );
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_with_program_test(program_test, 5).await;
+
+    let market = sdk.active_market_key;
+    let payer = sdk.client.payer.pubkey();
+    let (governance_authority, _) = governance::get_authority_address();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_create_admin_history_instruction(&market, &payer, &payer),
+                with_admin_history(
+                    create_name_seat_manager_successor_instruction(
+                        &payer,
+                        &market,
+                        &governance_authority,
+                    ),
+                    &market,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![governance::create_governance_instruction(
+                with_admin_history(
+                    create_claim_seat_manager_authority_instruction(&market, &governance_authority),
+                    &market,
+                ),
+                governance::GovernanceInstruction::ClaimSeatManagerAuthority { market },
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_seat_manager(&sdk).await.authority, governance_authority);
+
+    // The helper forwards the admin history, which is required once it is enabled
+    let change_market_status = create_change_market_status_instruction(
+        &market,
+        &governance_authority,
+        MarketStatus::Paused,
+    );
+    let governance_instruction = || governance::GovernanceInstruction::ChangeMarketStatus {
+        market,
+        status: MarketStatus::Paused,
+    };
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![governance::create_governance_instruction(
+                change_market_status.clone(),
+                governance_instruction(),
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![governance::create_governance_instruction(
+                with_admin_history(change_market_status, &market),
+                governance_instruction(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let admin_history_data = sdk
+        .client
+        .get_account_data(&get_admin_history_address(&market).0)
+        .await
+        .unwrap();
+    let last_record = *AdminHistory::load(&admin_history_data)
+        .unwrap()
+        .iter()
+        .last()
+        .unwrap();
+    assert_eq!(
+        last_record.get_kind().unwrap(),
+        AdminActionKind::ChangeMarketStatus
+    );
+    assert_eq!(last_record.signer, governance_authority);
+}
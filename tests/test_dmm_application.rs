@@ -0,0 +1,288 @@
+mod setup;
+
+use crate::setup::helpers::airdrop;
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::PhoenixTestClient;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::get_dmm_application_address;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_apply_for_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_approve_dmm_application_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_reject_dmm_application_instruction;
+use phoenix_seat_manager::instruction_builders::create_resign_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_withdraw_dmm_application_instruction;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn get_seat_manager(sdk: &SDKClient) -> SeatManager {
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    *bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap()
+}
+
+async fn setup_trader_with_seat(sdk: &SDKClient) -> Keypair {
+    let trader = Keypair::new();
+    airdrop(&sdk.client, &trader.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_authorized_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    trader
+}
+
+#[tokio::test]
+async fn test_apply_approve_and_resign() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let trader = setup_trader_with_seat(&sdk).await;
+    let (dmm_application, _) =
+        get_dmm_application_address(&sdk.active_market_key, &trader.pubkey());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_apply_for_dmm_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+            )],
+            vec![&trader],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.client.get_account(&dmm_application).await.is_ok());
+
+    // Only the seat manager authority can approve the application
+    let unauthorized = Keypair::new();
+    airdrop(&sdk.client, &unauthorized.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_approve_dmm_application_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+                &unauthorized.pubkey(),
+            )],
+            vec![&unauthorized],
+        )
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_approve_dmm_application_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(get_seat_manager(&sdk).await.contains(&trader.pubkey()));
+    assert!(sdk.client.get_account(&dmm_application).await.is_err());
+
+    // DMMs cannot apply again
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_apply_for_dmm_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+
+    // The DMM can resign without the authority
+    sdk.client
+        .sign_send_instructions(
+            vec![create_resign_dmm_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+            )],
+            vec![&trader],
+        )
+        .await
+        .unwrap();
+    assert!(!get_seat_manager(&sdk).await.contains(&trader.pubkey()));
+
+    // Traders that are not DMMs cannot resign
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_resign_dmm_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_reject_dmm_application() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let trader = setup_trader_with_seat(&sdk).await;
+    let (dmm_application, _) =
+        get_dmm_application_address(&sdk.active_market_key, &trader.pubkey());
+
+    let apply_for_dmm = create_apply_for_dmm_instruction(&sdk.active_market_key, &trader.pubkey());
+    sdk.client
+        .sign_send_instructions(vec![apply_for_dmm.clone()], vec![&trader])
+        .await
+        .unwrap();
+
+    // A trader can only have one pending application
+    ctx.warp_to_slot(10).unwrap();
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![apply_for_dmm.clone()], vec![&trader])
+        .await
+        .is_err());
+
+    let trader_lamports = sdk
+        .client
+        .get_account(&trader.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+    let application_lamports = sdk
+        .client
+        .get_account(&dmm_application)
+        .await
+        .unwrap()
+        .lamports;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_reject_dmm_application_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The application is closed, the rent is refunded and the trader is not added as a DMM
+    assert!(sdk.client.get_account(&dmm_application).await.is_err());
+    assert_eq!(
+        sdk.client
+            .get_account(&trader.pubkey())
+            .await
+            .unwrap()
+            .lamports,
+        trader_lamports + application_lamports
+    );
+    assert!(!get_seat_manager(&sdk).await.contains(&trader.pubkey()));
+
+    // Rejected traders can apply again
+    ctx.warp_to_slot(20).unwrap();
+    sdk.client
+        .sign_send_instructions(vec![apply_for_dmm], vec![&trader])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_withdraw_dmm_application() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let trader = setup_trader_with_seat(&sdk).await;
+    let (dmm_application, _) =
+        get_dmm_application_address(&sdk.active_market_key, &trader.pubkey());
+    let withdraw_dmm_application =
+        create_withdraw_dmm_application_instruction(&sdk.active_market_key, &trader.pubkey());
+
+    // Traders without a pending application have nothing to withdraw
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![withdraw_dmm_application.clone()], vec![&trader])
+        .await
+        .is_err());
+
+    let apply_for_dmm = create_apply_for_dmm_instruction(&sdk.active_market_key, &trader.pubkey());
+    sdk.client
+        .sign_send_instructions(vec![apply_for_dmm.clone()], vec![&trader])
+        .await
+        .unwrap();
+
+    let trader_lamports = sdk
+        .client
+        .get_account(&trader.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+    let application_lamports = sdk
+        .client
+        .get_account(&dmm_application)
+        .await
+        .unwrap()
+        .lamports;
+    ctx.warp_to_slot(10).unwrap();
+    sdk.client
+        .sign_send_instructions(vec![withdraw_dmm_application], vec![&trader])
+        .await
+        .unwrap();
+
+    // The application is closed and the rent is refunded to the trader
+    assert!(sdk.client.get_account(&dmm_application).await.is_err());
+    assert_eq!(
+        sdk.client
+            .get_account(&trader.pubkey())
+            .await
+            .unwrap()
+            .lamports,
+        trader_lamports + application_lamports
+    );
+    assert!(!get_seat_manager(&sdk).await.contains(&trader.pubkey()));
+
+    // The application can no longer be approved, but the trader can apply again
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_approve_dmm_application_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+    ctx.warp_to_slot(20).unwrap();
+    sdk.client
+        .sign_send_instructions(vec![apply_for_dmm], vec![&trader])
+        .await
+        .unwrap();
+}
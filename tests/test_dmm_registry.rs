@@ -0,0 +1,235 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::PhoenixTestClient;
+use phoenix_seat_manager::capability::SeatManagerCapability;
+use phoenix_seat_manager::dmm_registry::DmmRegistry;
+use phoenix_seat_manager::get_dmm_registry_address;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_add_registry_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_clear_dmm_registry_instruction;
+use phoenix_seat_manager::instruction_builders::create_confirm_renounce_capabilities_instruction;
+use phoenix_seat_manager::instruction_builders::create_create_dmm_registry_instruction;
+use phoenix_seat_manager::instruction_builders::create_evict_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_initiate_renounce_capabilities_instruction;
+use phoenix_seat_manager::instruction_builders::create_remove_registry_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_dmm_registry_instruction;
+use phoenix_seat_manager::instruction_builders::with_dmm_registry;
+use phoenix_seat_manager::instruction_builders::EvictTraderAccountBackup;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn test_dmm_registry_protects_dmms_from_eviction() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let (dmm_registry, _) = get_dmm_registry_address(&authority);
+    let trader = Pubkey::new_unique();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_create_dmm_registry_instruction(&authority, &authority, &authority),
+                create_claim_seat_authorized_instruction(&trader, &market, &authority),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Only the registry authority can add DMMs
+    let unauthorized = Keypair::new();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_add_registry_dmm_instruction(
+                &dmm_registry,
+                &unauthorized.pubkey(),
+                &trader,
+            )],
+            vec![&unauthorized],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_add_registry_dmm_instruction(&dmm_registry, &authority, &trader),
+                create_set_dmm_registry_instruction(&market, &authority, &dmm_registry),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    let dmm_registry_data = sdk.client.get_account_data(&dmm_registry).await.unwrap();
+    assert_eq!(
+        DmmRegistry::load(&dmm_registry_data).unwrap().get_dmms(),
+        &[trader]
+    );
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&market).0)
+        .await
+        .unwrap();
+    let seat_manager = bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap();
+    assert_eq!(seat_manager.get_dmm_registry(), Some(dmm_registry));
+    assert_eq!(seat_manager.num_makers, 0);
+
+    let evict_seat = create_evict_seat_instruction(
+        &market,
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        &authority,
+        vec![EvictTraderAccountBackup {
+            trader_pubkey: trader,
+            base_token_account_backup: None,
+            quote_token_account_backup: None,
+        }],
+    );
+
+    // Evictions must include the linked registry
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![evict_seat.clone()], vec![])
+        .await
+        .is_err());
+
+    // The registry's DMM is not evicted
+    sdk.client
+        .sign_send_instructions(
+            vec![with_dmm_registry(evict_seat.clone(), &dmm_registry)],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.get_traders().await.contains_key(&trader));
+
+    // Once removed from the registry, the trader can be evicted
+    sdk.client
+        .sign_send_instructions(
+            vec![create_remove_registry_dmm_instruction(
+                &dmm_registry,
+                &authority,
+                &trader,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                with_dmm_registry(evict_seat, &dmm_registry),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader));
+
+    // Unlinking the registry no longer requires it for evictions
+    sdk.client
+        .sign_send_instructions(
+            vec![create_clear_dmm_registry_instruction(&market, &authority)],
+            vec![],
+        )
+        .await
+        .unwrap();
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&market).0)
+        .await
+        .unwrap();
+    let seat_manager = bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap();
+    assert_eq!(seat_manager.get_dmm_registry(), None);
+}
+
+#[tokio::test]
+async fn test_dmm_registry_is_ignored_once_add_dmms_is_renounced() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let (dmm_registry, _) = get_dmm_registry_address(&authority);
+    let trader = Pubkey::new_unique();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_create_dmm_registry_instruction(&authority, &authority, &authority),
+                create_claim_seat_authorized_instruction(&trader, &market, &authority),
+                create_set_dmm_registry_instruction(&market, &authority, &dmm_registry),
+                create_initiate_renounce_capabilities_instruction(
+                    &market,
+                    &authority,
+                    &[SeatManagerCapability::AddDmms],
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_confirm_renounce_capabilities_instruction(
+                &market,
+                &authority,
+                &[SeatManagerCapability::AddDmms],
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The registry authority can still add the trader, but the seat manager no longer treats it as a DMM
+    sdk.client
+        .sign_send_instructions(
+            vec![create_add_registry_dmm_instruction(
+                &dmm_registry,
+                &authority,
+                &trader,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                with_dmm_registry(
+                    create_evict_seat_instruction(
+                        &market,
+                        &sdk.base_mint,
+                        &sdk.quote_mint,
+                        &authority,
+                        vec![EvictTraderAccountBackup {
+                            trader_pubkey: trader,
+                            base_token_account_backup: None,
+                            quote_token_account_backup: None,
+                        }],
+                    ),
+                    &dmm_registry,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader));
+}
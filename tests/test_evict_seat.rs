@@ -10,10 +10,15 @@ use phoenix::program::create_deposit_funds_instruction;
 use phoenix::program::deposit::DepositParams;
 use phoenix::program::load_with_dispatch;
 use phoenix::program::MarketHeader;
+use phoenix_seat_manager::eviction_policy::EvictionPolicy;
 use phoenix_seat_manager::get_seat_deposit_collector_address;
 use phoenix_seat_manager::get_seat_manager_address;
 use phoenix_seat_manager::instruction_builders::create_add_dmm_instruction;
 use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_create_admin_history_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_dust_threshold_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_eviction_policy_instruction;
+use phoenix_seat_manager::instruction_builders::with_admin_history;
 use phoenix_seat_manager::instruction_builders::EvictTraderAccountBackup;
 use phoenix_seat_manager::instruction_builders::{
     create_claim_seat_instruction, create_evict_seat_instruction,
@@ -21,6 +26,7 @@ use phoenix_seat_manager::instruction_builders::{
 use phoenix_seat_manager::seat_manager::SeatManager;
 use rand::thread_rng;
 use rand::Rng;
+use solana_program::instruction::AccountMeta;
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
@@ -61,8 +67,8 @@ async fn test_evict_seat_multiple_authorized() {
         .unwrap();
 
     let traders = sdk.get_traders().await;
-    assert!(traders.get(&trader_one.user.pubkey()).is_some());
-    assert!(traders.get(&trader_two.user.pubkey()).is_some());
+    assert!(traders.contains_key(&trader_one.user.pubkey()));
+    assert!(traders.contains_key(&trader_two.user.pubkey()));
 
     // Evict seats for both traders
     let evict_seats = create_evict_seat_instruction(
@@ -93,8 +99,8 @@ async fn test_evict_seat_multiple_authorized() {
 
     // Assert that neither trader are in the market state
     let traders = sdk.get_traders().await;
-    assert!(traders.get(&trader_one.user.pubkey()).is_none());
-    assert!(traders.get(&trader_two.user.pubkey()).is_none());
+    assert!(!traders.contains_key(&trader_one.user.pubkey()));
+    assert!(!traders.contains_key(&trader_two.user.pubkey()));
 }
 
 #[tokio::test]
@@ -168,7 +174,7 @@ async fn test_evict_seat_permissionless_succeeds_when_evicting_empty_seats() {
     let traders = sdk.get_traders().await;
     let eviction_successful = traders_to_evict
         .iter()
-        .all(|i| traders.get(&new_traders[*i].user.pubkey()).is_none());
+        .all(|i| !traders.contains_key(&new_traders[*i].user.pubkey()));
 
     assert!(eviction_successful);
 }
@@ -224,8 +230,8 @@ async fn test_evict_seat_permissionless_succeeds_when_full_and_only_evicts_one()
         .unwrap();
 
     let traders = sdk.get_traders().await;
-    assert!(traders.get(&trader_one.user.pubkey()).is_some());
-    assert!(traders.get(&trader_two.user.pubkey()).is_some());
+    assert!(traders.contains_key(&trader_one.user.pubkey()));
+    assert!(traders.contains_key(&trader_two.user.pubkey()));
 
     // Need to fill market with traders
     let num_new_traders = NUM_SEATS + 100;
@@ -299,8 +305,8 @@ async fn test_evict_seat_permissionless_succeeds_when_full_and_only_evicts_one()
 
     // Assert that trader one was evicted but trader two remains
     let traders = sdk.get_traders().await;
-    assert!(traders.get(&trader_one.user.pubkey()).is_none());
-    assert!(traders.get(&trader_two.user.pubkey()).is_some());
+    assert!(!traders.contains_key(&trader_one.user.pubkey()));
+    assert!(traders.contains_key(&trader_two.user.pubkey()));
 }
 
 #[tokio::test]
@@ -747,3 +753,359 @@ async fn test_evict_seat_change_ata_owners_uses_backup_token_accounts() {
     assert!(signer_final_lamports > signer_initial_lamports);
     assert_eq!(trader_final_lamports, trader_initial_lamports);
 }
+
+#[tokio::test]
+async fn test_evict_seat_permissionless_evicts_seats_below_dust_threshold() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint).await;
+    let base_amount_before_deposit = spl_token::state::Account::unpack_from_slice(
+        sdk.client
+            .get_account_data(&trader.base_ata)
+            .await
+            .unwrap()
+            .as_slice(),
+    )
+    .unwrap()
+    .amount;
+
+    let deposit = create_deposit_funds_instruction(
+        &sdk.active_market_key,
+        &trader.user.pubkey(),
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        &DepositParams {
+            quote_lots_to_deposit: 1,
+            base_lots_to_deposit: 1,
+        },
+    );
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_claim_seat_instruction(&trader.user.pubkey(), &sdk.active_market_key),
+                deposit,
+            ],
+            vec![&trader.user],
+        )
+        .await
+        .unwrap();
+
+    let evictor = Keypair::new();
+    let evict_seat = || {
+        create_evict_seat_instruction(
+            &sdk.active_market_key,
+            &sdk.base_mint,
+            &sdk.quote_mint,
+            &evictor.pubkey(),
+            vec![EvictTraderAccountBackup {
+                trader_pubkey: trader.user.pubkey(),
+                base_token_account_backup: None,
+                quote_token_account_backup: None,
+            }],
+        )
+    };
+
+    // Without a dust threshold, a seat with free lots is not evicted on a market with free seats
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat(),
+            ],
+            vec![&evictor],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.get_traders().await.contains_key(&trader.user.pubkey()));
+
+    // Only the seat manager authority can set the dust threshold
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_dust_threshold_instruction(
+                &sdk.active_market_key,
+                &trader.user.pubkey(),
+                2,
+            )],
+            vec![&trader.user],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_dust_threshold_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                2,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat(),
+            ],
+            vec![&evictor],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader.user.pubkey()));
+
+    // The dust is returned to the trader
+    let base_amount_after_eviction = spl_token::state::Account::unpack_from_slice(
+        sdk.client
+            .get_account_data(&trader.base_ata)
+            .await
+            .unwrap()
+            .as_slice(),
+    )
+    .unwrap()
+    .amount;
+    assert_eq!(base_amount_after_eviction, base_amount_before_deposit);
+}
+
+#[tokio::test]
+async fn test_evict_seat_authority_only_eviction_policy() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader],
+        )
+        .await
+        .unwrap();
+
+    // Only the seat manager authority can set the eviction policy
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_eviction_policy_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+                EvictionPolicy::AuthorityOnly,
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_eviction_policy_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                EvictionPolicy::AuthorityOnly,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    assert_eq!(
+        SeatManager::load(&seat_manager_data)
+            .unwrap()
+            .get_eviction_policy(),
+        EvictionPolicy::AuthorityOnly
+    );
+
+    let evict_seat = |signer: &Pubkey| {
+        create_evict_seat_instruction(
+            &sdk.active_market_key,
+            &sdk.base_mint,
+            &sdk.quote_mint,
+            signer,
+            vec![EvictTraderAccountBackup {
+                trader_pubkey: trader.pubkey(),
+                base_token_account_backup: None,
+                quote_token_account_backup: None,
+            }],
+        )
+    };
+
+    // The empty seat is no longer evictable by anyone
+    let evictor = Keypair::new();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat(&evictor.pubkey()),
+            ],
+            vec![&evictor],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.get_traders().await.contains_key(&trader.pubkey()));
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat(&sdk.client.payer.pubkey()),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader.pubkey()));
+}
+
+#[tokio::test]
+async fn test_evict_seat_reads_the_number_of_traders_from_the_instruction_data() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let trader_one = Pubkey::new_unique();
+    let trader_two = Pubkey::new_unique();
+    for trader in [&trader_one, &trader_two] {
+        sdk.client
+            .sign_send_instructions(
+                vec![create_claim_seat_authorized_instruction(
+                    trader,
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                )],
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+    let evict_seat = |trader: &Pubkey| {
+        create_evict_seat_instruction(
+            &sdk.active_market_key,
+            &sdk.base_mint,
+            &sdk.quote_mint,
+            &sdk.client.payer.pubkey(),
+            vec![EvictTraderAccountBackup {
+                trader_pubkey: *trader,
+                base_token_account_backup: None,
+                quote_token_account_backup: None,
+            }],
+        )
+    };
+
+    // The trader accounts must be complete
+    let mut incomplete_evict_seat = evict_seat(&trader_one);
+    incomplete_evict_seat.data[1] = 2;
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![incomplete_evict_seat], vec![])
+        .await
+        .is_err());
+
+    // Accounts after the trader accounts are not treated as trader accounts
+    let mut evict_seat_one = evict_seat(&trader_one);
+    evict_seat_one
+        .accounts
+        .push(AccountMeta::new_readonly(Pubkey::new_unique(), false));
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat_one,
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader_one));
+
+    // Without the number of traders, every account after the signer belongs to an evicted trader
+    let mut evict_seat_two = evict_seat(&trader_two);
+    evict_seat_two.data.truncate(1);
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat_two,
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader_two));
+}
+
+#[tokio::test]
+async fn test_evict_seat_without_the_number_of_traders_fails_if_accounts_must_follow_them() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let trader = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_claim_seat_authorized_instruction(
+                    &trader,
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                ),
+                create_create_admin_history_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    &sdk.client.payer.pubkey(),
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The authority's evictions are recorded in the admin history, which follows the evicted traders
+    let evict_seat = with_admin_history(
+        create_evict_seat_instruction(
+            &sdk.active_market_key,
+            &sdk.base_mint,
+            &sdk.quote_mint,
+            &sdk.client.payer.pubkey(),
+            vec![EvictTraderAccountBackup {
+                trader_pubkey: trader,
+                base_token_account_backup: None,
+                quote_token_account_backup: None,
+            }],
+        ),
+        &sdk.active_market_key,
+    );
+    let mut legacy_evict_seat = evict_seat.clone();
+    legacy_evict_seat.data.truncate(1);
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![legacy_evict_seat], vec![])
+        .await
+        .is_err());
+    assert!(sdk.get_traders().await.contains_key(&trader));
+
+    sdk.client
+        .sign_send_instructions(vec![evict_seat], vec![])
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader));
+}
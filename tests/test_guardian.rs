@@ -0,0 +1,218 @@
+mod setup;
+use std::mem::size_of;
+
+use crate::setup::helpers::airdrop;
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::PhoenixTestClient;
+use phoenix::program::status::MarketStatus;
+use phoenix::program::MarketHeader;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_change_market_status_instruction;
+use phoenix_seat_manager::instruction_builders::create_clear_guardian_instruction;
+use phoenix_seat_manager::instruction_builders::create_confirm_renounce_seat_manager_authority_instruction;
+use phoenix_seat_manager::instruction_builders::create_guardian_pause_market_instruction;
+use phoenix_seat_manager::instruction_builders::create_initiate_renounce_seat_manager_authority_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_guardian_instruction;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn get_market_status(sdk: &SDKClient) -> u64 {
+    let market_data = sdk
+        .client
+        .get_account_data(&sdk.active_market_key)
+        .await
+        .unwrap();
+    let (header_bytes, _) = market_data.split_at(size_of::<MarketHeader>());
+    bytemuck::try_from_bytes::<MarketHeader>(header_bytes)
+        .unwrap()
+        .status
+}
+
+#[tokio::test]
+async fn test_guardian_pause_market() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let guardian = Keypair::new();
+    airdrop(&sdk.client, &guardian.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+
+    // Fails if no guardian is set
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_guardian_pause_market_instruction(
+                &market,
+                &guardian.pubkey(),
+                MarketStatus::Paused,
+            )],
+            vec![&guardian],
+        )
+        .await
+        .is_err());
+
+    // Only the authority can set the guardian
+    assert!(sdk
+        .client
+        .sign_send_instructions_with_payer(
+            vec![create_set_guardian_instruction(
+                &market,
+                &guardian.pubkey(),
+                &guardian.pubkey(),
+            )],
+            vec![&guardian],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_guardian_instruction(
+                &market,
+                &authority,
+                &guardian.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    sdk.client
+        .sign_send_instructions_with_payer(
+            vec![create_guardian_pause_market_instruction(
+                &market,
+                &guardian.pubkey(),
+                MarketStatus::Paused,
+            )],
+            vec![&guardian],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_market_status(&sdk).await, MarketStatus::Paused as u64);
+
+    // The guardian cannot re-activate the market, through either instruction
+    assert!(sdk
+        .client
+        .sign_send_instructions_with_payer(
+            vec![create_guardian_pause_market_instruction(
+                &market,
+                &guardian.pubkey(),
+                MarketStatus::Active,
+            )],
+            vec![&guardian],
+        )
+        .await
+        .is_err());
+    assert!(sdk
+        .client
+        .sign_send_instructions_with_payer(
+            vec![create_change_market_status_instruction(
+                &market,
+                &guardian.pubkey(),
+                MarketStatus::Active,
+            )],
+            vec![&guardian],
+        )
+        .await
+        .is_err());
+    assert_eq!(get_market_status(&sdk).await, MarketStatus::Paused as u64);
+
+    // The authority can
+    sdk.client
+        .sign_send_instructions(
+            vec![create_change_market_status_instruction(
+                &market,
+                &authority,
+                MarketStatus::Active,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_clear_guardian_instruction(&market, &authority)],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(sdk
+        .client
+        .sign_send_instructions_with_payer(
+            vec![create_guardian_pause_market_instruction(
+                &market,
+                &guardian.pubkey(),
+                MarketStatus::PostOnly,
+            )],
+            vec![&guardian],
+        )
+        .await
+        .is_err());
+    assert_eq!(get_market_status(&sdk).await, MarketStatus::Active as u64);
+}
+
+#[tokio::test]
+async fn test_guardian_cannot_pause_renounced_market() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let guardian = Keypair::new();
+    airdrop(&sdk.client, &guardian.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_set_guardian_instruction(&market, &authority, &guardian.pubkey()),
+                create_initiate_renounce_seat_manager_authority_instruction(&authority, &market),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_confirm_renounce_seat_manager_authority_instruction(
+                &authority, &market,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&market).0)
+        .await
+        .unwrap();
+    let seat_manager = bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap();
+    assert_eq!(seat_manager.get_guardian(), None);
+
+    assert!(sdk
+        .client
+        .sign_send_instructions_with_payer(
+            vec![create_guardian_pause_market_instruction(
+                &market,
+                &guardian.pubkey(),
+                MarketStatus::Paused,
+            )],
+            vec![&guardian],
+        )
+        .await
+        .is_err());
+    assert_eq!(get_market_status(&sdk).await, MarketStatus::Active as u64);
+}
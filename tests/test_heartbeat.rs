@@ -0,0 +1,325 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::setup_account;
+use crate::setup::init::PhoenixTestClient;
+use crate::setup::init::NUM_SEATS;
+use phoenix::program::create_deposit_funds_instruction;
+use phoenix::program::deposit::DepositParams;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::get_seat_record_address;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_evict_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_evict_seat_with_seat_records_instruction;
+use phoenix_seat_manager::instruction_builders::create_heartbeat_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_heartbeat_staleness_window_instruction;
+use phoenix_seat_manager::instruction_builders::EvictTraderAccountBackup;
+use phoenix_seat_manager::seat_record::SeatRecord;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn fill_market(sdk: &SDKClient) {
+    while sdk.get_traders().await.len() < NUM_SEATS {
+        let claim_seat = create_claim_seat_authorized_instruction(
+            &Pubkey::new_unique(),
+            &sdk.active_market_key,
+            &sdk.client.payer.pubkey(),
+        );
+        sdk.client
+            .sign_send_instructions(vec![claim_seat], vec![])
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_heartbeat_protects_active_seats_from_eviction() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let staleness_window_in_slots = 100;
+
+    // Only the seat manager authority can set the staleness window
+    let non_authority = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_heartbeat_staleness_window_instruction(
+                &sdk.active_market_key,
+                &non_authority.pubkey(),
+                staleness_window_in_slots,
+            )],
+            vec![&non_authority],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_heartbeat_staleness_window_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                staleness_window_in_slots,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Both traders have free lots, so their seats are not empty
+    let active_trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let inactive_trader =
+        setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+            .await
+            .user;
+    for trader in [&active_trader, &inactive_trader] {
+        sdk.client
+            .sign_send_instructions(
+                vec![
+                    create_claim_seat_instruction(&trader.pubkey(), &sdk.active_market_key),
+                    create_deposit_funds_instruction(
+                        &sdk.active_market_key,
+                        &trader.pubkey(),
+                        &sdk.base_mint,
+                        &sdk.quote_mint,
+                        &DepositParams {
+                            quote_lots_to_deposit: 1,
+                            base_lots_to_deposit: 1,
+                        },
+                    ),
+                ],
+                vec![trader],
+            )
+            .await
+            .unwrap();
+    }
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_heartbeat_instruction(
+                &sdk.active_market_key,
+                &active_trader.pubkey(),
+            )],
+            vec![&active_trader],
+        )
+        .await
+        .unwrap();
+    let seat_record_data = sdk
+        .client
+        .get_account_data(
+            &get_seat_record_address(&sdk.active_market_key, &active_trader.pubkey()).0,
+        )
+        .await
+        .unwrap();
+    let seat_record = SeatRecord::load(&seat_record_data).unwrap();
+    assert_eq!(seat_record.market, sdk.active_market_key);
+    assert_eq!(seat_record.trader, active_trader.pubkey());
+
+    fill_market(&sdk).await;
+
+    let evictor = Keypair::new();
+    let evict_seats = |traders: Vec<Pubkey>| {
+        create_evict_seat_with_seat_records_instruction(
+            &sdk.active_market_key,
+            &sdk.base_mint,
+            &sdk.quote_mint,
+            &evictor.pubkey(),
+            traders
+                .into_iter()
+                .map(|trader_pubkey| EvictTraderAccountBackup {
+                    trader_pubkey,
+                    base_token_account_backup: None,
+                    quote_token_account_backup: None,
+                })
+                .collect(),
+        )
+    };
+
+    // Seat records are required while the staleness window is set
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                create_evict_seat_instruction(
+                    &sdk.active_market_key,
+                    &sdk.base_mint,
+                    &sdk.quote_mint,
+                    &evictor.pubkey(),
+                    vec![EvictTraderAccountBackup {
+                        trader_pubkey: inactive_trader.pubkey(),
+                        base_token_account_backup: None,
+                        quote_token_account_backup: None,
+                    }],
+                ),
+            ],
+            vec![&evictor],
+        )
+        .await
+        .is_err());
+
+    // The trader with a recent heartbeat is skipped, and the trader without a seat record is evicted
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seats(vec![active_trader.pubkey(), inactive_trader.pubkey()]),
+            ],
+            vec![&evictor],
+        )
+        .await
+        .unwrap();
+    let traders = sdk.get_traders().await;
+    assert!(traders.contains_key(&active_trader.pubkey()));
+    assert!(!traders.contains_key(&inactive_trader.pubkey()));
+
+    // Once the heartbeat is older than the staleness window, the seat can be evicted
+    fill_market(&sdk).await;
+    ctx.warp_to_slot(seat_record.last_active_slot + staleness_window_in_slots + 1)
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seats(vec![active_trader.pubkey()]),
+            ],
+            vec![&evictor],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk
+        .get_traders()
+        .await
+        .contains_key(&active_trader.pubkey()));
+}
+
+#[tokio::test]
+async fn test_heartbeat_protects_active_seats_from_the_authority_on_a_full_market() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let staleness_window_in_slots = 100;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_heartbeat_staleness_window_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                staleness_window_in_slots,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let active_trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_claim_seat_instruction(&active_trader.pubkey(), &sdk.active_market_key),
+                create_deposit_funds_instruction(
+                    &sdk.active_market_key,
+                    &active_trader.pubkey(),
+                    &sdk.base_mint,
+                    &sdk.quote_mint,
+                    &DepositParams {
+                        quote_lots_to_deposit: 1,
+                        base_lots_to_deposit: 1,
+                    },
+                ),
+                create_heartbeat_instruction(&sdk.active_market_key, &active_trader.pubkey()),
+            ],
+            vec![&active_trader],
+        )
+        .await
+        .unwrap();
+    let seat_record_data = sdk
+        .client
+        .get_account_data(
+            &get_seat_record_address(&sdk.active_market_key, &active_trader.pubkey()).0,
+        )
+        .await
+        .unwrap();
+    let seat_record = SeatRecord::load(&seat_record_data).unwrap();
+
+    // The empty seat is not protected by a heartbeat
+    let empty_trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &empty_trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&empty_trader],
+        )
+        .await
+        .unwrap();
+
+    fill_market(&sdk).await;
+
+    let evict_seats = |traders: Vec<Pubkey>| {
+        create_evict_seat_with_seat_records_instruction(
+            &sdk.active_market_key,
+            &sdk.base_mint,
+            &sdk.quote_mint,
+            &sdk.client.payer.pubkey(),
+            traders
+                .into_iter()
+                .map(|trader_pubkey| EvictTraderAccountBackup {
+                    trader_pubkey,
+                    base_token_account_backup: None,
+                    quote_token_account_backup: None,
+                })
+                .collect(),
+        )
+    };
+
+    // The authority skips the trader with a recent heartbeat while the market is full
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seats(vec![active_trader.pubkey(), empty_trader.pubkey()]),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    let traders = sdk.get_traders().await;
+    assert!(traders.contains_key(&active_trader.pubkey()));
+    assert!(!traders.contains_key(&empty_trader.pubkey()));
+
+    // Once the heartbeat is older than the staleness window, the authority can evict the seat
+    fill_market(&sdk).await;
+    ctx.warp_to_slot(seat_record.last_active_slot + staleness_window_in_slots + 1)
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seats(vec![active_trader.pubkey()]),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk
+        .get_traders()
+        .await
+        .contains_key(&active_trader.pubkey()));
+}
@@ -0,0 +1,380 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::setup_account;
+use crate::setup::init::PhoenixTestClient;
+use crate::setup::init::NUM_SEATS;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_add_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_bid_for_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_configure_seat_auction_instruction;
+use phoenix_seat_manager::instruction_builders::create_join_seat_waitlist_instruction;
+use phoenix_seat_manager::instruction_builders::create_remove_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_launch_phase_end_slot_instruction;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+async fn get_seat_manager(sdk: &SDKClient) -> SeatManager {
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    *bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap()
+}
+
+#[tokio::test]
+async fn test_launch_phase_only_seats_dmms() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let launch_phase_end_slot = 1_000;
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let dmm = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+
+    // Only the seat manager authority can start a launch phase
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_launch_phase_end_slot_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+                launch_phase_end_slot,
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_launch_phase_end_slot_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                launch_phase_end_slot,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Permissionless claims are refused during the launch phase
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+
+    // DMMs can be pre-registered without a seat, and then claim their own seat
+    sdk.client
+        .sign_send_instructions(
+            vec![create_add_dmm_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                &dmm.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &dmm.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&dmm],
+        )
+        .await
+        .unwrap();
+
+    // The authority can still seat traders
+    let authorized_trader = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_authorized_instruction(
+                &authorized_trader,
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The launch phase ends without an authority transaction
+    ctx.warp_to_slot(launch_phase_end_slot).unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader],
+        )
+        .await
+        .unwrap();
+
+    let traders = sdk.get_traders().await;
+    assert!(traders.contains_key(&dmm.pubkey()));
+    assert!(traders.contains_key(&authorized_trader));
+    assert!(traders.contains_key(&trader.pubkey()));
+
+    // The launch phase cannot be reopened once it has ended
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_launch_phase_end_slot_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                launch_phase_end_slot + 1_000,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_launch_phase_blocks_the_waitlist_and_auction() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let launch_phase_end_slot = 1_000;
+    let deposit_amount = sdk.client.rent_exempt(spl_token::state::Account::LEN) * 2;
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_configure_seat_auction_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    deposit_amount,
+                    10,
+                ),
+                create_set_launch_phase_end_slot_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    launch_phase_end_slot,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    while sdk.get_traders().await.len() < NUM_SEATS {
+        sdk.client
+            .sign_send_instructions(
+                vec![create_claim_seat_authorized_instruction(
+                    &Pubkey::new_unique(),
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                )],
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+
+    // Non-DMM traders can neither queue nor bid for a seat during the launch phase
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let join_waitlist =
+        || create_join_seat_waitlist_instruction(&trader.pubkey(), &sdk.active_market_key);
+    let bid_for_seat = || {
+        create_bid_for_seat_instruction(
+            &sdk.active_market_key,
+            &trader.pubkey(),
+            None,
+            deposit_amount,
+        )
+    };
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![join_waitlist()], vec![&trader])
+        .await
+        .is_err());
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![bid_for_seat()], vec![&trader])
+        .await
+        .is_err());
+
+    ctx.warp_to_slot(launch_phase_end_slot).unwrap();
+    sdk.client
+        .sign_send_instructions(vec![join_waitlist()], vec![&trader])
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(vec![bid_for_seat()], vec![&trader])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_launch_phase_claim_seat_seats_a_pre_registered_dmm() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let dmm = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_set_launch_phase_end_slot_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    1_000,
+                ),
+                create_add_dmm_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    &dmm.pubkey(),
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(get_seat_manager(&sdk).await.contains(&dmm.pubkey()));
+    assert!(!sdk.get_traders().await.contains_key(&dmm.pubkey()));
+
+    // A trader that was not pre-registered as a DMM cannot claim a seat
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+    assert!(!sdk.get_traders().await.contains_key(&trader.pubkey()));
+
+    // A single ClaimSeat requests and approves the pre-registered DMM's seat
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &dmm.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&dmm],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.get_traders().await.contains_key(&dmm.pubkey()));
+    assert!(get_seat_manager(&sdk).await.contains(&dmm.pubkey()));
+}
+
+#[tokio::test]
+async fn test_seatless_dmms_can_only_be_removed_during_the_launch_phase() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let launch_phase_end_slot = 1_000;
+    let dmm = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let other_dmm = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_set_launch_phase_end_slot_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    launch_phase_end_slot,
+                ),
+                create_add_dmm_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    &dmm.pubkey(),
+                ),
+                create_add_dmm_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    &other_dmm,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // A pre-registered DMM can be dropped before it claims its seat
+    sdk.client
+        .sign_send_instructions(
+            vec![create_remove_dmm_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                &other_dmm,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!get_seat_manager(&sdk).await.contains(&other_dmm));
+
+    // Once the launch phase has ended, DMMs must have a seat to be removed
+    ctx.warp_to_slot(launch_phase_end_slot).unwrap();
+    let remove_dmm = || {
+        create_remove_dmm_instruction(
+            &sdk.active_market_key,
+            &sdk.client.payer.pubkey(),
+            &dmm.pubkey(),
+        )
+    };
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![remove_dmm()], vec![])
+        .await
+        .is_err());
+    assert!(get_seat_manager(&sdk).await.contains(&dmm.pubkey()));
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &dmm.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&dmm],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(vec![remove_dmm()], vec![])
+        .await
+        .unwrap();
+    assert!(!get_seat_manager(&sdk).await.contains(&dmm.pubkey()));
+}
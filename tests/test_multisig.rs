@@ -0,0 +1,269 @@
+mod setup;
+
+use std::mem::size_of;
+
+use crate::setup::helpers::airdrop;
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::PhoenixTestClient;
+use phoenix::program::status::MarketStatus;
+use phoenix::program::MarketHeader;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::get_multisig_address;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_add_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_change_market_status_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_manager_authority_instruction;
+use phoenix_seat_manager::instruction_builders::create_create_multisig_instruction;
+use phoenix_seat_manager::instruction_builders::create_name_seat_manager_successor_instruction;
+use phoenix_seat_manager::instruction_builders::create_update_multisig_instruction;
+use phoenix_seat_manager::instruction_builders::with_multisig_approval;
+use phoenix_seat_manager::multisig::Multisig;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn get_seat_manager(sdk: &SDKClient) -> SeatManager {
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    *bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap()
+}
+
+async fn get_market_status(sdk: &SDKClient) -> u64 {
+    let market_data = sdk
+        .client
+        .get_account_data(&sdk.active_market_key)
+        .await
+        .unwrap();
+    let (header_bytes, _) = market_data.split_at(size_of::<MarketHeader>());
+    bytemuck::try_from_bytes::<MarketHeader>(header_bytes)
+        .unwrap()
+        .status
+}
+
+#[tokio::test]
+async fn test_multisig_authority() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let (multisig, _) = get_multisig_address(&market);
+    let signers = (0..3).map(|_| Keypair::new()).collect::<Vec<_>>();
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<Pubkey>>();
+    airdrop(&sdk.client, &signer_keys[0], 1_000_000_000)
+        .await
+        .unwrap();
+
+    // Create a 2-of-3 multisig and hand it the seat manager authority
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_create_multisig_instruction(
+                    &market,
+                    &sdk.client.payer.pubkey(),
+                    &sdk.client.payer.pubkey(),
+                    2,
+                    signer_keys.clone(),
+                ),
+                create_name_seat_manager_successor_instruction(
+                    &sdk.client.payer.pubkey(),
+                    &market,
+                    &multisig,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let claim_authority = create_claim_seat_manager_authority_instruction(&market, &multisig);
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![with_multisig_approval(
+                claim_authority.clone(),
+                &market,
+                &signer_keys[..1]
+            )],
+            vec![&signers[0]],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![with_multisig_approval(
+                claim_authority,
+                &market,
+                &signer_keys[..2],
+            )],
+            vec![&signers[0], &signers[1]],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_seat_manager(&sdk).await.authority, multisig);
+
+    // The previous authority can no longer act
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_change_market_status_instruction(
+                &market,
+                &sdk.client.payer.pubkey(),
+                MarketStatus::Paused,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    // Status changes need two of the three signers
+    let pause_market =
+        create_change_market_status_instruction(&market, &multisig, MarketStatus::Paused);
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![with_multisig_approval(
+                pause_market.clone(),
+                &market,
+                &signer_keys[1..2]
+            )],
+            vec![&signers[1]],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![with_multisig_approval(
+                pause_market,
+                &market,
+                &signer_keys[1..],
+            )],
+            vec![&signers[1], &signers[2]],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_market_status(&sdk).await, MarketStatus::Paused as u64);
+
+    // Authorized claims are paid by one of the approving signers
+    let trader = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                with_multisig_approval(
+                    create_claim_seat_authorized_instruction(&trader, &market, &signer_keys[0]),
+                    &market,
+                    &signer_keys[..2],
+                ),
+                with_multisig_approval(
+                    create_add_dmm_instruction(&market, &multisig, &trader),
+                    &market,
+                    &signer_keys[..2],
+                ),
+            ],
+            vec![&signers[0], &signers[1]],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.get_traders().await.contains_key(&trader));
+    assert!(get_seat_manager(&sdk).await.contains(&trader));
+}
+
+#[tokio::test]
+async fn test_update_multisig() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let signers = (0..3).map(|_| Keypair::new()).collect::<Vec<_>>();
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<Pubkey>>();
+
+    // Only the seat manager authority can create the multisig, and the threshold must be reachable
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_create_multisig_instruction(
+                &market,
+                &signer_keys[0],
+                &sdk.client.payer.pubkey(),
+                2,
+                signer_keys.clone(),
+            )],
+            vec![&signers[0]],
+        )
+        .await
+        .is_err());
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_create_multisig_instruction(
+                &market,
+                &sdk.client.payer.pubkey(),
+                &sdk.client.payer.pubkey(),
+                4,
+                signer_keys.clone(),
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_create_multisig_instruction(
+                &market,
+                &sdk.client.payer.pubkey(),
+                &sdk.client.payer.pubkey(),
+                2,
+                signer_keys.clone(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The multisig can only be updated by its own signers
+    let new_signer = Pubkey::new_unique();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_update_multisig_instruction(
+                &market,
+                &signer_keys[..1],
+                1,
+                vec![new_signer],
+            )],
+            vec![&signers[0]],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_update_multisig_instruction(
+                &market,
+                &signer_keys[..2],
+                1,
+                vec![new_signer],
+            )],
+            vec![&signers[0], &signers[1]],
+        )
+        .await
+        .unwrap();
+
+    let multisig_data = sdk
+        .client
+        .get_account_data(&get_multisig_address(&market).0)
+        .await
+        .unwrap();
+    let multisig = Multisig::load(&multisig_data).unwrap();
+    assert_eq!(multisig.threshold, 1);
+    assert_eq!(multisig.get_signers(), &[new_signer]);
+}
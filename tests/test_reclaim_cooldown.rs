@@ -0,0 +1,326 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::setup_account;
+use crate::setup::init::PhoenixTestClient;
+use crate::setup::init::NUM_SEATS;
+use phoenix_seat_manager::get_seat_record_address;
+use phoenix_seat_manager::instruction_builders::create_bid_for_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_configure_seat_auction_instruction;
+use phoenix_seat_manager::instruction_builders::create_evict_seat_with_seat_records_instruction;
+use phoenix_seat_manager::instruction_builders::create_heartbeat_instruction;
+use phoenix_seat_manager::instruction_builders::create_join_seat_waitlist_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_reclaim_cooldown_instruction;
+use phoenix_seat_manager::instruction_builders::EvictTraderAccountBackup;
+use phoenix_seat_manager::seat_record::SeatRecord;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn test_reclaim_cooldown() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let reclaim_cooldown_in_slots = 1_000;
+    // A last evicted slot of 0 means the trader was never evicted
+    ctx.warp_to_slot(100).unwrap();
+
+    // Only the seat manager authority can set the re-claim cooldown
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_reclaim_cooldown_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+                reclaim_cooldown_in_slots,
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_reclaim_cooldown_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                reclaim_cooldown_in_slots,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The first permissionless claim creates the trader's seat record
+    let claim_seat = || create_claim_seat_instruction(&trader.pubkey(), &sdk.active_market_key);
+    sdk.client
+        .sign_send_instructions(vec![claim_seat()], vec![&trader])
+        .await
+        .unwrap();
+    let (seat_record, _) = get_seat_record_address(&sdk.active_market_key, &trader.pubkey());
+    assert!(sdk.client.get_account_data(&seat_record).await.is_ok());
+
+    let evict_seat = || {
+        create_evict_seat_with_seat_records_instruction(
+            &sdk.active_market_key,
+            &sdk.base_mint,
+            &sdk.quote_mint,
+            &sdk.client.payer.pubkey(),
+            vec![EvictTraderAccountBackup {
+                trader_pubkey: trader.pubkey(),
+                base_token_account_backup: None,
+                quote_token_account_backup: None,
+            }],
+        )
+    };
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat(),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader.pubkey()));
+
+    let seat_record_data = sdk.client.get_account_data(&seat_record).await.unwrap();
+    let last_evicted_slot = SeatRecord::load(&seat_record_data)
+        .unwrap()
+        .last_evicted_slot;
+    assert_ne!(last_evicted_slot, 0);
+
+    // The evicted trader cannot claim a seat permissionlessly during the cooldown
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![claim_seat()], vec![&trader])
+        .await
+        .is_err());
+
+    // The authority can bypass the cooldown
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_authorized_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.get_traders().await.contains_key(&trader.pubkey()));
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat(),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Once the cooldown ends, the trader can claim a seat again
+    ctx.warp_to_slot(last_evicted_slot + reclaim_cooldown_in_slots + 1)
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(vec![claim_seat()], vec![&trader])
+        .await
+        .unwrap();
+    assert!(sdk.get_traders().await.contains_key(&trader.pubkey()));
+}
+
+#[tokio::test]
+async fn test_reclaim_cooldown_applies_to_authorized_claimants_on_the_waitlist_and_auction() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let reclaim_cooldown_in_slots = 1_000;
+    ctx.warp_to_slot(100).unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_reclaim_cooldown_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                reclaim_cooldown_in_slots,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    let deposit_amount = sdk.client.rent_exempt(spl_token::state::Account::LEN) * 2;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_configure_seat_auction_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                deposit_amount,
+                10,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // A trader seated by the authority has no seat record
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_authorized_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    let (seat_record, _) = get_seat_record_address(&sdk.active_market_key, &trader.pubkey());
+    assert!(sdk.client.get_account_data(&seat_record).await.is_err());
+
+    // The evictor does not pay for seat records, so a trader without one is evicted without a cooldown
+    let untracked_trader =
+        setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+            .await
+            .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_authorized_instruction(
+                &untracked_trader.pubkey(),
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                create_evict_seat_with_seat_records_instruction(
+                    &sdk.active_market_key,
+                    &sdk.base_mint,
+                    &sdk.quote_mint,
+                    &sdk.client.payer.pubkey(),
+                    vec![EvictTraderAccountBackup {
+                        trader_pubkey: untracked_trader.pubkey(),
+                        base_token_account_backup: None,
+                        quote_token_account_backup: None,
+                    }],
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(sdk
+        .client
+        .get_account_data(
+            &get_seat_record_address(&sdk.active_market_key, &untracked_trader.pubkey()).0
+        )
+        .await
+        .is_err());
+
+    // The trader pays for its own seat record with a heartbeat, and eviction then starts the cooldown
+    sdk.client
+        .sign_send_instructions(
+            vec![create_heartbeat_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+            )],
+            vec![&trader],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                create_evict_seat_with_seat_records_instruction(
+                    &sdk.active_market_key,
+                    &sdk.base_mint,
+                    &sdk.quote_mint,
+                    &sdk.client.payer.pubkey(),
+                    vec![EvictTraderAccountBackup {
+                        trader_pubkey: trader.pubkey(),
+                        base_token_account_backup: None,
+                        quote_token_account_backup: None,
+                    }],
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader.pubkey()));
+    let seat_record_data = sdk.client.get_account_data(&seat_record).await.unwrap();
+    let last_evicted_slot = SeatRecord::load(&seat_record_data)
+        .unwrap()
+        .last_evicted_slot;
+    assert_ne!(last_evicted_slot, 0);
+
+    while sdk.get_traders().await.len() < NUM_SEATS {
+        sdk.client
+            .sign_send_instructions(
+                vec![create_claim_seat_authorized_instruction(
+                    &Pubkey::new_unique(),
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                )],
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+
+    // During the cooldown, the trader can neither queue nor bid for a seat
+    let join_waitlist =
+        || create_join_seat_waitlist_instruction(&trader.pubkey(), &sdk.active_market_key);
+    let bid_for_seat = || {
+        create_bid_for_seat_instruction(
+            &sdk.active_market_key,
+            &trader.pubkey(),
+            None,
+            deposit_amount,
+        )
+    };
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![join_waitlist()], vec![&trader])
+        .await
+        .is_err());
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![bid_for_seat()], vec![&trader])
+        .await
+        .is_err());
+
+    ctx.warp_to_slot(last_evicted_slot + reclaim_cooldown_in_slots + 1)
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(vec![join_waitlist()], vec![&trader])
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(vec![bid_for_seat()], vec![&trader])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,201 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::PhoenixTestClient;
+use phoenix::program::status::MarketStatus;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::capability::SeatManagerCapability;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_add_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_change_market_status_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_confirm_renounce_capabilities_instruction;
+use phoenix_seat_manager::instruction_builders::create_initiate_renounce_capabilities_instruction;
+use phoenix_seat_manager::instruction_builders::create_remove_dmm_instruction;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn get_seat_manager(sdk: &SDKClient) -> SeatManager {
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    *bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap()
+}
+
+#[tokio::test]
+async fn test_renounce_capabilities() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let capabilities = [
+        SeatManagerCapability::ChangeMarketStatus,
+        SeatManagerCapability::RemoveDmms,
+    ];
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_initiate_renounce_capabilities_instruction(
+                &market,
+                &authority,
+                &capabilities,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_seat_manager(&sdk).await.renounced_capabilities, 0);
+
+    // The confirmed capabilities must match the staged ones
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_confirm_renounce_capabilities_instruction(
+                &market,
+                &authority,
+                &[SeatManagerCapability::ChangeMarketStatus],
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    // Only the authority can confirm
+    let non_authority = Keypair::new();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_confirm_renounce_capabilities_instruction(
+                &market,
+                &non_authority.pubkey(),
+                &capabilities,
+            )],
+            vec![&non_authority],
+        )
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_confirm_renounce_capabilities_instruction(
+                &market,
+                &authority,
+                &capabilities,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    let seat_manager = get_seat_manager(&sdk).await;
+    assert_eq!(
+        seat_manager.renounced_capabilities,
+        SeatManagerCapability::to_mask(&capabilities)
+    );
+    assert_eq!(seat_manager.pending_renounced_capabilities, 0);
+
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_change_market_status_instruction(
+                &market,
+                &authority,
+                MarketStatus::Paused,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    // Capabilities that were not renounced are kept
+    let trader = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_claim_seat_authorized_instruction(&trader, &market, &authority),
+                create_add_dmm_instruction(&market, &authority, &trader),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(get_seat_manager(&sdk).await.contains(&trader));
+
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_remove_dmm_instruction(&market, &authority, &trader)],
+            vec![],
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_cancel_renounce_capabilities() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let capabilities = [SeatManagerCapability::ChangeMarketStatus];
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_initiate_renounce_capabilities_instruction(
+                &market,
+                &authority,
+                &capabilities,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Staging an empty bitmask cancels the renunciation
+    sdk.client
+        .sign_send_instructions(
+            vec![create_initiate_renounce_capabilities_instruction(
+                &market,
+                &authority,
+                &[],
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_confirm_renounce_capabilities_instruction(
+                &market,
+                &authority,
+                &capabilities,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_change_market_status_instruction(
+                &market,
+                &authority,
+                MarketStatus::Paused,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,234 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::setup_account;
+use crate::setup::init::PhoenixTestClient;
+use crate::setup::init::NUM_SEATS;
+use phoenix_seat_manager::instruction_builders::create_add_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_with_eviction_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_reserved_seats_instruction;
+use phoenix_seat_manager::instruction_builders::EvictTraderAccountBackup;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn test_reserved_seats_limit_permissionless_claims() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    // Only the seat manager authority can reserve seats, and no more than the market's seats
+    let trader_one = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_reserved_seats_instruction(
+                &sdk.active_market_key,
+                &trader_one.pubkey(),
+                1,
+            )],
+            vec![&trader_one],
+        )
+        .await
+        .is_err());
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_reserved_seats_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                NUM_SEATS as u64 + 1,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    // Leave room for two non-DMM traders
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_reserved_seats_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                NUM_SEATS as u64 - 2,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // DMM seats do not count towards the non-DMM seat cap
+    let dmm = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_add_dmm_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    &dmm,
+                ),
+                create_claim_seat_authorized_instruction(
+                    &dmm,
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &trader_one.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader_one],
+        )
+        .await
+        .unwrap();
+
+    // Authority claims can use the reserved seats
+    let authorized_trader = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_authorized_instruction(
+                &authorized_trader,
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The non-DMM seat cap is reached, so permissionless claims are refused
+    let trader_two = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &trader_two.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader_two],
+        )
+        .await
+        .is_err());
+
+    let traders = sdk.get_traders().await;
+    assert!(traders.contains_key(&dmm));
+    assert!(traders.contains_key(&trader_one.pubkey()));
+    assert!(traders.contains_key(&authorized_trader));
+    assert!(!traders.contains_key(&trader_two.pubkey()));
+}
+
+#[tokio::test]
+async fn test_reserved_seats_limit_claims_with_eviction() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    // Leave room for one non-DMM trader
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_reserved_seats_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                NUM_SEATS as u64 - 1,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The authority seats a second non-DMM trader in a reserved seat
+    let trader_one = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let authorized_trader =
+        setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+            .await
+            .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_claim_seat_instruction(&trader_one.pubkey(), &sdk.active_market_key),
+                create_claim_seat_authorized_instruction(
+                    &authorized_trader.pubkey(),
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                ),
+            ],
+            vec![&trader_one],
+        )
+        .await
+        .unwrap();
+
+    let trader_two = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let claim_seat_with_eviction = |evicted_trader: Pubkey| {
+        vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            create_claim_seat_with_eviction_instruction(
+                &trader_two.pubkey(),
+                &sdk.active_market_key,
+                &sdk.base_mint,
+                &sdk.quote_mint,
+                EvictTraderAccountBackup {
+                    trader_pubkey: evicted_trader,
+                    base_token_account_backup: None,
+                    quote_token_account_backup: None,
+                },
+            ),
+        ]
+    };
+
+    // Evicting an empty seat would free a seat beyond the non-DMM seat cap, so it cannot be refilled permissionlessly
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            claim_seat_with_eviction(trader_one.pubkey()),
+            vec![&trader_two]
+        )
+        .await
+        .is_err());
+
+    // Once the non-DMM seat cap is reached rather than exceeded, a non-DMM trader's seat can be taken
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_reserved_seats_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                NUM_SEATS as u64 - 2,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            claim_seat_with_eviction(authorized_trader.pubkey()),
+            vec![&trader_two],
+        )
+        .await
+        .unwrap();
+
+    let traders = sdk.get_traders().await;
+    assert!(traders.contains_key(&trader_one.pubkey()));
+    assert!(!traders.contains_key(&authorized_trader.pubkey()));
+    assert!(traders.contains_key(&trader_two.pubkey()));
+}
@@ -0,0 +1,150 @@
+mod setup;
+
+use crate::setup::helpers::airdrop;
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::PhoenixTestClient;
+use phoenix::program::status::MarketStatus;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_add_dmm_instruction;
+use phoenix_seat_manager::instruction_builders::create_change_market_status_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_revoke_role_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_role_instruction;
+use phoenix_seat_manager::role::SeatManagerRole;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn test_roles() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let keeper = Keypair::new();
+    airdrop(&sdk.client, &keeper.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+
+    // Only the seat manager authority can assign roles
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_role_instruction(
+                &market,
+                &keeper.pubkey(),
+                SeatManagerRole::StatusOperator,
+                &keeper.pubkey(),
+            )],
+            vec![&keeper],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_set_role_instruction(
+                    &market,
+                    &sdk.client.payer.pubkey(),
+                    SeatManagerRole::StatusOperator,
+                    &keeper.pubkey(),
+                ),
+                create_set_role_instruction(
+                    &market,
+                    &sdk.client.payer.pubkey(),
+                    SeatManagerRole::AuthorizedClaimer,
+                    &keeper.pubkey(),
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&market).0)
+        .await
+        .unwrap();
+    let seat_manager = bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap();
+    assert_eq!(
+        seat_manager.get_role_holder(SeatManagerRole::StatusOperator),
+        Some(keeper.pubkey())
+    );
+    assert_eq!(
+        seat_manager.get_role_holder(SeatManagerRole::FeeAdmin),
+        None
+    );
+
+    // The keeper can change the market status and claim seats, but cannot manage DMMs
+    let trader = Pubkey::new_unique();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_change_market_status_instruction(
+                    &market,
+                    &keeper.pubkey(),
+                    MarketStatus::PostOnly,
+                ),
+                create_claim_seat_authorized_instruction(&trader, &market, &keeper.pubkey()),
+            ],
+            vec![&keeper],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.get_traders().await.contains_key(&trader));
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_add_dmm_instruction(
+                &market,
+                &keeper.pubkey(),
+                &trader
+            )],
+            vec![&keeper],
+        )
+        .await
+        .is_err());
+
+    // The authority keeps the powers of every role
+    sdk.client
+        .sign_send_instructions(
+            vec![create_change_market_status_instruction(
+                &market,
+                &sdk.client.payer.pubkey(),
+                MarketStatus::Active,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Revoked roles can no longer be exercised
+    sdk.client
+        .sign_send_instructions(
+            vec![create_revoke_role_instruction(
+                &market,
+                &sdk.client.payer.pubkey(),
+                SeatManagerRole::StatusOperator,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_change_market_status_instruction(
+                &market,
+                &keeper.pubkey(),
+                MarketStatus::Paused,
+            )],
+            vec![&keeper],
+        )
+        .await
+        .is_err());
+}
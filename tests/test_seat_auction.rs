@@ -0,0 +1,564 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::setup_account;
+use crate::setup::init::PhoenixTestClient;
+use crate::setup::init::NUM_SEATS;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::eviction_policy::EvictionPolicy;
+use phoenix_seat_manager::get_seat_auction_address;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::get_treasury_address;
+use phoenix_seat_manager::instruction_builders::create_bid_for_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_configure_seat_auction_instruction;
+use phoenix_seat_manager::instruction_builders::create_join_seat_waitlist_instruction;
+use phoenix_seat_manager::instruction_builders::create_leave_seat_waitlist_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_eviction_policy_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_permissionless_access_instruction;
+use phoenix_seat_manager::instruction_builders::create_settle_seat_auction_instruction;
+use phoenix_seat_manager::instruction_builders::EvictTraderAccountBackup;
+use phoenix_seat_manager::processor::get_lowest_priority_evictable_trader;
+use phoenix_seat_manager::seat_auction::SeatAuction;
+use phoenix_seat_manager::seat_auction::SEAT_AUCTION_SETTLE_WINDOW_IN_SLOTS;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn fill_market(sdk: &SDKClient) -> Vec<Pubkey> {
+    let mut traders = vec![];
+    while sdk.get_traders().await.len() < NUM_SEATS {
+        let trader = Pubkey::new_unique();
+        let claim_seat = create_claim_seat_authorized_instruction(
+            &trader,
+            &sdk.active_market_key,
+            &sdk.client.payer.pubkey(),
+        );
+        sdk.client
+            .sign_send_instructions(vec![claim_seat], vec![])
+            .await
+            .unwrap();
+        traders.push(trader);
+    }
+    traders
+}
+
+async fn get_lamports(sdk: &SDKClient, account: &Pubkey) -> u64 {
+    sdk.client
+        .get_account(account)
+        .await
+        .map(|account| account.lamports)
+        .unwrap_or(0)
+}
+
+#[tokio::test]
+async fn test_bid_for_seat_fails_if_market_is_not_full() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let reserve_price = sdk.client.rent_exempt(spl_token::state::Account::LEN) * 10;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_configure_seat_auction_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                reserve_price,
+                10,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let bidder = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_bid_for_seat_instruction(
+                &sdk.active_market_key,
+                &bidder.pubkey(),
+                None,
+                reserve_price,
+            )],
+            vec![&bidder],
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_seat_auction_happy_path() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let (treasury, _) = get_treasury_address(&sdk.active_market_key);
+    let deposit_amount = sdk.client.rent_exempt(spl_token::state::Account::LEN) * 2;
+    let reserve_price = deposit_amount * 10;
+
+    // Only the seat manager authority can configure the auction, and the reserve must cover the seat deposit
+    let non_authority = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_configure_seat_auction_instruction(
+                &sdk.active_market_key,
+                &non_authority.pubkey(),
+                reserve_price,
+                10,
+            )],
+            vec![&non_authority],
+        )
+        .await
+        .is_err());
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_configure_seat_auction_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                deposit_amount - 1,
+                10,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_configure_seat_auction_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                reserve_price,
+                10,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    fill_market(&sdk).await;
+
+    let bidder_one = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let bidder_two = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+
+    // Bids below the reserve are rejected
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_bid_for_seat_instruction(
+                &sdk.active_market_key,
+                &bidder_one.pubkey(),
+                None,
+                reserve_price - 1,
+            )],
+            vec![&bidder_one],
+        )
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_bid_for_seat_instruction(
+                &sdk.active_market_key,
+                &bidder_one.pubkey(),
+                None,
+                reserve_price,
+            )],
+            vec![&bidder_one],
+        )
+        .await
+        .unwrap();
+
+    // Bids must exceed the highest bid
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_bid_for_seat_instruction(
+                &sdk.active_market_key,
+                &bidder_two.pubkey(),
+                Some(bidder_one.pubkey()),
+                reserve_price,
+            )],
+            vec![&bidder_two],
+        )
+        .await
+        .is_err());
+
+    // Outbidding refunds the previous highest bidder
+    let bidder_one_lamports = get_lamports(&sdk, &bidder_one.pubkey()).await;
+    let winning_bid = reserve_price + 1;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_bid_for_seat_instruction(
+                &sdk.active_market_key,
+                &bidder_two.pubkey(),
+                Some(bidder_one.pubkey()),
+                winning_bid,
+            )],
+            vec![&bidder_two],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        get_lamports(&sdk, &bidder_one.pubkey()).await,
+        bidder_one_lamports + reserve_price
+    );
+
+    let market_data = sdk
+        .client
+        .get_account_data(&sdk.active_market_key)
+        .await
+        .unwrap();
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    let evicted_trader = get_lowest_priority_evictable_trader(
+        &market_data,
+        bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap(),
+        None,
+        true,
+        |_| false,
+    )
+    .unwrap()
+    .unwrap();
+
+    let settle_seat_auction = create_settle_seat_auction_instruction(
+        &sdk.active_market_key,
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        &sdk.client.payer.pubkey(),
+        &bidder_two.pubkey(),
+        Some(EvictTraderAccountBackup {
+            trader_pubkey: evicted_trader,
+            base_token_account_backup: None,
+            quote_token_account_backup: None,
+        }),
+    );
+
+    // The round cannot be settled before it ends
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                settle_seat_auction.clone(),
+            ],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    ctx.warp_to_slot(1_000_000).unwrap();
+
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                settle_seat_auction,
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let traders = sdk.get_traders().await;
+    assert_eq!(traders.len(), NUM_SEATS);
+    assert!(!traders.contains_key(&evicted_trader));
+    assert!(traders.contains_key(&bidder_two.pubkey()));
+    assert!(!traders.contains_key(&bidder_one.pubkey()));
+    assert_eq!(
+        get_lamports(&sdk, &treasury).await,
+        winning_bid - deposit_amount
+    );
+}
+
+#[tokio::test]
+async fn test_settle_seat_auction_respects_the_waitlist_and_eviction_privileges() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let reserve_price = sdk.client.rent_exempt(spl_token::state::Account::LEN) * 10;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_configure_seat_auction_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                reserve_price,
+                10,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    fill_market(&sdk).await;
+
+    let bidder = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_bid_for_seat_instruction(
+                &sdk.active_market_key,
+                &bidder.pubkey(),
+                None,
+                reserve_price,
+            )],
+            vec![&bidder],
+        )
+        .await
+        .unwrap();
+
+    let waitlisted_trader =
+        setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+            .await
+            .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_join_seat_waitlist_instruction(
+                &waitlisted_trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&waitlisted_trader],
+        )
+        .await
+        .unwrap();
+    ctx.warp_to_slot(1_000_000).unwrap();
+
+    let market_data = sdk
+        .client
+        .get_account_data(&sdk.active_market_key)
+        .await
+        .unwrap();
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    let evicted_trader = get_lowest_priority_evictable_trader(
+        &market_data,
+        bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap(),
+        None,
+        true,
+        |_| false,
+    )
+    .unwrap()
+    .unwrap();
+    let settle_seat_auction = |signer: &Pubkey| {
+        create_settle_seat_auction_instruction(
+            &sdk.active_market_key,
+            &sdk.base_mint,
+            &sdk.quote_mint,
+            signer,
+            &bidder.pubkey(),
+            Some(EvictTraderAccountBackup {
+                trader_pubkey: evicted_trader,
+                base_token_account_backup: None,
+                quote_token_account_backup: None,
+            }),
+        )
+    };
+
+    // Waitlisted traders have priority over the auction
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![settle_seat_auction(&sdk.client.payer.pubkey())],
+            vec![]
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_leave_seat_waitlist_instruction(
+                &waitlisted_trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&waitlisted_trader],
+        )
+        .await
+        .unwrap();
+
+    // Settling evicts a seat, so it is refused to permissionless signers when permissionless eviction is disabled
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_permissionless_access_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                true,
+                false,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    let settler = Keypair::new();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                settle_seat_auction(&settler.pubkey()),
+            ],
+            vec![&settler],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                settle_seat_auction(&sdk.client.payer.pubkey()),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let traders = sdk.get_traders().await;
+    assert!(!traders.contains_key(&evicted_trader));
+    assert!(traders.contains_key(&bidder.pubkey()));
+}
+
+#[tokio::test]
+async fn test_settle_seat_auction_refunds_the_winner_when_no_seat_can_be_evicted() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let reserve_price = sdk.client.rent_exempt(spl_token::state::Account::LEN) * 10;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_configure_seat_auction_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                reserve_price,
+                10,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    fill_market(&sdk).await;
+
+    let bidder = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_bid_for_seat_instruction(
+                &sdk.active_market_key,
+                &bidder.pubkey(),
+                None,
+                reserve_price,
+            )],
+            vec![&bidder],
+        )
+        .await
+        .unwrap();
+    let bidder_lamports = get_lamports(&sdk, &bidder.pubkey()).await;
+
+    // Only the authority can evict seats, so permissionless settlers cannot give the winner a seat
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_eviction_policy_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                EvictionPolicy::AuthorityOnly,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    ctx.warp_to_slot(1_000).unwrap();
+
+    let market_data = sdk
+        .client
+        .get_account_data(&sdk.active_market_key)
+        .await
+        .unwrap();
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    assert!(get_lowest_priority_evictable_trader(
+        &market_data,
+        bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap(),
+        None,
+        false,
+        |_| false,
+    )
+    .unwrap()
+    .is_none());
+
+    let settler = Keypair::new();
+    let settle_seat_auction = create_settle_seat_auction_instruction(
+        &sdk.active_market_key,
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        &settler.pubkey(),
+        &bidder.pubkey(),
+        None,
+    );
+
+    // The bid stays escrowed until the settle deadline
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![settle_seat_auction.clone()], vec![&settler])
+        .await
+        .is_err());
+
+    ctx.warp_to_slot(SEAT_AUCTION_SETTLE_WINDOW_IN_SLOTS + 1_000)
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                settle_seat_auction,
+            ],
+            vec![&settler],
+        )
+        .await
+        .unwrap();
+
+    // The winning bid is refunded, no seat is evicted and the round is cleared
+    assert_eq!(
+        get_lamports(&sdk, &bidder.pubkey()).await,
+        bidder_lamports + reserve_price
+    );
+    let traders = sdk.get_traders().await;
+    assert_eq!(traders.len(), NUM_SEATS);
+    assert!(!traders.contains_key(&bidder.pubkey()));
+    let seat_auction_data = sdk
+        .client
+        .get_account_data(&get_seat_auction_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    assert!(!bytemuck::try_from_bytes::<SeatAuction>(&seat_auction_data)
+        .unwrap()
+        .is_in_progress());
+}
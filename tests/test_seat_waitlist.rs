@@ -0,0 +1,362 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::setup_account;
+use crate::setup::init::PhoenixTestClient;
+use crate::setup::init::NUM_SEATS;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::get_seat_waitlist_address;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_with_eviction_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_evict_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_evict_seat_with_waitlist_instruction;
+use phoenix_seat_manager::instruction_builders::create_join_seat_waitlist_instruction;
+use phoenix_seat_manager::instruction_builders::create_leave_seat_waitlist_instruction;
+use phoenix_seat_manager::instruction_builders::EvictTraderAccountBackup;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use phoenix_seat_manager::seat_waitlist::SeatWaitlist;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signer::Signer;
+
+async fn fill_market(sdk: &SDKClient) -> Vec<Pubkey> {
+    let mut traders = vec![];
+    while sdk.get_traders().await.len() < NUM_SEATS {
+        let trader = Pubkey::new_unique();
+        let claim_seat = create_claim_seat_authorized_instruction(
+            &trader,
+            &sdk.active_market_key,
+            &sdk.client.payer.pubkey(),
+        );
+        sdk.client
+            .sign_send_instructions(vec![claim_seat], vec![])
+            .await
+            .unwrap();
+        traders.push(trader);
+    }
+    traders
+}
+
+async fn get_seat_waitlist(sdk: &SDKClient) -> SeatWaitlist {
+    let (seat_waitlist_address, _) = get_seat_waitlist_address(&sdk.active_market_key);
+    let seat_waitlist_data = sdk
+        .client
+        .get_account_data(&seat_waitlist_address)
+        .await
+        .unwrap();
+    *bytemuck::try_from_bytes::<SeatWaitlist>(&seat_waitlist_data).unwrap()
+}
+
+async fn get_seat_manager(sdk: &SDKClient) -> SeatManager {
+    let (seat_manager_address, _) = get_seat_manager_address(&sdk.active_market_key);
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&seat_manager_address)
+        .await
+        .unwrap();
+    *bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap()
+}
+
+#[tokio::test]
+async fn test_join_seat_waitlist_fails_if_market_is_not_full() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+
+    let join_waitlist =
+        create_join_seat_waitlist_instruction(&trader.pubkey(), &sdk.active_market_key);
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![join_waitlist], vec![&trader])
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_evict_seat_approves_next_waitlisted_trader() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let seated_traders = fill_market(&sdk).await;
+
+    let trader_one = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let trader_two = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_join_seat_waitlist_instruction(&trader_one.pubkey(), &sdk.active_market_key),
+                create_join_seat_waitlist_instruction(&trader_two.pubkey(), &sdk.active_market_key),
+            ],
+            vec![&trader_one, &trader_two],
+        )
+        .await
+        .unwrap();
+
+    let seat_waitlist = get_seat_waitlist(&sdk).await;
+    assert_eq!(
+        seat_waitlist.iter().copied().collect::<Vec<_>>(),
+        vec![trader_one.pubkey(), trader_two.pubkey()]
+    );
+    assert_eq!(get_seat_manager(&sdk).await.num_waitlisted_traders, 2);
+
+    // Evicting without the waitlist fails while traders are waitlisted
+    let evicted_trader = EvictTraderAccountBackup {
+        trader_pubkey: seated_traders[0],
+        base_token_account_backup: None,
+        quote_token_account_backup: None,
+    };
+    let evict_seat = create_evict_seat_instruction(
+        &sdk.active_market_key,
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        &sdk.client.payer.pubkey(),
+        vec![evicted_trader],
+    );
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat,
+            ],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    let evict_seat = create_evict_seat_with_waitlist_instruction(
+        &sdk.active_market_key,
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        &sdk.client.payer.pubkey(),
+        vec![EvictTraderAccountBackup {
+            trader_pubkey: seated_traders[0],
+            base_token_account_backup: None,
+            quote_token_account_backup: None,
+        }],
+        vec![trader_one.pubkey()],
+    );
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat,
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The freed seat went to the front of the waitlist
+    let traders = sdk.get_traders().await;
+    assert_eq!(traders.len(), NUM_SEATS);
+    assert!(!traders.contains_key(&seated_traders[0]));
+    assert!(traders.contains_key(&trader_one.pubkey()));
+    assert!(!traders.contains_key(&trader_two.pubkey()));
+
+    let seat_waitlist = get_seat_waitlist(&sdk).await;
+    assert_eq!(
+        seat_waitlist.iter().copied().collect::<Vec<_>>(),
+        vec![trader_two.pubkey()]
+    );
+    assert_eq!(get_seat_manager(&sdk).await.num_waitlisted_traders, 1);
+}
+
+#[tokio::test]
+async fn test_leave_seat_waitlist_refunds_deposit() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    fill_market(&sdk).await;
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_join_seat_waitlist_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader],
+        )
+        .await
+        .unwrap();
+
+    // Joining twice fails
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_join_seat_waitlist_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+
+    let trader_initial_lamports = sdk
+        .client
+        .get_account(&trader.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_leave_seat_waitlist_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader],
+        )
+        .await
+        .unwrap();
+
+    let trader_final_lamports = sdk
+        .client
+        .get_account(&trader.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+    let deposit_amount = sdk.client.rent_exempt(spl_token::state::Account::LEN) * 2;
+    assert_eq!(
+        trader_final_lamports,
+        trader_initial_lamports + deposit_amount
+    );
+
+    assert!(get_seat_waitlist(&sdk).await.is_empty());
+    assert_eq!(get_seat_manager(&sdk).await.num_waitlisted_traders, 0);
+
+    // Leaving again fails
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_leave_seat_waitlist_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_evict_seat_refunds_waitlisted_trader_whose_seat_is_no_longer_pending() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let seated_traders = fill_market(&sdk).await;
+
+    let trader_one = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let trader_two = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_join_seat_waitlist_instruction(&trader_one.pubkey(), &sdk.active_market_key),
+                create_join_seat_waitlist_instruction(&trader_two.pubkey(), &sdk.active_market_key),
+            ],
+            vec![&trader_one, &trader_two],
+        )
+        .await
+        .unwrap();
+
+    // The authority seats the first waitlisted trader directly while they are still queued
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                create_claim_seat_with_eviction_authorized_instruction(
+                    &trader_one.pubkey(),
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    &sdk.base_mint,
+                    &sdk.quote_mint,
+                    EvictTraderAccountBackup {
+                        trader_pubkey: seated_traders[0],
+                        base_token_account_backup: None,
+                        quote_token_account_backup: None,
+                    },
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_seat_waitlist(&sdk).await.len(), 2);
+
+    let trader_one_initial_lamports = sdk
+        .client
+        .get_account(&trader_one.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+
+    let evict_seat = create_evict_seat_with_waitlist_instruction(
+        &sdk.active_market_key,
+        &sdk.base_mint,
+        &sdk.quote_mint,
+        &sdk.client.payer.pubkey(),
+        vec![EvictTraderAccountBackup {
+            trader_pubkey: seated_traders[1],
+            base_token_account_backup: None,
+            quote_token_account_backup: None,
+        }],
+        vec![trader_one.pubkey(), trader_two.pubkey()],
+    );
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat,
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // The first trader is dropped and refunded, and the freed seat goes to the second
+    let trader_one_final_lamports = sdk
+        .client
+        .get_account(&trader_one.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+    let deposit_amount = sdk.client.rent_exempt(spl_token::state::Account::LEN) * 2;
+    assert_eq!(
+        trader_one_final_lamports,
+        trader_one_initial_lamports + deposit_amount
+    );
+    let traders = sdk.get_traders().await;
+    assert!(traders.contains_key(&trader_one.pubkey()));
+    assert!(traders.contains_key(&trader_two.pubkey()));
+    assert!(get_seat_waitlist(&sdk).await.is_empty());
+    assert_eq!(get_seat_manager(&sdk).await.num_waitlisted_traders, 0);
+}
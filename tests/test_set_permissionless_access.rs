@@ -0,0 +1,253 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::setup_account;
+use crate::setup::init::PhoenixTestClient;
+use crate::setup::init::NUM_SEATS;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_bid_for_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_authorized_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_configure_seat_auction_instruction;
+use phoenix_seat_manager::instruction_builders::create_evict_seat_instruction;
+use phoenix_seat_manager::instruction_builders::create_join_seat_waitlist_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_permissionless_access_instruction;
+use phoenix_seat_manager::instruction_builders::EvictTraderAccountBackup;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn test_disable_permissionless_claims_and_evictions() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+
+    // Only the seat manager authority can change permissionless access
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_permissionless_access_instruction(
+                &sdk.active_market_key,
+                &trader.pubkey(),
+                true,
+                true,
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_permissionless_access_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                true,
+                true,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    let seat_manager = SeatManager::load(&seat_manager_data).unwrap();
+    assert!(seat_manager.is_permissionless_eviction_disabled());
+    assert!(seat_manager.is_permissionless_claims_disabled());
+
+    // Permissionless claims fail, authorized claims succeed
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&trader],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_authorized_instruction(
+                &trader.pubkey(),
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let evict_seat = |signer: &Pubkey| {
+        create_evict_seat_instruction(
+            &sdk.active_market_key,
+            &sdk.base_mint,
+            &sdk.quote_mint,
+            signer,
+            vec![EvictTraderAccountBackup {
+                trader_pubkey: trader.pubkey(),
+                base_token_account_backup: None,
+                quote_token_account_backup: None,
+            }],
+        )
+    };
+
+    // Permissionless evictions fail, even for empty seats
+    let evictor = Keypair::new();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat(&evictor.pubkey()),
+            ],
+            vec![&evictor],
+        )
+        .await
+        .is_err());
+    assert!(sdk.get_traders().await.contains_key(&trader.pubkey()));
+
+    // The authority can still evict
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                evict_seat(&sdk.client.payer.pubkey()),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!sdk.get_traders().await.contains_key(&trader.pubkey()));
+
+    // Re-enabling permissionless claims lets the trader claim again
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_permissionless_access_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                false,
+                false,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    let new_trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_claim_seat_instruction(
+                &new_trader.pubkey(),
+                &sdk.active_market_key,
+            )],
+            vec![&new_trader],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.get_traders().await.contains_key(&new_trader.pubkey()));
+}
+
+#[tokio::test]
+async fn test_disabled_permissionless_claims_block_the_waitlist_and_auction() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let deposit_amount = sdk.client.rent_exempt(spl_token::state::Account::LEN) * 2;
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_configure_seat_auction_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    deposit_amount,
+                    10,
+                ),
+                create_set_permissionless_access_instruction(
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                    false,
+                    true,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    while sdk.get_traders().await.len() < NUM_SEATS {
+        sdk.client
+            .sign_send_instructions(
+                vec![create_claim_seat_authorized_instruction(
+                    &Pubkey::new_unique(),
+                    &sdk.active_market_key,
+                    &sdk.client.payer.pubkey(),
+                )],
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+
+    let trader = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    let join_waitlist =
+        || create_join_seat_waitlist_instruction(&trader.pubkey(), &sdk.active_market_key);
+    let bid_for_seat = || {
+        create_bid_for_seat_instruction(
+            &sdk.active_market_key,
+            &trader.pubkey(),
+            None,
+            deposit_amount,
+        )
+    };
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![join_waitlist()], vec![&trader])
+        .await
+        .is_err());
+    assert!(sdk
+        .client
+        .sign_send_instructions(vec![bid_for_seat()], vec![&trader])
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_permissionless_access_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                false,
+                false,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(vec![join_waitlist()], vec![&trader])
+        .await
+        .unwrap();
+    sdk.client
+        .sign_send_instructions(vec![bid_for_seat()], vec![&trader])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,431 @@
+mod setup;
+
+use crate::setup::helpers::airdrop;
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::PhoenixTestClient;
+use phoenix_sdk::sdk_client::SDKClient;
+use phoenix_seat_manager::get_pending_action_address;
+use phoenix_seat_manager::get_seat_manager_address;
+use phoenix_seat_manager::instruction_builders::create_cancel_timelocked_action_instruction;
+use phoenix_seat_manager::instruction_builders::create_claim_seat_manager_authority_instruction;
+use phoenix_seat_manager::instruction_builders::create_confirm_renounce_seat_manager_authority_instruction;
+use phoenix_seat_manager::instruction_builders::create_execute_timelocked_action_instruction;
+use phoenix_seat_manager::instruction_builders::create_name_market_authority_successor_instruction;
+use phoenix_seat_manager::instruction_builders::create_name_seat_manager_successor_instruction;
+use phoenix_seat_manager::instruction_builders::create_queue_timelocked_action_instruction;
+use phoenix_seat_manager::instruction_builders::create_set_timelock_delay_instruction;
+use phoenix_seat_manager::pending_action::TimelockedAction;
+use phoenix_seat_manager::seat_manager::SeatManager;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn get_seat_manager(sdk: &SDKClient) -> SeatManager {
+    let seat_manager_data = sdk
+        .client
+        .get_account_data(&get_seat_manager_address(&sdk.active_market_key).0)
+        .await
+        .unwrap();
+    *bytemuck::try_from_bytes::<SeatManager>(&seat_manager_data).unwrap()
+}
+
+#[tokio::test]
+async fn test_timelocked_successor() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    let timelock_delay_in_slots = 100;
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_timelock_delay_instruction(
+                &market,
+                &authority,
+                timelock_delay_in_slots,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Successors can no longer be named directly
+    let successor = Pubkey::new_unique();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_name_seat_manager_successor_instruction(
+                &authority, &market, &successor,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    // The timelock delay cannot be decreased directly
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_set_timelock_delay_instruction(
+                &market, &authority, 0
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    let action = TimelockedAction::NameSuccessor { successor };
+    sdk.client
+        .sign_send_instructions(
+            vec![create_queue_timelocked_action_instruction(
+                &market, &authority, &authority, action,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Anyone can execute the action once the delay has passed, without the authority's signature
+    let executor = Keypair::new();
+    airdrop(&sdk.client, &executor.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let execute_action = create_execute_timelocked_action_instruction(
+        &market,
+        &action,
+        &authority,
+        create_name_seat_manager_successor_instruction(&executor.pubkey(), &market, &successor),
+    );
+    assert!(sdk
+        .client
+        .sign_send_instructions_with_payer(vec![execute_action.clone()], vec![&executor])
+        .await
+        .is_err());
+    assert_ne!(get_seat_manager(&sdk).await.successor, successor);
+
+    ctx.warp_to_slot(timelock_delay_in_slots + 10).unwrap();
+    sdk.client
+        .sign_send_instructions_with_payer(vec![execute_action], vec![&executor])
+        .await
+        .unwrap();
+    assert_eq!(get_seat_manager(&sdk).await.successor, successor);
+    assert!(sdk
+        .client
+        .get_account(&get_pending_action_address(&market, &action).0)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_cancel_timelocked_action() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_timelock_delay_instruction(
+                &market, &authority, 100,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let successor = Pubkey::new_unique();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_name_market_authority_successor_instruction(
+                &market, &authority, &successor,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    let action = TimelockedAction::NameMarketAuthoritySuccessor { successor };
+    let (pending_action, _) = get_pending_action_address(&market, &action);
+    sdk.client
+        .sign_send_instructions(
+            vec![create_queue_timelocked_action_instruction(
+                &market, &authority, &authority, action,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.client.get_account(&pending_action).await.is_ok());
+
+    // Only the authority can cancel
+    let non_authority = Keypair::new();
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_cancel_timelocked_action_instruction(
+                &market,
+                &non_authority.pubkey(),
+                &authority,
+                action,
+            )],
+            vec![&non_authority],
+        )
+        .await
+        .is_err());
+    sdk.client
+        .sign_send_instructions(
+            vec![create_cancel_timelocked_action_instruction(
+                &market, &authority, &authority, action,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(sdk.client.get_account(&pending_action).await.is_err());
+}
+
+#[tokio::test]
+async fn test_pending_actions_block_renouncing_the_authority() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_timelock_delay_instruction(
+                &market, &authority, 100,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    // Renouncing is initiated by naming the default pubkey as the successor
+    let renounce_action = TimelockedAction::NameSuccessor {
+        successor: Pubkey::default(),
+    };
+    let market_authority_action = TimelockedAction::NameMarketAuthoritySuccessor {
+        successor: Pubkey::new_unique(),
+    };
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_queue_timelocked_action_instruction(
+                    &market,
+                    &authority,
+                    &authority,
+                    renounce_action,
+                ),
+                create_queue_timelocked_action_instruction(
+                    &market,
+                    &authority,
+                    &authority,
+                    market_authority_action,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_seat_manager(&sdk).await.num_pending_actions, 2);
+
+    ctx.warp_to_slot(110).unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_execute_timelocked_action_instruction(
+                &market,
+                &renounce_action,
+                &authority,
+                create_name_seat_manager_successor_instruction(
+                    &authority,
+                    &market,
+                    &Pubkey::default(),
+                ),
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_seat_manager(&sdk).await.num_pending_actions, 1);
+
+    // The queued market authority successor would remain executable by anyone after renouncing
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_confirm_renounce_seat_manager_authority_instruction(
+                &authority, &market,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_cancel_timelocked_action_instruction(
+                &market,
+                &authority,
+                &authority,
+                market_authority_action,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_seat_manager(&sdk).await.num_pending_actions, 0);
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                create_confirm_renounce_seat_manager_authority_instruction(&authority, &market),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_seat_manager(&sdk).await.authority, Pubkey::default());
+
+    // Nothing can be queued once the authority is renounced
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                create_queue_timelocked_action_instruction(
+                    &market,
+                    &authority,
+                    &authority,
+                    market_authority_action,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_pending_actions_lapse_when_the_authority_changes() {
+    let PhoenixTestClient {
+        mut ctx,
+        sdk,
+        mint_authority: _,
+    } = bootstrap_default(5).await;
+
+    let market = sdk.active_market_key;
+    let authority = sdk.client.payer.pubkey();
+    sdk.client
+        .sign_send_instructions(
+            vec![create_set_timelock_delay_instruction(
+                &market, &authority, 100,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let new_authority = Keypair::new();
+    airdrop(&sdk.client, &new_authority.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let handoff_action = TimelockedAction::NameSuccessor {
+        successor: new_authority.pubkey(),
+    };
+    let successor = Pubkey::new_unique();
+    let market_authority_action = TimelockedAction::NameMarketAuthoritySuccessor { successor };
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_queue_timelocked_action_instruction(
+                    &market,
+                    &authority,
+                    &authority,
+                    handoff_action,
+                ),
+                create_queue_timelocked_action_instruction(
+                    &market,
+                    &authority,
+                    &authority,
+                    market_authority_action,
+                ),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    ctx.warp_to_slot(110).unwrap();
+    sdk.client
+        .sign_send_instructions(
+            vec![
+                create_execute_timelocked_action_instruction(
+                    &market,
+                    &handoff_action,
+                    &authority,
+                    create_name_seat_manager_successor_instruction(
+                        &authority,
+                        &market,
+                        &new_authority.pubkey(),
+                    ),
+                ),
+                create_claim_seat_manager_authority_instruction(&market, &new_authority.pubkey()),
+            ],
+            vec![&new_authority],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        get_seat_manager(&sdk).await.authority,
+        new_authority.pubkey()
+    );
+
+    // The action queued by the previous authority can no longer be executed
+    assert!(
+        sdk.client
+            .sign_send_instructions(
+                vec![create_execute_timelocked_action_instruction(
+                    &market,
+                    &market_authority_action,
+                    &authority,
+                    create_name_market_authority_successor_instruction(
+                        &market, &authority, &successor,
+                    ),
+                )],
+                vec![],
+            )
+            .await
+            .is_err()
+    );
+
+    // The new authority cancels it instead
+    sdk.client
+        .sign_send_instructions(
+            vec![create_cancel_timelocked_action_instruction(
+                &market,
+                &new_authority.pubkey(),
+                &authority,
+                market_authority_action,
+            )],
+            vec![&new_authority],
+        )
+        .await
+        .unwrap();
+    assert!(sdk
+        .client
+        .get_account(&get_pending_action_address(&market, &market_authority_action).0)
+        .await
+        .is_err());
+    assert_eq!(get_seat_manager(&sdk).await.num_pending_actions, 0);
+}
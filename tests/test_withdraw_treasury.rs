@@ -0,0 +1,91 @@
+mod setup;
+
+use crate::setup::init::bootstrap_default;
+use crate::setup::init::setup_account;
+use crate::setup::init::PhoenixTestClient;
+use phoenix_seat_manager::get_treasury_address;
+use phoenix_seat_manager::instruction_builders::create_withdraw_treasury_instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn test_withdraw_treasury() {
+    let PhoenixTestClient {
+        ctx: _,
+        sdk,
+        mint_authority,
+    } = bootstrap_default(5).await;
+
+    let (treasury, _) = get_treasury_address(&sdk.active_market_key);
+    let treasury_balance = sdk.client.rent_exempt(0) * 10;
+    sdk.client
+        .sign_send_instructions(
+            vec![system_instruction::transfer(
+                &sdk.client.payer.pubkey(),
+                &treasury,
+                treasury_balance,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let destination = Pubkey::new_unique();
+    let withdrawal = treasury_balance / 2;
+
+    // Only the seat manager authority can withdraw
+    let non_authority = setup_account(&sdk.client, &mint_authority, sdk.base_mint, sdk.quote_mint)
+        .await
+        .user;
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_withdraw_treasury_instruction(
+                &sdk.active_market_key,
+                &non_authority.pubkey(),
+                &destination,
+                withdrawal,
+            )],
+            vec![&non_authority],
+        )
+        .await
+        .is_err());
+
+    // Withdrawals cannot exceed the treasury balance
+    assert!(sdk
+        .client
+        .sign_send_instructions(
+            vec![create_withdraw_treasury_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                &destination,
+                treasury_balance + 1,
+            )],
+            vec![],
+        )
+        .await
+        .is_err());
+
+    sdk.client
+        .sign_send_instructions(
+            vec![create_withdraw_treasury_instruction(
+                &sdk.active_market_key,
+                &sdk.client.payer.pubkey(),
+                &destination,
+                withdrawal,
+            )],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        sdk.client.get_account(&destination).await.unwrap().lamports,
+        withdrawal
+    );
+    assert_eq!(
+        sdk.client.get_account(&treasury).await.unwrap().lamports,
+        treasury_balance - withdrawal
+    );
+}